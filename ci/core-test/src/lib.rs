@@ -1,7 +1,49 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use chrono::format::StrftimeItems;
 use chrono::{TimeZone, Utc};
 
 pub fn create_time() {
     let _ = Utc.ymd(2019, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
 }
+
+/// Writing into a `fmt::Write` doesn't even need `alloc`.
+pub fn write_time<W: fmt::Write>(w: &mut W) -> fmt::Result {
+    let dt = Utc.ymd(2019, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    dt.write_rfc3339(w)?;
+    dt.write_to(w, StrftimeItems::new(" %Y-%m-%d %H:%M:%S"))
+}
+
+/// Formatting only needs `alloc`, not `std`.
+#[cfg(feature = "alloc")]
+pub fn format_time() -> (String, String) {
+    let dt = Utc.ymd(2019, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    (dt.format("%Y-%m-%d %H:%M:%S").to_string(), dt.to_rfc3339())
+}
+
+// the embedded targets only build this crate, so the output is checked on the host
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::String;
+
+    #[test]
+    fn test_write_time() {
+        let mut s = String::new();
+        super::write_time(&mut s).unwrap();
+        assert_eq!(s, "2019-01-01T00:00:00+00:00 2019-01-01 00:00:00");
+    }
+
+    #[test]
+    fn test_format_time() {
+        let (formatted, rfc3339) = super::format_time();
+        assert_eq!(formatted, "2019-01-01 00:00:00");
+        assert_eq!(rfc3339, "2019-01-01T00:00:00+00:00");
+    }
+}
@@ -6,7 +6,7 @@ set -euo pipefail
 source "${BASH_SOURCE[0]%/*}/_shlib.sh"
 
 TEST_TZS=(ACST-9:30 EST4 UTC0 Asia/Katmandu)
-FEATURES=(std serde clock "alloc serde" unstable-locales)
+FEATURES=(std serde clock "alloc serde" unstable-locales arbitrary proptest)
 CHECK_FEATURES=(alloc "std unstable-locales" "serde clock" "clock unstable-locales")
 RUST_132_FEATURES=(serde)
 
@@ -93,6 +93,8 @@ test_core() {
     (
         cd ci/core-test
         runt cargo build --target thumbv6m-none-eabi --color=always
+        runt cargo build --target thumbv6m-none-eabi --features alloc --color=always
+        runt cargo test --features alloc --color=always
     )
 }
 
@@ -52,6 +52,21 @@ use crate::{ChronoError, DateTime, Datelike, Weekday};
 /// - The date is timezone-agnostic up to one day (i.e. practically always),
 ///   so the local date and UTC date should be equal for most cases
 ///   even though the raw calculation between `NaiveDate` and `Duration` may not.
+///
+/// # Replacing `Date`
+///
+/// Code using `Date<Tz>` only to build a `DateTime<Tz>` can combine a
+/// `NaiveDate` with a `NaiveTime` instead, and resolve the result in the time
+/// zone once, with [`TimeZone::from_local_datetime`]:
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let local = NaiveDate::from_ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+/// let dt = Utc.from_local_datetime(&local)?;
+/// assert_eq!(dt, Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?);
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
 #[derive(Clone)]
 #[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct Date<Tz: TimeZone> {
@@ -79,9 +94,26 @@ impl<Tz: TimeZone> Date<Tz> {
     }
 
     /// Makes a new `DateTime` from the current date and given `NaiveTime`.
-    /// The offset in the current date is preserved.
     ///
-    /// Panics on invalid datetime.
+    /// The offset is resolved again in the time zone for the new local date
+    /// and time, as it may differ from the offset of the date, e.g. on the day
+    /// daylight saving time starts.
+    ///
+    /// Returns `Err(ChronoError)` if the local date and time doesn't exist in
+    /// the time zone, e.g. when skipped by the transition to daylight saving
+    /// time, or if it's ambiguous because it occurs twice. It never picks one
+    /// of the offsets on its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveTime, TimeZone};
+    ///
+    /// let date = FixedOffset::east(3600).ymd(2022, 6, 1)?;
+    /// let dt = date.and_time(NaiveTime::from_hms(12, 0, 0)?)?;
+    /// assert_eq!(dt.to_string(), "2022-06-01 12:00:00 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn and_time(&self, time: NaiveTime) -> Result<DateTime<Tz>, ChronoError> {
         let dt = self.naive_local().and_time(time);
@@ -89,9 +121,10 @@ impl<Tz: TimeZone> Date<Tz> {
     }
 
     /// Makes a new `DateTime` from the current date, hour, minute and second.
-    /// The offset in the current date is preserved.
+    /// The offset is resolved again as in [`Date::and_time`].
     ///
-    /// Returns `Err(ChronoError)` on invalid hour, minute and/or second.
+    /// Returns `Err(ChronoError)` on invalid hour, minute and/or second, or if the
+    /// local date and time doesn't exist or is ambiguous in the time zone.
     #[inline]
     pub fn and_hms(&self, hour: u32, min: u32, sec: u32) -> Result<DateTime<Tz>, ChronoError> {
         let time = NaiveTime::from_hms(hour, min, sec)?;
@@ -100,9 +133,10 @@ impl<Tz: TimeZone> Date<Tz> {
 
     /// Makes a new `DateTime` from the current date, hour, minute, second and millisecond.
     /// The millisecond part can exceed 1,000 in order to represent the leap second.
-    /// The offset in the current date is preserved.
+    /// The offset is resolved again as in [`Date::and_time`].
     ///
-    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or millisecond.
+    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or millisecond, or if the
+    /// local date and time doesn't exist or is ambiguous in the time zone.
     #[inline]
     pub fn and_hms_milli(
         &self,
@@ -117,9 +151,10 @@ impl<Tz: TimeZone> Date<Tz> {
 
     /// Makes a new `DateTime` from the current date, hour, minute, second and microsecond.
     /// The microsecond part can exceed 1,000,000 in order to represent the leap second.
-    /// The offset in the current date is preserved.
+    /// The offset is resolved again as in [`Date::and_time`].
     ///
-    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or microsecond.
+    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or microsecond, or if the
+    /// local date and time doesn't exist or is ambiguous in the time zone.
     #[inline]
     pub fn and_hms_micro(
         &self,
@@ -134,9 +169,10 @@ impl<Tz: TimeZone> Date<Tz> {
 
     /// Makes a new `DateTime` from the current date, hour, minute, second and nanosecond.
     /// The nanosecond part can exceed 1,000,000,000 in order to represent the leap second.
-    /// The offset in the current date is preserved.
+    /// The offset is resolved again as in [`Date::and_time`].
     ///
-    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or nanosecond.
+    /// Returns `Err(ChronoError)` on invalid hour, minute, second and/or nanosecond, or if the
+    /// local date and time doesn't exist or is ambiguous in the time zone.
     #[inline]
     pub fn and_hms_nano(
         &self,
@@ -174,6 +210,19 @@ impl<Tz: TimeZone> Date<Tz> {
     }
 
     /// Retrieves an associated time zone.
+    ///
+    /// The time zone is reconstructed from the stored offset through
+    /// [`TimeZone::from_offset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let date = Utc.ymd(2022, 6, 1)?;
+    /// assert_eq!(date.timezone().ymd(2022, 6, 2)?, date.succ()?);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn timezone(&self) -> Tz {
         TimeZone::from_offset(&self.offset)
@@ -435,6 +484,8 @@ impl<Tz: TimeZone> Ord for Date<Tz> {
     }
 }
 
+/// Hashes the local calendar date, ignoring the offset, consistent with
+/// [`PartialEq`].
 impl<Tz: TimeZone> hash::Hash for Date<Tz> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.date.hash(state)
@@ -594,4 +645,19 @@ mod tests {
         date_sub -= TimeDelta::days(5);
         assert_eq!(date_sub, date - TimeDelta::days(5));
     }
+
+    #[test]
+    fn test_date_timezone() {
+        use crate::TimeZone;
+
+        let date = Utc.ymd(2022, 6, 1).unwrap();
+        assert_eq!(date.timezone(), Utc);
+        assert_eq!(date.timezone().ymd(2022, 6, 1).unwrap(), date);
+
+        let tz = FixedOffset::west(4 * 3600);
+        let date = tz.ymd(2022, 6, 1).unwrap();
+        assert_eq!(date.timezone(), tz);
+        let other = date.timezone().ymd(2022, 12, 1).unwrap();
+        assert_eq!(other.offset(), date.offset());
+    }
 }
@@ -0,0 +1,125 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A span of time between two `DateTime`s.
+
+use core::cmp;
+
+use super::DateTime;
+use crate::error::ChronoErrorKind;
+use crate::offset::TimeZone;
+use crate::{ChronoError, TimeDelta};
+
+/// A span of time from a `start` up to, but not including, an `end`.
+///
+/// The interval is half-open, so two intervals where one ends when the other
+/// starts are adjacent and don't overlap. An interval where `start` equals
+/// `end` is empty.
+///
+/// Comparisons are done by instant, like those of [`DateTime`], so intervals
+/// in different time zones can be checked against each other.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Interval, TimeDelta, TimeZone, Utc};
+///
+/// let meeting = Interval::new(
+///     Utc.ymd(2022, 6, 1)?.and_hms(9, 0, 0)?,
+///     Utc.ymd(2022, 6, 1)?.and_hms(10, 30, 0)?,
+/// )?;
+/// let lunch = Interval::new(
+///     Utc.ymd(2022, 6, 1)?.and_hms(10, 0, 0)?,
+///     Utc.ymd(2022, 6, 1)?.and_hms(11, 0, 0)?,
+/// )?;
+///
+/// assert_eq!(meeting.duration(), TimeDelta::minutes(90));
+/// assert!(meeting.overlaps(&lunch));
+/// assert_eq!(meeting.intersection(&lunch).unwrap().duration(), TimeDelta::minutes(30));
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interval<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Interval<Tz> {
+    /// Makes a new interval from `start` up to `end`.
+    ///
+    /// Returns `Err(ChronoError)` if `end` is before `start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd(2022, 6, 1)?.and_hms(9, 0, 0)?;
+    /// let end = Utc.ymd(2022, 6, 1)?.and_hms(17, 0, 0)?;
+    /// assert!(Interval::new(start, end).is_ok());
+    /// assert!(Interval::new(end, start).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Result<Interval<Tz>, ChronoError> {
+        if end < start {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidInterval));
+        }
+        Ok(Interval { start, end })
+    }
+
+    /// Returns the start of the interval, which is included in it.
+    #[inline]
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the interval, which is not included in it.
+    #[inline]
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns the length of the interval, which is never negative.
+    pub fn duration(&self) -> TimeDelta {
+        self.end.clone().signed_duration_since(self.start.clone())
+    }
+
+    /// Returns `true` if the interval starts and ends at the same instant, so
+    /// it contains nothing.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `dt` is at or after the start of the interval and
+    /// before its end.
+    pub fn contains<Tz2: TimeZone>(&self, dt: &DateTime<Tz2>) -> bool {
+        self.start <= *dt && self.end > *dt
+    }
+
+    /// Returns `true` if the intervals have any instant in common.
+    ///
+    /// Adjacent intervals, where one ends when the other starts, don't
+    /// overlap. Neither does an empty interval.
+    pub fn overlaps<Tz2: TimeZone>(&self, other: &Interval<Tz2>) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the part of the interval that is also in `other`, or `None`
+    /// if they don't [overlap](#method.overlaps).
+    pub fn intersection(&self, other: &Interval<Tz>) -> Option<Interval<Tz>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = cmp::max(&self.start, &other.start).clone();
+        let end = cmp::min(&self.end, &other.end).clone();
+        Some(Interval { start, end })
+    }
+}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<Interval<Tz2>> for Interval<Tz> {
+    fn eq(&self, other: &Interval<Tz2>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Tz: TimeZone> Eq for Interval<Tz> {}
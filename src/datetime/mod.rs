@@ -8,10 +8,10 @@ extern crate alloc;
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::string::{String, ToString};
-#[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
+use core::iter::FusedIterator;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::{fmt, hash, str};
 #[cfg(feature = "std")]
@@ -22,17 +22,23 @@ use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
+use crate::error::ChronoErrorKind;
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::format::DelayedFormat;
 #[cfg(feature = "unstable-locales")]
 use crate::format::Locale;
-use crate::format::{parse, ParseError, ParseResult, Parsed, StrftimeItems, OUT_OF_RANGE};
-use crate::format::{Fixed, Item};
+use crate::format::{
+    parse, parse_and_remainder, ParseError, ParseResult, Parsed, StrftimeItems, OUT_OF_RANGE,
+};
+use crate::format::{write_items, Fixed, Item, OffsetName};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, FixedTimeZone, Offset, TimeZone, Utc};
-use crate::{ChronoError, Date, Datelike, Months, TimeDelta, Timelike, Weekday};
+use crate::offset::{FixedOffset, FixedTimeZone, LocalResult, Offset, TimeZone, Utc};
+use crate::{ChronoError, Date, DateField, Datelike, Months, TimeDelta, Timelike, Weekday};
+
+mod interval;
+pub use self::interval::Interval;
 
 /// documented at re-export site
 #[cfg(feature = "serde")]
@@ -110,9 +116,35 @@ impl<Tz: TimeZone> DateTime<Tz> {
         DateTime { datetime, offset }
     }
 
+    /// Makes a new `DateTime` from a *UTC* datetime and an offset that is
+    /// already known, e.g. because it was stored next to the timestamp.
+    ///
+    /// The offset is used as is, and not derived again from a time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, NaiveDateTime};
+    ///
+    /// let offset = FixedOffset::west(5 * 3600);
+    /// let utc = NaiveDateTime::from_timestamp(1_500_000_000, 0)?;
+    /// let dt = DateTime::<FixedOffset>::from_naive_utc_and_offset(utc, offset);
+    /// assert_eq!(dt.timestamp(), 1_500_000_000);
+    /// assert_eq!(dt.offset(), &offset);
+    /// assert_eq!(dt.to_rfc3339(), "2017-07-13T21:40:00-05:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn from_naive_utc_and_offset(datetime: NaiveDateTime, offset: Tz::Offset) -> DateTime<Tz> {
+        DateTime::from_utc(datetime, offset)
+    }
+
     /// Makes a new `DateTime` with given **local** datetime and offset that
     /// presents local timezone.
     ///
+    /// Returns `Err(ChronoError)` if the corresponding UTC datetime would be
+    /// out of range.
+    ///
     /// # Example
     ///
     /// ```
@@ -125,21 +157,73 @@ impl<Tz: TimeZone> DateTime<Tz> {
     ///
     /// let timezone_east = FixedOffset::east(8 * 60 * 60);
     /// let naivedatetime_east = NaiveDate::from_ymd(2000, 1, 12)?.and_hms(10, 0, 0)?;
-    /// let datetime_east = DateTime::<FixedOffset>::from_local(naivedatetime_east, timezone_east);
+    /// let datetime_east = DateTime::<FixedOffset>::from_local(naivedatetime_east, timezone_east)?;
     ///
     /// let timezone_west = FixedOffset::west(7 * 60 * 60);
     /// let naivedatetime_west = NaiveDate::from_ymd(2000, 1, 11)?.and_hms(19, 0, 0)?;
-    /// let datetime_west = DateTime::<FixedOffset>::from_local(naivedatetime_west, timezone_west);
+    /// let datetime_west = DateTime::<FixedOffset>::from_local(naivedatetime_west, timezone_west)?;
     ///
-    /// assert_eq!(datetime_east, datetime_utc.with_timezone(&timezone_east));
-    /// assert_eq!(datetime_west, datetime_utc.with_timezone(&timezone_west));
+    /// assert_eq!(datetime_east, datetime_utc.with_timezone(&timezone_east)?);
+    /// assert_eq!(datetime_west, datetime_utc.with_timezone(&timezone_west)?);
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
-    pub fn from_local(datetime: NaiveDateTime, offset: Tz::Offset) -> DateTime<Tz> {
-        let datetime_utc = datetime - offset.fix();
+    pub fn from_local(
+        datetime: NaiveDateTime,
+        offset: Tz::Offset,
+    ) -> Result<DateTime<Tz>, ChronoError> {
+        let datetime_utc = datetime.checked_sub_offset(offset.fix())?;
+
+        Ok(DateTime { datetime: datetime_utc, offset })
+    }
 
-        DateTime { datetime: datetime_utc, offset }
+    /// Makes all `DateTime`s for the given **local** datetime in the time zone
+    /// `tz`.
+    ///
+    /// Unlike [`TimeZone::from_local_datetime`], this returns both readings
+    /// when the local time occurs twice, ordered by instant, using
+    /// [`TimeZone::offset_from_local_datetime_all`]. Returns `Err(ChronoError)`
+    /// if the local time doesn't exist in `tz`, or the result is out of range.
+    ///
+    /// Time zones which don't implement `offset_from_local_datetime_all`
+    /// themselves, like `Local`, rely on its default implementation, which
+    /// only finds the other reading if the offsets a day before and after the
+    /// local time lead to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, LocalResult, NaiveDate, PosixTz};
+    ///
+    /// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+    /// let local = NaiveDate::from_ymd(2021, 11, 7)?.and_hms(1, 30, 0)?;
+    ///
+    /// match DateTime::from_local_all(local, &tz)? {
+    ///     LocalResult::Ambiguous(earlier, later) => {
+    ///         assert_eq!(earlier.to_rfc3339(), "2021-11-07T01:30:00-04:00");
+    ///         assert_eq!(later.to_rfc3339(), "2021-11-07T01:30:00-05:00");
+    ///     }
+    ///     LocalResult::Single(_) => unreachable!(),
+    /// }
+    ///
+    /// let fixed = FixedOffset::east(3600);
+    /// let dt = DateTime::from_local_all(local, &fixed)?;
+    /// assert_eq!(dt.single().unwrap().to_rfc3339(), "2021-11-07T01:30:00+01:00");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_local_all(
+        local: NaiveDateTime,
+        tz: &Tz,
+    ) -> Result<LocalResult<DateTime<Tz>>, ChronoError> {
+        let at_offset = |offset: Tz::Offset| {
+            local.checked_sub_offset(offset.fix()).map(|utc| DateTime::from_utc(utc, offset))
+        };
+        Ok(match tz.offset_from_local_datetime_all(&local)? {
+            LocalResult::Single(offset) => LocalResult::Single(at_offset(offset)?),
+            LocalResult::Ambiguous(earlier, later) => {
+                LocalResult::Ambiguous(at_offset(earlier)?, at_offset(later)?)
+            }
+        })
     }
 
     /// Retrieves a date component
@@ -166,6 +250,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Retrieves the Date without an associated timezone
     ///
+    /// This is the local calendar date, the same as `self.naive_local().date()`,
+    /// which may differ from the date in UTC.
+    ///
     /// [`NaiveDate`] is a more well-defined type, and has more traits implemented on it,
     /// so should be preferred to [`Date`] any time you truly want to operate on Dates.
     ///
@@ -175,6 +262,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// let date: DateTime<Utc> = Utc.ymd(2020, 1, 1)?.and_hms(0, 0, 0)?;
     /// let other: DateTime<FixedOffset> = FixedOffset::east(23).ymd(2020, 1, 1)?.and_hms(0, 0, 0)?;
     /// assert_eq!(date.date_naive(), other.date_naive());
+    ///
+    /// let ist = Utc.ymd(2020, 1, 1)?.and_hms(20, 0, 0)?.with_timezone(&FixedOffset::east(19800))?;
+    /// assert_eq!(ist.date_naive(), NaiveDate::from_ymd(2020, 1, 2)?);
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
@@ -189,6 +279,110 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.time() + self.offset.fix()
     }
 
+    /// Makes a new `DateTime` with the wall-clock time replaced by the given
+    /// hour, minute and second, keeping the local date.
+    ///
+    /// The new local date and time is resolved against the time zone again,
+    /// so the offset may differ from the original one, e.g. when moving across
+    /// a daylight saving time transition.
+    ///
+    /// Returns `Err(ChronoError)` if the time is invalid, if it falls into a
+    /// gap where the local time doesn't exist, or if it is ambiguous because
+    /// the local time occurs twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(1, 30, 0)?;
+    /// assert_eq!(dt.with_time(13, 15, 0)?.to_string(), "2022-03-27 13:15:00 +01:00");
+    /// assert!(dt.with_time(24, 0, 0).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn with_time(&self, hour: u32, min: u32, sec: u32) -> Result<DateTime<Tz>, ChronoError> {
+        map_local(self, |datetime| datetime.date().and_hms(hour, min, sec))
+    }
+
+    /// Makes a new `DateTime` with the nanosecond number changed, validating
+    /// it against [leap second](./naive/struct.NaiveTime.html#leap-second-handling)
+    /// rules.
+    ///
+    /// Nanoseconds from 1,000,000,000 up to 1,999,999,999 represent a leap
+    /// second, which is only accepted when the UTC second is 59. Unlike
+    /// [`Timelike::with_nanosecond`], which allows a leap second after any
+    /// second, this rejects leap seconds that can't occur in UTC. Since
+    /// offsets have no sub-second part, the offset of `self` is kept as is.
+    ///
+    /// Returns `Err(ChronoError)` if `nano` is 2,000,000,000 or more, or if
+    /// it represents a leap second anywhere but at the end of a UTC minute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2016, 12, 31)?.and_hms(23, 59, 59)?;
+    /// assert_eq!(dt.with_nanosecond_checked(1_500_000_000)?.to_string(),
+    ///            "2016-12-31 23:59:60.500 UTC");
+    ///
+    /// let dt = Utc.ymd(2016, 12, 31)?.and_hms(12, 0, 0)?;
+    /// assert!(dt.with_nanosecond_checked(1_500_000_000).is_err());
+    /// assert!(dt.with_nanosecond_checked(2_000_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn with_nanosecond_checked(&self, nano: u32) -> Result<DateTime<Tz>, ChronoError> {
+        if nano >= 1_000_000_000 && self.datetime.second() != 59 {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+        }
+
+        let datetime = self.datetime.with_nanosecond(nano)?;
+        Ok(DateTime { datetime, offset: self.offset.clone() })
+    }
+
+    /// Makes a new `DateTime` with the second number changed, allowing a
+    /// [leap second](./naive/struct.NaiveTime.html#leap-second-handling).
+    ///
+    /// Seconds up to 59 behave like [`Timelike::with_second`]. The second 60
+    /// is only accepted in the leap second position, at 23:59 UTC, and is
+    /// represented as second 59 with nanoseconds of 1,000,000,000 or more.
+    /// The fraction of the second is kept, and so is the offset of `self`.
+    ///
+    /// Returns `Err(ChronoError)` if `sec` is more than 60, if it is 60
+    /// anywhere but at 23:59 UTC, or if the offset has a seconds part, so the
+    /// local second 60 wouldn't line up with the UTC one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Timelike, Utc};
+    ///
+    /// let dt = Utc.ymd(2016, 12, 31)?.and_hms(23, 59, 30)?;
+    /// let leap = dt.with_second_checked(60)?;
+    /// assert_eq!(leap.to_string(), "2016-12-31 23:59:60 UTC");
+    /// assert_eq!((leap.second(), leap.nanosecond()), (59, 1_000_000_000));
+    ///
+    /// let dt = Utc.ymd(2016, 12, 31)?.and_hms(10, 0, 0)?;
+    /// assert!(dt.with_second_checked(60).is_err());
+    /// assert_eq!(dt.with_second_checked(59)?.to_string(), "2016-12-31 10:00:59 UTC");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn with_second_checked(&self, sec: u32) -> Result<DateTime<Tz>, ChronoError> {
+        if sec < 60 {
+            return self.with_second(sec);
+        }
+
+        let whole_minutes = self.offset.fix().local_minus_utc() % 60 == 0;
+        if sec > 60 || self.datetime.hour() != 23 || self.datetime.minute() != 59 || !whole_minutes
+        {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+        }
+
+        let nano = 1_000_000_000 + self.datetime.nanosecond() % 1_000_000_000;
+        let datetime = self.datetime.with_second(59)?.with_nanosecond(nano)?;
+        Ok(DateTime { datetime, offset: self.offset.clone() })
+    }
+
     /// Returns the number of non-leap seconds since January 1, 1970 0:00:00 UTC
     /// (aka "UNIX timestamp").
     #[inline]
@@ -221,6 +415,25 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_millis()
     }
 
+    /// Returns the number of non-leap-milliseconds since January 1, 1970 UTC,
+    /// or `None` if it doesn't fit in an `i64`.
+    ///
+    /// See [`NaiveDateTime::timestamp_millis_opt`] for the supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2001, 9, 9)?.and_hms_milli(1, 46, 40, 555)?;
+    /// assert_eq!(dt.timestamp_millis_opt(), Some(1_000_000_000_555));
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_millis_opt(&self) -> Option<i64> {
+        self.datetime.timestamp_millis_opt()
+    }
+
     /// Returns the number of non-leap-microseconds since January 1, 1970 UTC
     ///
     /// Note that this does reduce the number of years that can be represented
@@ -271,31 +484,119 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_nanos()
     }
 
-    /// Returns the number of milliseconds since the last second boundary
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC,
+    /// or `None` if it doesn't fit in an `i64`.
+    ///
+    /// See [`NaiveDateTime::timestamp_nanos_opt`] for the supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2001, 9, 9)?.and_hms_nano(1, 46, 40, 555)?;
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// let dt = Utc.ymd(3000, 1, 1)?.and_hms(0, 0, 0)?;
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        self.datetime.timestamp_nanos_opt()
+    }
+
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC,
+    /// as an `i128`.
+    ///
+    /// This never overflows, see [`TimeZone::timestamp_nanos_i128`] for the
+    /// reverse conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(5000, 1, 1)?.and_hms_nano(0, 0, 0, 1)?;
+    /// assert_eq!(dt.timestamp_nanos_i128(), 95_617_584_000_000_000_001);
+    /// assert_eq!(Utc.timestamp_nanos_i128(dt.timestamp_nanos_i128())?, dt);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_nanos_i128(&self) -> i128 {
+        self.datetime.timestamp_nanos_i128()
+    }
+
+    /// Returns the number of milliseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,
+    /// or for [leap seconds](./naive/struct.NaiveTime.html#leap-second-handling), to 1,999.
+    ///
+    /// Note that this is not the number of milliseconds since January 1, 1970 0:00:00 UTC.
     ///
-    /// warning: in event of a leap second, this may exceed 999
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
     ///
-    /// note: this is not the number of milliseconds since January 1, 1970 0:00:00 UTC
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2016, 7, 8)?.and_hms_nano(9, 10, 11, 123_456_789)?;
+    /// assert_eq!(dt.timestamp_subsec_millis(), 123);
+    ///
+    /// let dt = tz.ymd(2015, 7, 1)?.and_hms_nano(8, 59, 59, 1_234_567_890)?;
+    /// assert_eq!(dt.timestamp_subsec_millis(), 1_234);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn timestamp_subsec_millis(&self) -> u32 {
         self.datetime.timestamp_subsec_millis()
     }
 
-    /// Returns the number of microseconds since the last second boundary
+    /// Returns the number of microseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,999,
+    /// or for [leap seconds](./naive/struct.NaiveTime.html#leap-second-handling), to 1,999,999.
+    ///
+    /// Note that this is not the number of microseconds since January 1, 1970 0:00:00 UTC.
+    ///
+    /// # Example
     ///
-    /// warning: in event of a leap second, this may exceed 999_999
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2016, 7, 8)?.and_hms_nano(9, 10, 11, 123_456_789)?;
+    /// assert_eq!(dt.timestamp_subsec_micros(), 123_456);
     ///
-    /// note: this is not the number of microseconds since January 1, 1970 0:00:00 UTC
+    /// let dt = tz.ymd(2015, 7, 1)?.and_hms_nano(8, 59, 59, 1_234_567_890)?;
+    /// assert_eq!(dt.timestamp_subsec_micros(), 1_234_567);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn timestamp_subsec_micros(&self) -> u32 {
         self.datetime.timestamp_subsec_micros()
     }
 
-    /// Returns the number of nanoseconds since the last second boundary
+    /// Returns the number of nanoseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,999,999,
+    /// or for [leap seconds](./naive/struct.NaiveTime.html#leap-second-handling), to 1,999,999,999.
     ///
-    /// warning: in event of a leap second, this may exceed 999_999_999
+    /// Note that this is not the number of nanoseconds since January 1, 1970 0:00:00 UTC.
     ///
-    /// note: this is not the number of nanoseconds since January 1, 1970 0:00:00 UTC
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2016, 7, 8)?.and_hms_nano(9, 10, 11, 123_456_789)?;
+    /// assert_eq!(dt.timestamp_subsec_nanos(), 123_456_789);
+    ///
+    /// let dt = tz.ymd(2015, 7, 1)?.and_hms_nano(8, 59, 59, 1_234_567_890)?;
+    /// assert_eq!(dt.timestamp_subsec_nanos(), 1_234_567_890);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn timestamp_subsec_nanos(&self) -> u32 {
         self.datetime.timestamp_subsec_nanos()
@@ -308,6 +609,22 @@ impl<Tz: TimeZone> DateTime<Tz> {
     }
 
     /// Retrieves an associated time zone.
+    ///
+    /// The time zone is reconstructed from the stored offset through
+    /// [`TimeZone::from_offset`], and can be used to construct new values in
+    /// the same time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(9 * 3600).ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// let tz = dt.timezone();
+    /// assert_eq!(tz, FixedOffset::east(9 * 3600));
+    /// assert_eq!(tz.ymd(2022, 12, 24)?.and_hms(18, 0, 0)?.to_string(), "2022-12-24 18:00:00 +09:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn timezone(&self) -> Tz {
         TimeZone::from_offset(&self.offset)
@@ -327,9 +644,100 @@ impl<Tz: TimeZone> DateTime<Tz> {
         tz.from_utc_datetime_fixed(&self.datetime)
     }
 
+    /// Converts this `DateTime` into the same instant in [`Utc`].
+    ///
+    /// Unlike [`with_timezone(&Utc)`](#method.with_timezone) this can't fail,
+    /// since the UTC date and time is what a `DateTime` stores internally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(9 * 3600).ymd(2022, 6, 1)?.and_hms(9, 0, 0)?;
+    /// assert_eq!(dt.to_utc().to_string(), "2022-06-01 00:00:00 UTC");
+    /// assert_eq!(dt.to_utc().timestamp(), dt.timestamp());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn to_utc(&self) -> DateTime<Utc> {
+        DateTime { datetime: self.datetime, offset: Utc }
+    }
+
+    /// Converts this `DateTime` into one with a [`FixedOffset`], keeping both
+    /// the instant and the offset in effect for it.
+    ///
+    /// This is useful to store values of different time zones together, and
+    /// unlike [`with_timezone`](#method.with_timezone) it can't fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// let fixed: DateTime<FixedOffset> = dt.fixed_offset();
+    /// assert_eq!(fixed.offset(), &FixedOffset::east(0));
+    /// assert_eq!(fixed, dt);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn fixed_offset(&self) -> DateTime<FixedOffset> {
+        DateTime { datetime: self.datetime, offset: self.offset.fix() }
+    }
+
+    /// Compares the instants of two `DateTime`s, which may be in different
+    /// time zones.
+    ///
+    /// The `PartialEq` and `PartialOrd` implementations already compare
+    /// instants across time zones, but `Eq` and `Ord` are only implemented for
+    /// values with the same `Tz`. This returns a total [`Ordering`] for any
+    /// pair, which is handy with methods like `sort_by` or `max_by`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// let east = FixedOffset::east(2 * 3600).ymd(2022, 6, 1)?.and_hms(13, 0, 0)?;
+    ///
+    /// assert_eq!(utc.cmp_instant(&east), Ordering::Greater);
+    /// assert_eq!(east.cmp_instant(&utc), Ordering::Less);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn cmp_instant<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+
+    /// Returns `true` if both `DateTime`s denote the same instant, regardless
+    /// of their time zones and offsets.
+    ///
+    /// This is the same as comparing them with `==`, spelled out for clarity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// let east = FixedOffset::east(2 * 3600).ymd(2022, 6, 1)?.and_hms(14, 0, 0)?;
+    ///
+    /// assert!(utc.eq_instant(&east));
+    /// assert_ne!(utc.to_string(), east.to_string());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn eq_instant<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> bool {
+        self.datetime == other.datetime
+    }
+
     /// Adds given `Duration` to the current date and time.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// Returns `Err(ChronoError)` when it will result in overflow, or if the
+    /// time zone fails to resolve the new instant.
     #[inline]
     pub fn checked_add_signed(self, rhs: TimeDelta) -> Result<DateTime<Tz>, ChronoError> {
         let datetime = self.datetime.checked_add_signed(rhs)?;
@@ -337,6 +745,45 @@ impl<Tz: TimeZone> DateTime<Tz> {
         tz.from_utc_datetime(&datetime)
     }
 
+    /// Adds given `Duration` to the current date and time, and reports whether
+    /// the new local date and time is ambiguous in its time zone.
+    ///
+    /// The new instant is the same as with [`checked_add_signed`], but if its
+    /// local date and time also occurs at another instant, e.g. in the hour
+    /// repeated when daylight saving time ends, both instants are returned in
+    /// [`LocalResult::Ambiguous`], earliest first. This is useful to decide
+    /// whether the offset has to be displayed along with the local time.
+    ///
+    /// The local date and time is resolved with [`DateTime::from_local_all`].
+    /// If the time zone can't resolve it, only the new instant is returned.
+    ///
+    /// Returns `Err(ChronoError)` when it will result in overflow, or if the
+    /// time zone fails to resolve the new instant.
+    ///
+    /// [`checked_add_signed`]: DateTime::checked_add_signed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, LocalResult, TimeDelta, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 10, 30)?.and_hms(1, 30, 0)?;
+    /// let later = dt.add_signed_local(TimeDelta::hours(1))?;
+    /// assert_eq!(later, LocalResult::Single(dt + TimeDelta::hours(1)));
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn add_signed_local(
+        self,
+        rhs: TimeDelta,
+    ) -> Result<LocalResult<DateTime<Tz>>, ChronoError> {
+        let result = self.checked_add_signed(rhs)?;
+        match DateTime::from_local_all(result.naive_local(), &result.timezone()) {
+            Ok(local) => Ok(local),
+            // `result` is valid even if its local date and time can't be resolved
+            Err(_) => Ok(LocalResult::Single(result)),
+        }
+    }
+
     /// Adds given `Months` to the current date and time.
     ///
     /// Returns `None` when it will result in overflow, or if the
@@ -350,55 +797,322 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Subtracts given `Duration` from the current date and time.
     ///
-    /// Returns `None` when it will result in overflow.
-    #[inline]
-    pub fn checked_sub_signed(self, rhs: TimeDelta) -> Result<DateTime<Tz>, ChronoError> {
-        let datetime = self.datetime.checked_sub_signed(rhs)?;
-        let tz = self.timezone();
-        tz.from_utc_datetime(&datetime)
+    /// Returns `Err(ChronoError)` when it will result in overflow, or if the
+    /// time zone fails to resolve the new instant.
+    #[inline]
+    pub fn checked_sub_signed(self, rhs: TimeDelta) -> Result<DateTime<Tz>, ChronoError> {
+        let datetime = self.datetime.checked_sub_signed(rhs)?;
+        let tz = self.timezone();
+        tz.from_utc_datetime(&datetime)
+    }
+
+    /// Adds given `Duration` to the current date and time, saturating at the
+    /// earliest or latest representable date and time instead of failing.
+    ///
+    /// The offset of `self` is preserved as is; it is not looked up again for
+    /// the new instant. The bounds are chosen such that the local date and time
+    /// stays representable, so with a positive offset the result saturates
+    /// before [`NaiveDateTime::MAX`] in UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.saturating_add_signed(TimeDelta::hours(1)), dt + TimeDelta::hours(1));
+    /// assert_eq!(dt.saturating_add_signed(TimeDelta::max_value()), DateTime::<Utc>::MAX_UTC);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn saturating_add_signed(self, rhs: TimeDelta) -> DateTime<Tz> {
+        let (min, max) = self.saturating_bounds();
+
+        let datetime = match self.datetime.checked_add_signed(rhs) {
+            Ok(datetime) => datetime.max(min).min(max),
+            Err(_) if rhs < TimeDelta::zero() => min,
+            Err(_) => max,
+        };
+
+        DateTime { datetime, offset: self.offset }
+    }
+
+    /// Subtracts given `Duration` from the current date and time, saturating
+    /// at the earliest or latest representable date and time instead of
+    /// failing.
+    ///
+    /// Like [`saturating_add_signed`](#method.saturating_add_signed), the
+    /// offset of `self` is preserved as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.saturating_sub_signed(TimeDelta::hours(1)), dt - TimeDelta::hours(1));
+    /// assert_eq!(dt.saturating_sub_signed(TimeDelta::max_value()), DateTime::<Utc>::MIN_UTC);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn saturating_sub_signed(self, rhs: TimeDelta) -> DateTime<Tz> {
+        let (min, max) = self.saturating_bounds();
+
+        let datetime = match self.datetime.checked_sub_signed(rhs) {
+            Ok(datetime) => datetime.max(min).min(max),
+            Err(_) if rhs < TimeDelta::zero() => max,
+            Err(_) => min,
+        };
+
+        DateTime { datetime, offset: self.offset }
+    }
+
+    /// The range of UTC date and times for which the local date and time
+    /// with the current offset can be represented.
+    fn saturating_bounds(&self) -> (NaiveDateTime, NaiveDateTime) {
+        let offset = TimeDelta::seconds(i64::from(self.offset.fix().local_minus_utc()));
+
+        if offset < TimeDelta::zero() {
+            (NaiveDateTime::MIN - offset, NaiveDateTime::MAX)
+        } else {
+            (NaiveDateTime::MIN, NaiveDateTime::MAX - offset)
+        }
+    }
+
+    /// Subtracts given `Months` from the current date and time.
+    ///
+    /// Returns `None` when it will result in overflow, or if the
+    /// local time is not valid on the newly calculated date.
+    ///
+    /// See [`NaiveDate::checked_sub_months`] for more details on behavior
+    pub fn checked_sub_months(self, rhs: Months) -> Result<DateTime<Tz>, ChronoError> {
+        let dt = self.naive_local().checked_sub_months(rhs)?;
+        dt.and_local_timezone(Tz::from_offset(&self.offset))
+    }
+
+    /// Add a duration in [`Days`] to the date part of the `DateTime`
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    pub fn checked_add_days(self, days: Days) -> Result<Self, ChronoError> {
+        let dt = self.datetime.checked_add_days(days)?;
+        dt.and_local_timezone(TimeZone::from_offset(&self.offset))
+    }
+
+    /// Subtract a duration in [`Days`] from the date part of the `DateTime`
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    pub fn checked_sub_days(self, days: Days) -> Result<Self, ChronoError> {
+        let dt = self.datetime.checked_sub_days(days)?;
+        dt.and_local_timezone(TimeZone::from_offset(&self.offset))
+    }
+
+    /// Subtracts another `DateTime` from the current date and time.
+    /// This does not overflow or underflow at all.
+    #[inline]
+    pub fn signed_duration_since<Tz2: TimeZone>(self, rhs: DateTime<Tz2>) -> TimeDelta {
+        self.datetime.signed_duration_since(rhs.datetime)
+    }
+
+    /// Restricts the `DateTime` to the range from `min` to `max`, inclusive.
+    ///
+    /// The comparison is by instant, so the offsets don't matter. Returns
+    /// `min` or `max` themselves, with their own offsets, if `self` is outside
+    /// of the range.
+    ///
+    /// `min` must not be after `max`, which is only checked in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let min = Utc.ymd(2022, 1, 1)?.and_hms(0, 0, 0)?;
+    /// let max = Utc.ymd(2022, 12, 31)?.and_hms(23, 59, 59)?;
+    ///
+    /// let dt = Utc.ymd(2021, 6, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.clamp(min, max), min);
+    /// let dt = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.clamp(min, max), dt);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn clamp(self, min: DateTime<Tz>, max: DateTime<Tz>) -> DateTime<Tz> {
+        debug_assert!(min <= max, "`DateTime::clamp` called with `min` after `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the first instant of the local day of `self`.
+    ///
+    /// The boundary is computed in local wall-clock time and resolved against
+    /// the time zone again, so the offset may differ from the one of `self`.
+    /// If local midnight occurs twice, the earlier instant is returned. If it
+    /// is skipped by a transition, this rolls forward to the first instant
+    /// after the gap.
+    ///
+    /// Returns `Err(ChronoError)` if the result is out of range, or if the
+    /// time zone fails to resolve it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.start_of_day()?.to_string(), "2022-03-27 00:00:00 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn start_of_day(&self) -> Result<DateTime<Tz>, ChronoError> {
+        self.start_of_local_date(self.naive_local().date())
+    }
+
+    /// Returns the last instant of the local day of `self`, which is one
+    /// nanosecond before the start of the next local day.
+    ///
+    /// This is usually `23:59:59.999999999`. See [`DateTime::start_of_day`]
+    /// for how the boundary is resolved against the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.end_of_day()?.to_string(), "2022-03-27 23:59:59.999999999 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn end_of_day(&self) -> Result<DateTime<Tz>, ChronoError> {
+        self.end_before_local_date(self.naive_local().date().succ()?)
+    }
+
+    /// Returns the first instant of the local month of `self`.
+    ///
+    /// See [`DateTime::start_of_day`] for how the boundary is resolved
+    /// against the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.start_of_month()?.to_string(), "2022-03-01 00:00:00 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn start_of_month(&self) -> Result<DateTime<Tz>, ChronoError> {
+        self.start_of_local_date(self.naive_local().date().with_day(1)?)
+    }
+
+    /// Returns the last instant of the local month of `self`, which is one
+    /// nanosecond before the start of the next local month.
+    ///
+    /// See [`DateTime::start_of_day`] for how the boundary is resolved
+    /// against the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2024, 2, 10)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.end_of_month()?.to_string(), "2024-02-29 23:59:59.999999999 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn end_of_month(&self) -> Result<DateTime<Tz>, ChronoError> {
+        let first = self.naive_local().date().with_day(1)?;
+        self.end_before_local_date(first.checked_add_months(Months::new(1))?)
     }
 
-    /// Subtracts given `Months` from the current date and time.
+    /// Returns the first instant of the local year of `self`.
     ///
-    /// Returns `None` when it will result in overflow, or if the
-    /// local time is not valid on the newly calculated date.
+    /// See [`DateTime::start_of_day`] for how the boundary is resolved
+    /// against the time zone.
     ///
-    /// See [`NaiveDate::checked_sub_months`] for more details on behavior
-    pub fn checked_sub_months(self, rhs: Months) -> Result<DateTime<Tz>, ChronoError> {
-        let dt = self.naive_local().checked_sub_months(rhs)?;
-        dt.and_local_timezone(Tz::from_offset(&self.offset))
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.start_of_year()?.to_string(), "2022-01-01 00:00:00 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn start_of_year(&self) -> Result<DateTime<Tz>, ChronoError> {
+        self.start_of_local_date(NaiveDate::from_ymd(self.naive_local().year(), 1, 1)?)
     }
 
-    /// Add a duration in [`Days`] to the date part of the `DateTime`
+    /// Returns the last instant of the local year of `self`, which is one
+    /// nanosecond before the start of the next local year.
     ///
-    /// Returns `None` if the resulting date would be out of range.
-    pub fn checked_add_days(self, days: Days) -> Result<Self, ChronoError> {
-        let dt = self.datetime.checked_add_days(days)?;
-        dt.and_local_timezone(TimeZone::from_offset(&self.offset))
+    /// See [`DateTime::start_of_day`] for how the boundary is resolved
+    /// against the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 3, 27)?.and_hms(13, 15, 0)?;
+    /// assert_eq!(dt.end_of_year()?.to_string(), "2022-12-31 23:59:59.999999999 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn end_of_year(&self) -> Result<DateTime<Tz>, ChronoError> {
+        self.end_before_local_date(NaiveDate::from_ymd(self.naive_local().year() + 1, 1, 1)?)
     }
 
-    /// Subtract a duration in [`Days`] from the date part of the `DateTime`
-    ///
-    /// Returns `None` if the resulting date would be out of range.
-    pub fn checked_sub_days(self, days: Days) -> Result<Self, ChronoError> {
-        let dt = self.datetime.checked_sub_days(days)?;
-        dt.and_local_timezone(TimeZone::from_offset(&self.offset))
+    /// Returns the first instant of the given local date in the time zone of
+    /// `self`.
+    fn start_of_local_date(&self, date: NaiveDate) -> Result<DateTime<Tz>, ChronoError> {
+        resolve_local_earliest(&self.timezone(), date.and_hms(0, 0, 0)?)
     }
 
-    /// Subtracts another `DateTime` from the current date and time.
-    /// This does not overflow or underflow at all.
-    #[inline]
-    pub fn signed_duration_since<Tz2: TimeZone>(self, rhs: DateTime<Tz2>) -> TimeDelta {
-        self.datetime.signed_duration_since(rhs.datetime)
+    /// Returns the instant one nanosecond before the given local date starts
+    /// in the time zone of `self`.
+    fn end_before_local_date(&self, date: NaiveDate) -> Result<DateTime<Tz>, ChronoError> {
+        self.start_of_local_date(date)?.checked_sub_signed(TimeDelta::nanoseconds(1))
     }
 
     /// Returns a view to the naive UTC datetime.
+    ///
+    /// This is the value the `DateTime` stores, so it is independent of the
+    /// offset: two `DateTime`s denoting the same instant have the same
+    /// `naive_utc`, regardless of their time zones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+    /// let dt = ist.ymd(2022, 6, 1)?.and_hms(9, 0, 0)?;
+    /// assert_eq!(dt.naive_utc(), NaiveDate::from_ymd(2022, 6, 1)?.and_hms(3, 30, 0)?);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn naive_utc(&self) -> NaiveDateTime {
         self.datetime
     }
 
     /// Returns a view to the naive local datetime.
+    ///
+    /// This is the wall clock time in the time zone of the `DateTime`, computed
+    /// as [`naive_utc`](#method.naive_utc) plus the offset. Offsets are
+    /// positive east of Greenwich, so `naive_local` is ahead of `naive_utc`
+    /// for an offset like `+05:30` and behind it for an offset like `-08:00`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, TimeDelta, TimeZone};
+    ///
+    /// let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+    /// let dt = ist.ymd(2022, 6, 1)?.and_hms(9, 0, 0)?;
+    /// assert_eq!(dt.naive_local(), NaiveDate::from_ymd(2022, 6, 1)?.and_hms(9, 0, 0)?);
+    /// assert_eq!(dt.naive_local() - dt.naive_utc(), TimeDelta::minutes(5 * 60 + 30));
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[inline]
     pub fn naive_local(&self) -> NaiveDateTime {
         self.datetime + self.offset.fix()
@@ -421,12 +1135,257 @@ impl<Tz: TimeZone> DateTime<Tz> {
         }
     }
 
+    /// Returns the local date and time as a `(year, month, day, hour, minute,
+    /// second, nanosecond)` tuple, as database drivers commonly want them.
+    ///
+    /// The nanosecond is above 999,999,999 during a
+    /// [leap second](./naive/struct.NaiveTime.html#leap-second-handling).
+    /// [`TimeZone::from_sql_parts`] is the reverse conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2022, 6, 15)?.and_hms_nano(18, 59, 36, 500)?;
+    /// assert_eq!(dt.to_sql_parts(), (2022, 6, 15, 18, 59, 36, 500));
+    /// assert_eq!(tz.from_sql_parts(dt.to_sql_parts())?, dt);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn to_sql_parts(&self) -> (i32, u32, u32, u32, u32, u32, u32) {
+        let local = self.naive_local();
+        (
+            local.year(),
+            local.month(),
+            local.day(),
+            local.hour(),
+            local.minute(),
+            local.second(),
+            local.nanosecond(),
+        )
+    }
+
+    /// Returns a single field of the local date and time.
+    ///
+    /// See [`DateField`] for the range of each field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateField, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2022, 6, 15)?.and_hms(18, 59, 36)?;
+    /// let fields = [DateField::Year, DateField::Month, DateField::Day, DateField::Hour];
+    /// let values: Vec<_> = fields.iter().map(|&field| dt.get_field(field)).collect();
+    /// assert_eq!(values, [2022, 6, 15, 18]);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn get_field(&self, field: DateField) -> i64 {
+        let local = self.naive_local();
+        match field {
+            DateField::Year => i64::from(local.year()),
+            DateField::Month => i64::from(local.month()),
+            DateField::Day => i64::from(local.day()),
+            DateField::Hour => i64::from(local.hour()),
+            DateField::Minute => i64::from(local.minute()),
+            DateField::Second => i64::from(local.second()),
+            DateField::Nanosecond => i64::from(local.nanosecond()),
+            DateField::Ordinal => i64::from(local.ordinal()),
+            DateField::Weekday => i64::from(local.weekday().number_from_monday()),
+            DateField::IsoWeek => i64::from(local.iso_week().week()),
+        }
+    }
+
+    /// Returns an iterator yielding `self`, `self + step`, `self + 2 * step`
+    /// and so on.
+    ///
+    /// Each value is computed from the previous one with
+    /// [`checked_add_signed`](#method.checked_add_signed), so the offset is
+    /// recomputed for every step, e.g. across daylight saving time transitions.
+    /// The iterator ends instead of overflowing at the end of the supported
+    /// range. A negative `step` steps backwards in time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd(2022, 6, 1)?.and_hms(22, 0, 0)?;
+    /// let hours: Vec<_> = start.step_by_duration(TimeDelta::hours(1)).take(3).collect();
+    /// assert_eq!(hours[0].to_string(), "2022-06-01 22:00:00 UTC");
+    /// assert_eq!(hours[2].to_string(), "2022-06-02 00:00:00 UTC");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn step_by_duration(self, step: TimeDelta) -> DateTimeStepIterator<Tz> {
+        assert!(!step.is_zero(), "DateTime::step_by_duration step must not be zero");
+        DateTimeStepIterator { next: Some(self), end: None, step }
+    }
+
+    /// Returns an iterator yielding `self`, `self + step`, `self + 2 * step`
+    /// and so on, as long as the values are before `end`.
+    ///
+    /// `end` itself is excluded. If `step` is negative, the values step
+    /// backwards and have to be after `end` instead. See
+    /// [`step_by_duration`](#method.step_by_duration) for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd(2022, 6, 1)?.and_hms(0, 0, 0)?;
+    /// let end = Utc.ymd(2022, 6, 2)?.and_hms(0, 0, 0)?;
+    /// assert_eq!(start.step_by_duration_until(end, TimeDelta::hours(1)).count(), 24);
+    /// assert_eq!(end.step_by_duration_until(start, TimeDelta::hours(-6)).count(), 4);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn step_by_duration_until(
+        self,
+        end: DateTime<Tz>,
+        step: TimeDelta,
+    ) -> DateTimeStepIterator<Tz> {
+        assert!(!step.is_zero(), "DateTime::step_by_duration_until step must not be zero");
+        DateTimeStepIterator { next: Some(self), end: Some(end), step }
+    }
+
     /// The minimum possible `DateTime<Utc>`.
     pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
     /// The maximum possible `DateTime<Utc>`.
     pub const MAX_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MAX, offset: Utc };
 }
 
+/// Iterator over `DateTime` with a fixed step size.
+///
+/// This is created by [`DateTime::step_by_duration`] and
+/// [`DateTime::step_by_duration_until`].
+#[derive(Clone, Debug)]
+pub struct DateTimeStepIterator<Tz: TimeZone> {
+    next: Option<DateTime<Tz>>,
+    end: Option<DateTime<Tz>>,
+    step: TimeDelta,
+}
+
+impl<Tz: TimeZone> Iterator for DateTimeStepIterator<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let current = self.next.take()?;
+        if let Some(end) = &self.end {
+            let before_end =
+                if self.step > TimeDelta::zero() { current < *end } else { current > *end };
+            if !before_end {
+                return None;
+            }
+        }
+        // on overflow `self.next` stays `None`, which ends the iteration
+        self.next = current.clone().checked_add_signed(self.step).ok();
+        Some(current)
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for DateTimeStepIterator<Tz> {}
+
+/// Wraps a [`DateTime`] to compare and hash it by its instant in UTC only.
+///
+/// For `DateTime` this gives the same results as its own `PartialEq` and
+/// `Hash` implementations, which already ignore the offset. The difference is
+/// that `ByInstant` makes this explicit in the type, like in the keys of a
+/// `HashSet` deduplicating instants from several offsets, and that it only
+/// compares values with the same time zone type. The wrapped value keeps its
+/// own offset.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{ByInstant, FixedOffset, TimeZone};
+/// use std::collections::HashSet;
+///
+/// let utc = FixedOffset::east(0).ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+/// let ist = FixedOffset::east(5 * 3600 + 1800).ymd(2022, 6, 1)?.and_hms(17, 30, 0)?;
+///
+/// let set: HashSet<_> = [ByInstant(utc), ByInstant(ist)].iter().cloned().collect();
+/// assert_eq!(set.len(), 1);
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ByInstant<T>(pub T);
+
+impl<Tz: TimeZone> PartialEq for ByInstant<DateTime<Tz>> {
+    fn eq(&self, other: &ByInstant<DateTime<Tz>>) -> bool {
+        self.0.naive_utc() == other.0.naive_utc()
+    }
+}
+
+impl<Tz: TimeZone> Eq for ByInstant<DateTime<Tz>> {}
+
+impl<Tz: TimeZone> hash::Hash for ByInstant<DateTime<Tz>> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.naive_utc().hash(state)
+    }
+}
+
+impl DateTime<Utc> {
+    /// Makes a new `DateTime<Utc>` from the number of non-leap seconds since
+    /// January 1, 1970 0:00:00 UTC (aka "UNIX timestamp") and the number of
+    /// nanoseconds since the last whole non-leap second.
+    ///
+    /// This is the same as [`Utc.timestamp(secs, nsecs)`](TimeZone::timestamp),
+    /// without having to import [`TimeZone`].
+    ///
+    /// Returns `Err(ChronoError)` on out-of-range number of seconds and/or
+    /// invalid nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let dt = DateTime::<Utc>::from_timestamp(1431648000, 0)?;
+    /// assert_eq!(dt.to_string(), "2015-05-15 00:00:00 UTC");
+    ///
+    /// assert!(DateTime::<Utc>::from_timestamp(0, 2_000_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn from_timestamp(secs: i64, nsecs: u32) -> Result<DateTime<Utc>, ChronoError> {
+        Utc.timestamp(secs, nsecs)
+    }
+
+    /// Makes a new `DateTime<Utc>` from the number of non-leap milliseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// This is the same as [`Utc.timestamp_millis(millis)`](TimeZone::timestamp_millis),
+    /// without having to import [`TimeZone`].
+    ///
+    /// Returns `Err(ChronoError)` on out-of-range number of milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let dt = DateTime::<Utc>::from_timestamp_millis(1431648000123)?;
+    /// assert_eq!(dt.to_string(), "2015-05-15 00:00:00.123 UTC");
+    ///
+    /// let dt = DateTime::<Utc>::from_timestamp_millis(-1)?;
+    /// assert_eq!(dt.to_string(), "1969-12-31 23:59:59.999 UTC");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn from_timestamp_millis(millis: i64) -> Result<DateTime<Utc>, ChronoError> {
+        Utc.timestamp_millis(millis)
+    }
+}
+
 impl Default for DateTime<Utc> {
     fn default() -> Self {
         Utc.from_utc_datetime_fixed(&NaiveDateTime::default())
@@ -534,6 +1493,50 @@ where
     dt.timezone().from_local_datetime(&datetime)
 }
 
+/// Resolves the local datetime to the earliest matching instant.
+///
+/// If the time zone still reports the local datetime as ambiguous, the larger
+/// of the offsets a day before and after it gives the earlier instant.
+///
+/// If the local datetime falls into a gap, this rolls forward to the
+/// transition ending the gap, which is the first instant after it. Time zones
+/// which don't know their transitions shift the local datetime forward by the
+/// length of the gap instead.
+fn resolve_local_earliest<Tz: TimeZone>(
+    tz: &Tz,
+    local: NaiveDateTime,
+) -> Result<DateTime<Tz>, ChronoError> {
+    match tz.offset_from_local_datetime_all(&local) {
+        Ok(LocalResult::Single(offset)) | Ok(LocalResult::Ambiguous(offset, _)) => {
+            return Ok(DateTime::from_utc(local.checked_sub_offset(offset.fix())?, offset));
+        }
+        Err(e) if e == ChronoError::new(ChronoErrorKind::AmbiguousDate) => {
+            let day = TimeDelta::days(1);
+            let before = tz.offset_from_utc_datetime(&local.checked_sub_signed(day)?)?.fix();
+            let after = tz.offset_from_utc_datetime(&local.checked_add_signed(day)?)?.fix();
+            let offset =
+                if before.local_minus_utc() > after.local_minus_utc() { before } else { after };
+            return tz.from_utc_datetime(&local.checked_sub_offset(offset)?);
+        }
+        Err(e) if e != ChronoError::new(ChronoErrorKind::InvalidDateTime) => return Err(e),
+        Err(_) => {}
+    }
+
+    // Interpreted with the offset in effect before the gap, the local
+    // datetime denotes an instant after the transition. It is the transition
+    // itself if the gap starts exactly at `local`.
+    let before = local.checked_sub_signed(TimeDelta::days(1))?;
+    let offset = tz.offset_from_utc_datetime(&before)?;
+    let utc = local.checked_sub_offset(offset.fix())?;
+
+    let utc = match tz.next_transition(&DateTime::from_utc(before, Utc)) {
+        Some(transition) if transition.naive_utc() <= utc => transition.naive_utc(),
+        _ => utc,
+    };
+
+    tz.from_utc_datetime(&utc)
+}
+
 impl DateTime<FixedOffset> {
     /// Parses an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`,
     /// then returns a new [`DateTime`] with a parsed [`FixedOffset`].
@@ -596,13 +1599,58 @@ impl DateTime<FixedOffset> {
         parse(&mut parsed, s, StrftimeItems::new(fmt))?;
         parsed.to_datetime()
     }
+
+    /// Parses a string from a user-specified format into a new
+    /// `DateTime<FixedOffset>` value, and returns the slice of the string
+    /// that remains after parsing.
+    ///
+    /// Unlike [`DateTime::parse_from_str`] this doesn't require the format
+    /// to consume the entire string, so it can be used to parse a prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let (dt, remainder) = DateTime::parse_and_remainder(
+    ///     "2015-02-18 23:16:09 +0200 trailing text", "%Y-%m-%d %H:%M:%S %z")?;
+    /// assert_eq!(dt, FixedOffset::east(2 * 3600).ymd(2015, 2, 18)?.and_hms(23, 16, 9)?);
+    /// assert_eq!(remainder, " trailing text");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_and_remainder<'a>(
+        s: &'a str,
+        fmt: &str,
+    ) -> ParseResult<(DateTime<FixedOffset>, &'a str)> {
+        let mut parsed = Parsed::new();
+        let remainder = parse_and_remainder(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.to_datetime().map(|d| (d, remainder))
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz>
 where
     Tz::Offset: fmt::Display,
 {
-    /// Returns an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`.
+    /// Returns an RFC 2822 date and time string such as `Tue, 01 Jul 2003 10:52:37 +0200`.
+    ///
+    /// The day is always two digits, and the offset is always numeric, even
+    /// if the offset of the time zone is displayed as a name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, PosixTz, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(2 * 3600).ymd(2003, 7, 1)?.and_hms(10, 52, 37)?;
+    /// assert_eq!(dt.to_rfc2822(), "Tue, 01 Jul 2003 10:52:37 +0200");
+    ///
+    /// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+    /// let dt = tz.ymd(2003, 7, 1)?.and_hms(10, 52, 37)?;
+    /// assert_eq!(dt.to_string(), "2003-07-01 10:52:37 EDT");
+    /// assert_eq!(dt.to_rfc2822(), "Tue, 01 Jul 2003 10:52:37 -0400");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
     #[cfg(any(feature = "alloc", feature = "std", test))]
     #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
     pub fn to_rfc2822(&self) -> String {
@@ -720,6 +1768,63 @@ where
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Writes the combined date and time with the specified formatting items
+    /// into `w`.
+    ///
+    /// Unlike [`DateTime::format_with_items`] this doesn't allocate, so it is
+    /// available without the `alloc` feature. The output isn't padded or
+    /// aligned as with the width of a `{:>30}` format argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use chrono::format::StrftimeItems;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2017, 4, 2)?.and_hms(12, 50, 32)?;
+    ///
+    /// let mut buf = String::new();
+    /// dt.write_to(&mut buf, StrftimeItems::new("%d/%m/%Y %H:%M"))?;
+    /// write!(buf, " and more")?;
+    /// assert_eq!(buf, "02/04/2017 12:50 and more");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<'a, W, I, B>(&self, w: &mut W, items: I) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: Iterator<Item = B>,
+        B: Borrow<Item<'a>>,
+    {
+        let local = self.naive_local();
+        let name = OffsetName(&self.offset);
+        let off = (&name as &dyn fmt::Display, self.offset.fix());
+        write_items(w, Some(&local.date()), Some(&local.time()), Some(off), items)
+    }
+
+    /// Writes an RFC 3339 and ISO 8601 date and time string such as
+    /// `1996-12-19T16:39:57-08:00` into `w`.
+    ///
+    /// This writes the same as [`DateTime::to_rfc3339`] returns, without
+    /// allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::west(8 * 3600).ymd(1996, 12, 19)?.and_hms(16, 39, 57)?;
+    ///
+    /// let mut buf = String::new();
+    /// dt.write_rfc3339(&mut buf)?;
+    /// assert_eq!(buf, "1996-12-19T16:39:57-08:00");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_rfc3339<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC3339)];
+        self.write_to(w, ITEMS.iter())
+    }
+
     /// Formats the combined date and time with the specified formatting items and locale.
     #[cfg(feature = "unstable-locales")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable-locales")))]
@@ -746,6 +1851,10 @@ where
     /// Formats the combined date and time with the specified format string and
     /// locale.
     ///
+    /// The day and month names as well as the locale's date and time
+    /// representations (e.g. `%c` and `%x`) are localized, while numeric and
+    /// offset specifiers are formatted the same regardless of the locale.
+    ///
     /// See the [`crate::format::strftime`] module on the supported escape
     /// sequences.
     #[cfg(feature = "unstable-locales")]
@@ -760,6 +1869,18 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl arbitrary::Arbitrary<'_> for DateTime<Utc> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<DateTime<Utc>> {
+        Ok(DateTime::from_utc(u.arbitrary()?, Utc))
+    }
+}
+
+/// The `with_*` methods replace a field of the local date while keeping the
+/// local time, and then look up the offset for the new local date and time.
+/// They return `Err` if it doesn't exist or is ambiguous in the time zone, like
+/// the local times skipped or repeated by daylight saving time transitions.
 impl<Tz: TimeZone> Datelike for DateTime<Tz> {
     #[inline]
     fn year(&self) -> i32 {
@@ -907,12 +2028,34 @@ impl<Tz: TimeZone, Tz2: TimeZone> PartialOrd<DateTime<Tz2>> for DateTime<Tz> {
     }
 }
 
+/// Orders by the instant in UTC, like the `PartialOrd` implementation.
+///
+/// A [leap second](./naive/struct.NaiveTime.html#leap-second-handling) sorts after
+/// the second it extends, and before the following second.
 impl<Tz: TimeZone> Ord for DateTime<Tz> {
     fn cmp(&self, other: &DateTime<Tz>) -> Ordering {
         self.datetime.cmp(&other.datetime)
     }
 }
 
+/// Hashes the instant in time, ignoring the offset.
+///
+/// This is consistent with [`PartialEq`], so `DateTime`s denoting the same
+/// instant in different offsets collapse into one entry in a `HashSet`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use std::collections::HashSet;
+///
+/// let utc = FixedOffset::east(0).ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+/// let ist = FixedOffset::east(5 * 3600 + 1800).ymd(2022, 6, 1)?.and_hms(17, 30, 0)?;
+///
+/// let set: HashSet<_> = [utc, ist].iter().cloned().collect();
+/// assert_eq!(set.len(), 1);
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
 impl<Tz: TimeZone> hash::Hash for DateTime<Tz> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.datetime.hash(state)
@@ -1033,6 +2176,48 @@ impl str::FromStr for DateTime<Utc> {
     }
 }
 
+/// Parses a string with the relaxed form of RFC3339 accepted by [`str::parse`],
+/// keeping the parsed offset.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset};
+/// use std::convert::TryFrom;
+///
+/// let dt = DateTime::<FixedOffset>::try_from("2020-01-01T00:00:00+05:30")?;
+/// assert_eq!(dt.offset(), &FixedOffset::east(19800));
+/// # Ok::<_, chrono::ParseError>(())
+/// ```
+impl<'a> TryFrom<&'a str> for DateTime<FixedOffset> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> ParseResult<DateTime<FixedOffset>> {
+        s.parse()
+    }
+}
+
+/// Parses a string with the relaxed form of RFC3339 accepted by [`str::parse`],
+/// converting a non-zero offset into UTC.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use std::convert::TryInto;
+///
+/// let dt: DateTime<Utc> = "2020-01-01T00:00:00+05:30".try_into()?;
+/// assert_eq!(dt, Utc.ymd(2019, 12, 31)?.and_hms(18, 30, 0)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+impl<'a> TryFrom<&'a str> for DateTime<Utc> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> ParseResult<DateTime<Utc>> {
+        s.parse()
+    }
+}
+
 /// Accepts a relaxed form of RFC3339.
 /// A space or a 'T' are acepted as the separator between the date and time
 /// parts. Additional spaces are allowed between each component.
@@ -1055,6 +2240,20 @@ impl str::FromStr for DateTime<Local> {
     }
 }
 
+/// Converts a `SystemTime` into a `DateTime<Utc>`.
+///
+/// A time before the Unix epoch results in a negative timestamp.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use chrono::{DateTime, TimeZone, Utc};
+///
+/// let dt = DateTime::<Utc>::from(UNIX_EPOCH - Duration::from_millis(1500));
+/// assert_eq!(dt, Utc.ymd(1969, 12, 31)?.and_hms_milli(23, 59, 58, 500)?);
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
 #[cfg(any(feature = "std", test))]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<SystemTime> for DateTime<Utc> {
@@ -1087,6 +2286,19 @@ impl From<SystemTime> for DateTime<Local> {
     }
 }
 
+/// Converts a `DateTime` into a `SystemTime`, which works for any instant
+/// before or after the Unix epoch.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.ymd(1969, 12, 31)?.and_hms_milli(23, 59, 58, 500)?;
+/// assert_eq!(SystemTime::from(dt), UNIX_EPOCH - Duration::from_millis(1500));
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
 #[cfg(any(feature = "std", test))]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl<Tz: TimeZone> From<DateTime<Tz>> for SystemTime {
@@ -1100,6 +1100,505 @@ pub mod ts_seconds_option {
     }
 }
 
+/// Ser/de to/from RFC 3339 strings
+///
+/// Unlike the default `Serialize` implementation, this always writes the offset
+/// in the `+00:00` form, and deserializing is strict about the RFC 3339 syntax.
+/// Any `DateTime` can be serialized, while deserializing supports
+/// `DateTime<Utc>` and `DateTime<FixedOffset>`.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc3339;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc3339")]
+///     created: DateTime<Utc>,
+///     #[serde(with = "rfc3339")]
+///     local: DateTime<FixedOffset>,
+/// }
+///
+/// let created = Utc.ymd(2018, 5, 17)?.and_hms_milli(2, 4, 59, 918)?;
+/// let local = FixedOffset::east(2 * 3600).ymd(2018, 5, 17)?.and_hms(4, 4, 59)?;
+/// let my_s = S { created, local };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(
+///     as_string,
+///     r#"{"created":"2018-05-17T02:04:59.918+00:00","local":"2018-05-17T04:04:59+02:00"}"#
+/// );
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.created, created);
+/// assert_eq!(my_s.local.offset(), local.offset());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub mod rfc3339 {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{de, ser};
+
+    use crate::offset::{FixedOffset, TimeZone};
+    use crate::DateTime;
+
+    /// Serialize a datetime into an RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc3339::serialize as to_rfc3339;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s = S {
+    ///     time: Utc.ymd(2015, 5, 15)?.and_hms(10, 0, 0)?,
+    /// };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00+00:00"}"#);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn serialize<Tz, S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&dt.to_rfc3339())
+    }
+
+    /// Deserialize a `DateTime` from an RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc3339::deserialize as from_rfc3339;
+    /// #[derive(Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "2015-05-15T12:00:00+02:00" }"#)?;
+    /// assert_eq!(my_s.time.to_string(), "2015-05-15 10:00:00 UTC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        d.deserialize_str(Rfc3339Visitor(PhantomData))
+    }
+
+    pub(super) struct Rfc3339Visitor<Tz>(pub(super) PhantomData<Tz>);
+
+    impl<'de, Tz> de::Visitor<'de> for Rfc3339Visitor<Tz>
+    where
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        type Value = DateTime<Tz>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC 3339 formatted date and time string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::parse_from_rfc3339(value).map(DateTime::from).map_err(E::custom)
+        }
+    }
+
+    /// Ser/de to/from optional RFC 3339 strings
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::rfc3339;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "rfc3339::option")]
+    ///     time: Option<DateTime<Utc>>
+    /// }
+    ///
+    /// let my_s = S { time: None };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":null}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, None);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub mod option {
+        use core::fmt;
+        use core::marker::PhantomData;
+        use serde::{de, ser};
+
+        use super::Rfc3339Visitor;
+        use crate::offset::{FixedOffset, TimeZone};
+        use crate::DateTime;
+
+        /// Serialize an optional datetime into an RFC 3339 string or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<Tz, S>(
+            opt: &Option<DateTime<Tz>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            Tz: TimeZone,
+            Tz::Offset: fmt::Display,
+            S: ser::Serializer,
+        {
+            match *opt {
+                Some(ref dt) => serializer.serialize_some(dt.to_rfc3339().as_str()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserialize an optional `DateTime` from an RFC 3339 string or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+            Tz: TimeZone,
+            DateTime<Tz>: From<DateTime<FixedOffset>>,
+        {
+            d.deserialize_option(OptionRfc3339Visitor(PhantomData))
+        }
+
+        struct OptionRfc3339Visitor<Tz>(PhantomData<Tz>);
+
+        impl<'de, Tz> de::Visitor<'de> for OptionRfc3339Visitor<Tz>
+        where
+            Tz: TimeZone,
+            DateTime<Tz>: From<DateTime<FixedOffset>>,
+        {
+            type Value = Option<DateTime<Tz>>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an RFC 3339 formatted date and time string or none")
+            }
+
+            /// Deserialize an RFC 3339 string
+            fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                d.deserialize_str(Rfc3339Visitor(PhantomData)).map(Some)
+            }
+
+            /// Deserialize a missing value
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
+            /// Deserialize a missing value
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Ser/de to/from either timestamps in seconds or RFC 3339 strings
+///
+/// Deserializing accepts an integer number of seconds since the epoch, a
+/// float which is treated as seconds with a fractional part, or an RFC 3339
+/// string. The fraction is rounded to the nearest nanosecond, which is only
+/// as precise as the `f64` itself. Serializing always writes an RFC 3339 string.
+///
+/// This relies on the input being self-describing, like JSON is, so it
+/// can't be used with formats like bincode.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{TimeZone, DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::flexible;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "flexible")]
+///     time: DateTime<Utc>
+/// }
+///
+/// let time = Utc.ymd(2021, 1, 1)?.and_hms(0, 0, 0)?;
+/// let from_int: S = serde_json::from_str(r#"{"time":1609459200}"#)?;
+/// let from_str: S = serde_json::from_str(r#"{"time":"2021-01-01T00:00:00Z"}"#)?;
+/// assert_eq!(from_int.time, time);
+/// assert_eq!(from_str.time, time);
+///
+/// let as_string = serde_json::to_string(&from_int)?;
+/// assert_eq!(as_string, r#"{"time":"2021-01-01T00:00:00Z"}"#);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub mod flexible {
+    use core::{fmt, i64};
+    use serde::{de, ser};
+
+    use crate::offset::TimeZone;
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into an RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::flexible::serialize as to_flexible;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_flexible")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s = S {
+    ///     time: Utc.ymd(2015, 5, 15)?.and_hms(10, 0, 0)?,
+    /// };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00Z"}"#);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        ser::Serialize::serialize(dt, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a timestamp in seconds or an RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::flexible::deserialize as from_flexible;
+    /// #[derive(Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_flexible")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000.5 }"#)?;
+    /// assert_eq!(my_s.time.to_string(), "2015-05-15 10:00:00.500 UTC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_any(FlexibleVisitor)
+    }
+
+    struct FlexibleVisitor;
+
+    impl<'de> de::Visitor<'de> for FlexibleVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds or an RFC 3339 formatted string")
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Utc.timestamp(value, 0).map_err(E::custom)
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if value > i64::MAX as u64 {
+                return Err(E::invalid_value(de::Unexpected::Unsigned(value), &self));
+            }
+            Utc.timestamp(value as i64, 0).map_err(E::custom)
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch, with a fractional part
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // the range check also rejects NaN and infinities
+            if !(value >= i64::MIN as f64 && value < i64::MAX as f64) {
+                return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+            }
+            // `f64::floor` isn't available without `std`
+            let mut secs = value as i64;
+            if secs as f64 > value {
+                secs -= 1;
+            }
+            // round to the nearest nanosecond, which can carry into the seconds
+            let mut nsecs = ((value - secs as f64) * 1e9 + 0.5) as u32;
+            if nsecs >= 1_000_000_000 {
+                secs += 1;
+                nsecs = 0;
+            }
+            Utc.timestamp(secs, nsecs).map_err(E::custom)
+        }
+
+        /// Deserialize an RFC 3339 string
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::parse_from_rfc3339(value).map(DateTime::from).map_err(E::custom)
+        }
+    }
+}
+
+/// Ser/de to/from a compact timestamp in formats which aren't human-readable
+///
+/// Formats like bincode get a `(i64, u32)` tuple of the seconds and
+/// nanoseconds since the epoch, which is smaller than a string. Human-readable
+/// formats like JSON still get an RFC 3339 string, like with the default
+/// implementations.
+///
+/// The tuple can't hold an offset, so only `DateTime<Utc>` is supported. The
+/// binary form isn't compatible with the default implementations, so data
+/// written with this module has to be read back with it.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{TimeZone, DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::compact;
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "compact")]
+///     time: DateTime<Utc>
+/// }
+///
+/// let my_s = S {
+///     time: Utc.ymd(2018, 5, 17)?.and_hms_nano(02, 04, 59, 918355733)?,
+/// };
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"2018-05-17T02:04:59.918355733Z"}"#);
+/// let encoded = bincode::serialize(&my_s)?;
+/// assert_eq!(encoded, bincode::serialize(&(1526522699i64, 918355733u32))?);
+/// assert_eq!(bincode::deserialize::<S>(&encoded)?, my_s);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub mod compact {
+    use serde::{de, ser};
+
+    use crate::offset::TimeZone;
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into a timestamp tuple, or an RFC 3339 string
+    /// for human-readable formats
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::compact::serialize as to_compact;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_compact")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s = S {
+    ///     time: Utc.ymd(2015, 5, 15)?.and_hms(10, 0, 0)?,
+    /// };
+    /// let encoded = bincode::serialize(&my_s)?;
+    /// assert_eq!(encoded, bincode::serialize(&(1431684000i64, 0u32))?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            return ser::Serialize::serialize(dt, serializer);
+        }
+        ser::Serialize::serialize(&(dt.timestamp(), dt.timestamp_subsec_nanos()), serializer)
+    }
+
+    /// Deserialize a `DateTime` from a timestamp tuple, or an RFC 3339 string
+    /// for human-readable formats
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::compact::deserialize as from_compact;
+    /// #[derive(Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_compact")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let encoded = bincode::serialize(&(1431684000i64, 500_000_000u32))?;
+    /// let my_s: S = bincode::deserialize(&encoded)?;
+    /// assert_eq!(my_s.time.to_string(), "2015-05-15 10:00:00.500 UTC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            return de::Deserialize::deserialize(d);
+        }
+        let (secs, nsecs): (i64, u32) = de::Deserialize::deserialize(d)?;
+        Utc.timestamp(secs, nsecs).map_err(de::Error::custom)
+    }
+}
+
 #[test]
 fn test_serde_serialize() {
     super::test_encodable_json(serde_json::to_string, serde_json::to_string);
@@ -1126,4 +1625,155 @@ fn test_serde_bincode() {
     let decoded: DateTime<Utc> = deserialize(&encoded).unwrap();
     assert_eq!(dt, decoded);
     assert_eq!(dt.offset(), decoded.offset());
+
+    let dt = FixedOffset::east(3660).ymd(2014, 7, 24).unwrap().and_hms(12, 34, 6).unwrap();
+    let encoded = serialize(&dt).unwrap();
+    let decoded: DateTime<FixedOffset> = deserialize(&encoded).unwrap();
+    assert_eq!(dt, decoded);
+    assert_eq!(dt.offset(), decoded.offset());
+}
+
+#[test]
+fn test_serde_compact() {
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact {
+        #[serde(with = "compact")]
+        time: DateTime<Utc>,
+    }
+
+    let dt = Utc.ymd(2014, 7, 24).unwrap().and_hms_nano(12, 34, 6, 7).unwrap();
+
+    // a string for human-readable formats
+    let json = serde_json::to_string(&Compact { time: dt }).unwrap();
+    assert_eq!(json, r#"{"time":"2014-07-24T12:34:06.000000007Z"}"#);
+    assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), Compact { time: dt });
+
+    // a tuple of seconds and nanoseconds otherwise
+    let encoded = bincode::serialize(&Compact { time: dt }).unwrap();
+    assert_eq!(encoded, bincode::serialize(&(1_406_205_246i64, 7u32)).unwrap());
+    assert_eq!(bincode::deserialize::<Compact>(&encoded).unwrap(), Compact { time: dt });
+
+    // which isn't interchangeable with the string of the default implementation
+    assert!(bincode::deserialize::<DateTime<Utc>>(&encoded).is_err());
+    let encoded = bincode::serialize(&dt).unwrap();
+    assert_ne!(bincode::deserialize::<Compact>(&encoded).ok(), Some(Compact { time: dt }));
+
+    // before the epoch, and during a leap second
+    for &time in &[
+        Utc.timestamp(-1, 999_999_999).unwrap(),
+        Utc.ymd(2016, 12, 31).unwrap().and_hms_nano(23, 59, 59, 1_500_000_000).unwrap(),
+    ] {
+        let encoded = bincode::serialize(&Compact { time }).unwrap();
+        assert_eq!(bincode::deserialize::<Compact>(&encoded).unwrap(), Compact { time });
+    }
+
+    // out of range values are rejected
+    let encoded = bincode::serialize(&(i64::max_value(), 0u32)).unwrap();
+    assert!(bincode::deserialize::<Compact>(&encoded).is_err());
+    let encoded = bincode::serialize(&(0i64, 2_000_000_000u32)).unwrap();
+    assert!(bincode::deserialize::<Compact>(&encoded).is_err());
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn test_serde_rfc3339() {
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        #[serde(with = "rfc3339")]
+        utc: DateTime<Utc>,
+        #[serde(with = "rfc3339")]
+        fixed: DateTime<FixedOffset>,
+        #[serde(with = "rfc3339::option")]
+        maybe: Option<DateTime<FixedOffset>>,
+    }
+
+    let ist = FixedOffset::east(5 * 3600 + 1800);
+    let value = Test {
+        utc: Utc.ymd(2022, 6, 1).unwrap().and_hms_micro(12, 0, 0, 250).unwrap(),
+        fixed: ist.ymd(2022, 6, 1).unwrap().and_hms(17, 30, 0).unwrap(),
+        maybe: Some(ist.ymd(2022, 12, 31).unwrap().and_hms(23, 59, 59).unwrap()),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        json,
+        r#"{"utc":"2022-06-01T12:00:00.000250+00:00","fixed":"2022-06-01T17:30:00+05:30","maybe":"2022-12-31T23:59:59+05:30"}"#
+    );
+    let decoded: Test = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, value);
+    assert_eq!(decoded.fixed.offset(), &ist);
+    assert_eq!(decoded.maybe.unwrap().offset(), &ist);
+
+    let value = Test { maybe: None, ..value };
+    let json = serde_json::to_string(&value).unwrap();
+    assert!(json.ends_with(r#""maybe":null}"#));
+    assert_eq!(serde_json::from_str::<Test>(&json).unwrap(), value);
+
+    // converts the offset away for `Utc`
+    let utc: DateTime<Utc> = rfc3339::deserialize(&mut serde_json::Deserializer::from_str(
+        r#""2022-06-01T17:30:00+05:30""#,
+    ))
+    .unwrap();
+    assert_eq!(utc, Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap());
+
+    // errors are reported through serde, and the syntax is strict
+    for invalid in &[r#""2022-06-01 17:30:00+05:30""#, r#""2022-06-01T17:30:00""#, "1654084800"] {
+        let json = format!(r#"{{"utc":{0},"fixed":{0},"maybe":null}}"#, invalid);
+        assert!(serde_json::from_str::<Test>(&json).is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_serde_flexible() {
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        #[serde(with = "flexible")]
+        time: DateTime<Utc>,
+    }
+
+    let time = Utc.ymd(2021, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    for json in &[
+        r#"{"time":1609459200}"#,
+        r#"{"time":1609459200.0}"#,
+        r#"{"time":"2021-01-01T00:00:00Z"}"#,
+        r#"{"time":"2021-01-01T01:00:00+01:00"}"#,
+    ] {
+        assert_eq!(serde_json::from_str::<Test>(json).unwrap(), Test { time }, "{}", json);
+    }
+    assert_eq!(
+        serde_json::to_string(&Test { time }).unwrap(),
+        r#"{"time":"2021-01-01T00:00:00Z"}"#
+    );
+
+    // floats are seconds with a fractional part, also before the epoch
+    let decoded: Test = serde_json::from_str(r#"{"time":1609459200.25}"#).unwrap();
+    assert_eq!(decoded.time, time + crate::TimeDelta::milliseconds(250));
+    let decoded: Test = serde_json::from_str(r#"{"time":-1.5}"#).unwrap();
+    assert_eq!(decoded.time, Utc.timestamp(-2, 500_000_000).unwrap());
+    let decoded: Test = serde_json::from_str(r#"{"time":0.3}"#).unwrap();
+    assert_eq!(decoded.time, Utc.timestamp(0, 300_000_000).unwrap());
+    let decoded: Test = serde_json::from_str(r#"{"time":-0.3}"#).unwrap();
+    assert_eq!(decoded.time, Utc.timestamp(-1, 700_000_000).unwrap());
+    let decoded: Test = serde_json::from_str(r#"{"time":0.9999999999}"#).unwrap();
+    assert_eq!(decoded.time, Utc.timestamp(1, 0).unwrap());
+    // the nearest `f64` to `1609459200.3` is 48 nanoseconds short of it
+    let decoded: Test = serde_json::from_str(r#"{"time":1609459200.3}"#).unwrap();
+    assert_eq!(decoded.time, time + crate::TimeDelta::nanoseconds(299_999_952));
+    let decoded: Test = serde_json::from_str(r#"{"time":-86400}"#).unwrap();
+    assert_eq!(decoded.time, Utc.ymd(1969, 12, 31).unwrap().and_hms(0, 0, 0).unwrap());
+
+    for invalid in &[
+        r#"{"time":"2021-01-01 00:00:00"}"#,
+        r#"{"time":1e300}"#,
+        r#"{"time":18446744073709551615}"#,
+        r#"{"time":true}"#,
+        r#"{"time":null}"#,
+    ] {
+        assert!(serde_json::from_str::<Test>(invalid).is_err(), "{}", invalid);
+    }
 }
@@ -1,14 +1,13 @@
 use std::convert::TryFrom;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::DateTime;
-use crate::naive::{NaiveDate, NaiveTime};
+use super::{ByInstant, DateTime, Interval};
+use crate::error::{ChronoError, ChronoErrorKind};
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, TimeZone, Utc};
-#[cfg(feature = "clock")]
-use crate::Datelike;
-use crate::TimeDelta;
+use crate::offset::{FixedOffset, LocalResult, TimeZone, Utc};
+use crate::{DateField, Datelike, TimeDelta, Timelike};
 
 macro_rules! ymd {
     ($year:expr, $month:expr, $day:expr) => {
@@ -16,6 +15,99 @@ macro_rules! ymd {
     };
 }
 
+/// A time zone which observes daylight saving time like central Europe did in
+/// 2022: `+01:00`, and `+02:00` between 2022-03-27 01:00 and 2022-10-30 01:00
+/// UTC.
+#[derive(Clone)]
+struct DstTester;
+
+impl DstTester {
+    fn offset(utc: &NaiveDateTime) -> FixedOffset {
+        let start = ymd!(2022, 3, 27).and_hms(1, 0, 0).unwrap();
+        let end = ymd!(2022, 10, 30).and_hms(1, 0, 0).unwrap();
+
+        if *utc >= start && *utc < end {
+            FixedOffset::east(2 * 3600)
+        } else {
+            FixedOffset::east(3600)
+        }
+    }
+}
+
+impl TimeZone for DstTester {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &FixedOffset) -> Self {
+        DstTester
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> Result<FixedOffset, ChronoError> {
+        self.offset_from_local_datetime(&local.and_hms(12, 0, 0)?)
+    }
+
+    fn offset_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<FixedOffset, ChronoError> {
+        match self.offset_from_local_datetime_all(local)? {
+            LocalResult::Single(offset) => Ok(offset),
+            LocalResult::Ambiguous(..) => Err(ChronoError::new(ChronoErrorKind::AmbiguousDate)),
+        }
+    }
+
+    fn offset_from_local_datetime_all(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<LocalResult<FixedOffset>, ChronoError> {
+        let offsets = [FixedOffset::east(2 * 3600), FixedOffset::east(3600)];
+        let mut candidates =
+            offsets.iter().filter(|&&offset| DstTester::offset(&(*local - offset)) == offset);
+
+        match (candidates.next(), candidates.next()) {
+            (Some(&offset), None) => Ok(LocalResult::Single(offset)),
+            (Some(&earlier), Some(&later)) => Ok(LocalResult::Ambiguous(earlier, later)),
+            _ => Err(ChronoError::new(ChronoErrorKind::InvalidDateTime)),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Result<FixedOffset, ChronoError> {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0)?)
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Result<FixedOffset, ChronoError> {
+        Ok(DstTester::offset(utc))
+    }
+}
+
+/// Wraps a time zone to rely on the default `offset_from_local_datetime_all`,
+/// like `Local` and most other time zones.
+#[derive(Clone)]
+struct DefaultAll<Tz>(Tz);
+
+impl<Tz: TimeZone> TimeZone for DefaultAll<Tz> {
+    type Offset = Tz::Offset;
+
+    fn from_offset(offset: &Tz::Offset) -> Self {
+        DefaultAll(Tz::from_offset(offset))
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> Result<Tz::Offset, ChronoError> {
+        self.0.offset_from_local_date(local)
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> Result<Tz::Offset, ChronoError> {
+        self.0.offset_from_local_datetime(local)
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Result<Tz::Offset, ChronoError> {
+        self.0.offset_from_utc_date(utc)
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Result<Tz::Offset, ChronoError> {
+        self.0.offset_from_utc_datetime(utc)
+    }
+}
+
 #[test]
 fn test_datetime_offset() {
     let est = FixedOffset::west(5 * 60 * 60);
@@ -116,6 +208,25 @@ fn test_datetime_date_and_time() {
     assert!(utc_d < d);
 }
 
+#[test]
+fn test_datetime_naive_local_and_utc() {
+    let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+    let dt = ist.ymd(2022, 1, 1).unwrap().and_hms(2, 0, 0).unwrap();
+    assert_eq!(dt.naive_local(), ymd!(2022, 1, 1).and_hms(2, 0, 0).unwrap());
+    assert_eq!(dt.naive_utc(), ymd!(2021, 12, 31).and_hms(20, 30, 0).unwrap());
+    assert_eq!(dt.naive_local() - dt.naive_utc(), TimeDelta::hours(5) + TimeDelta::minutes(30));
+    assert_eq!(ist.from_utc_datetime(&dt.naive_utc()), Ok(dt));
+    assert_eq!(ist.from_local_datetime(&dt.naive_local()), Ok(dt));
+
+    let pst = FixedOffset::west(8 * 3600);
+    let dt = dt.with_timezone(&pst).unwrap();
+    assert_eq!(dt.naive_local() - dt.naive_utc(), TimeDelta::hours(-8));
+    assert_eq!(dt.naive_utc(), ymd!(2021, 12, 31).and_hms(20, 30, 0).unwrap());
+
+    let dt = dt.with_timezone(&Utc).unwrap();
+    assert_eq!(dt.naive_local(), dt.naive_utc());
+}
+
 #[test]
 #[cfg(feature = "clock")]
 fn test_datetime_with_timezone() {
@@ -176,6 +287,23 @@ fn test_datetime_rfc2822_and_rfc3339() {
     );
 }
 
+#[test]
+fn test_datetime_to_rfc2822() {
+    let dt = Utc.ymd(2003, 7, 1).unwrap().and_hms(10, 52, 37).unwrap();
+    assert_eq!(dt.to_rfc2822(), "Tue, 01 Jul 2003 10:52:37 +0000");
+
+    let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+    let dt = ist.ymd(2003, 7, 1).unwrap().and_hms(10, 52, 37).unwrap();
+    assert_eq!(dt.to_rfc2822(), "Tue, 01 Jul 2003 10:52:37 +0530");
+    let dt = FixedOffset::west(9 * 3600 + 30 * 60).ymd(2003, 12, 31).unwrap();
+    let dt = dt.and_hms(23, 5, 0).unwrap();
+    assert_eq!(dt.to_rfc2822(), "Wed, 31 Dec 2003 23:05:00 -0930");
+
+    // round-trips through the parser
+    let dt = ist.ymd(2003, 7, 1).unwrap().and_hms(10, 52, 37).unwrap();
+    assert_eq!(DateTime::parse_from_rfc2822(&dt.to_rfc2822()), Ok(dt));
+}
+
 #[test]
 fn test_rfc3339_opts() {
     use crate::SecondsFormat::*;
@@ -270,6 +398,139 @@ fn test_datetime_parse_from_str() {
     );
 }
 
+#[test]
+fn test_datetime_parse_from_str_offset_forms() {
+    let ymdhms = |off| FixedOffset::east(off).ymd(2014, 5, 7).unwrap().and_hms(12, 34, 56).unwrap();
+
+    for &(s, off) in &[("+0500", 5 * 3600), ("+05:00", 5 * 3600), ("-0330", -(3 * 3600 + 1800))] {
+        let input = format!("2014-05-07T12:34:56{}", s);
+        assert_eq!(DateTime::parse_from_str(&input, "%Y-%m-%dT%H:%M:%S%z"), Ok(ymdhms(off)));
+        assert_eq!(DateTime::parse_from_str(&input, "%Y-%m-%dT%H:%M:%S%#z"), Ok(ymdhms(off)));
+    }
+
+    // `Z` and hour-only offsets are only accepted by the lenient `%#z`
+    for &(s, off) in &[("Z", 0), ("z", 0), ("+05", 5 * 3600), ("-05", -5 * 3600)] {
+        let input = format!("2014-05-07T12:34:56{}", s);
+        assert!(DateTime::parse_from_str(&input, "%Y-%m-%dT%H:%M:%S%z").is_err());
+        assert_eq!(DateTime::parse_from_str(&input, "%Y-%m-%dT%H:%M:%S%#z"), Ok(ymdhms(off)));
+    }
+
+    // an hour-only offset can be followed by other items
+    assert_eq!(
+        DateTime::parse_from_str("12:34:56+05T2014-05-07", "%H:%M:%S%#zT%Y-%m-%d"),
+        Ok(ymdhms(5 * 3600))
+    );
+    assert_eq!(
+        DateTime::parse_from_str("[2014-05-07 12:34:56 -05]", "[%Y-%m-%d %H:%M:%S %#z]"),
+        Ok(ymdhms(-5 * 3600))
+    );
+}
+
+#[test]
+fn test_datetime_parse_and_remainder() {
+    let dt = FixedOffset::east(9 * 3600).ymd(2020, 1, 2).unwrap().and_hms(3, 4, 5).unwrap();
+    assert_eq!(
+        DateTime::parse_and_remainder("2020-01-02 03:04:05+0900 rest", "%Y-%m-%d %H:%M:%S%z"),
+        Ok((dt, " rest"))
+    );
+    assert_eq!(
+        DateTime::parse_and_remainder("2020-01-02 03:04:05+0900", "%Y-%m-%d %H:%M:%S%z"),
+        Ok((dt, ""))
+    );
+    // the parsed prefix still needs an offset
+    assert!(DateTime::parse_and_remainder("2020-01-02 03:04:05 rest", "%Y-%m-%d %H:%M:%S").is_err());
+}
+
+#[test]
+fn test_datetime_timestamp_format_round_trip() {
+    let dt = Utc.timestamp(1_500_000_000, 0).unwrap();
+    assert_eq!(dt.format("%s").to_string(), "1500000000");
+    assert_eq!(Utc.datetime_from_str("1500000000", "%s"), Ok(dt));
+
+    let dt = Utc.timestamp(-1_500_000_000, 0).unwrap();
+    assert_eq!(dt.format("%s").to_string(), "-1500000000");
+    assert_eq!(Utc.datetime_from_str("-1500000000", "%s"), Ok(dt));
+
+    // the timestamp is always relative to UTC, regardless of the offset.
+    let dt = Utc.timestamp(1_500_000_000, 0).unwrap().with_fixed_timezone(&FixedOffset::east(3600));
+    assert_eq!(dt.format("%s").to_string(), "1500000000");
+    assert_eq!(FixedOffset::east(3600).datetime_from_str("1500000000", "%s"), Ok(dt));
+}
+
+#[test]
+fn test_datetime_leap_second_ordering() {
+    let before = Utc.ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 500).unwrap();
+    let leap = Utc.ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 1_000).unwrap();
+    let after = Utc.ymd(2017, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert!(before < leap && leap < after);
+
+    let mut sorted = vec![after, leap, before];
+    sorted.sort();
+    assert_eq!(sorted, [before, leap, after]);
+
+    // ordering is by the instant, so it also holds across offsets
+    let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+    let local_leap = leap.with_timezone(&ist).unwrap();
+    assert_eq!(local_leap.to_string(), "2017-01-01 05:29:60 +05:30");
+    assert!(local_leap > before && local_leap < after);
+    assert!(local_leap < after.with_timezone(&ist).unwrap());
+    assert_eq!(local_leap.partial_cmp(&leap), Some(core::cmp::Ordering::Equal));
+}
+
+#[test]
+fn test_datetime_try_from_str() {
+    let utc = Utc.ymd(2020, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!("2020-01-01T00:00:00Z".parse::<DateTime<Utc>>(), Ok(utc));
+    assert_eq!(DateTime::<Utc>::try_from("2020-01-01T00:00:00Z"), Ok(utc));
+    assert_eq!(DateTime::<Utc>::try_from("2020-01-01 00:00:00+00:00"), Ok(utc));
+
+    // a non-zero offset is converted into UTC
+    let dt = DateTime::<Utc>::try_from("2020-01-01T05:30:00+05:30").unwrap();
+    assert_eq!(dt, utc);
+    assert_eq!(DateTime::<Utc>::try_from("2019-12-31T19:00:00-05:00"), Ok(utc));
+
+    let fixed = DateTime::<FixedOffset>::try_from("2020-01-01T05:30:00+05:30").unwrap();
+    assert_eq!(fixed.offset(), &FixedOffset::east(19800));
+    assert_eq!(fixed, utc);
+    assert_eq!(Ok(fixed), "2020-01-01T05:30:00+05:30".parse::<DateTime<FixedOffset>>());
+
+    assert!(DateTime::<Utc>::try_from("2020-01-01T00:00:00").is_err());
+    assert!(DateTime::<FixedOffset>::try_from("2020-01-01").is_err());
+    assert!(DateTime::<FixedOffset>::try_from("not a date").is_err());
+}
+
+#[test]
+fn test_datetime_from_str_offset_mismatch() {
+    use crate::format::ParseErrorKind;
+
+    let fmt = "%Y-%m-%d %H:%M:%S %z";
+    let err = Utc.datetime_from_str("2020-01-01 00:00:00 +0100", fmt).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ParseErrorKind::OffsetMismatch {
+            parsed: FixedOffset::east(3600),
+            expected: FixedOffset::east(0)
+        }
+    );
+    assert_eq!(err.to_string(), "offset +01:00 doesn't match the time zone offset +00:00");
+    assert_eq!(
+        Utc.datetime_from_str("2020-01-01 00:00:00 +0000", fmt),
+        Ok(Utc.ymd(2020, 1, 1).unwrap().and_hms(0, 0, 0).unwrap())
+    );
+
+    // the offset of the time zone is the one at the parsed date and time
+    let err = DstTester.datetime_from_str("2022-06-01 12:00:00 +0100", fmt).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ParseErrorKind::OffsetMismatch {
+            parsed: FixedOffset::east(3600),
+            expected: FixedOffset::east(7200)
+        }
+    );
+    assert!(DstTester.datetime_from_str("2022-06-01 12:00:00 +0200", fmt).is_ok());
+    assert!(DstTester.datetime_from_str("2022-01-01 12:00:00 +0100", fmt).is_ok());
+}
+
 #[test]
 fn test_to_string_round_trip() {
     let dt = Utc.ymd(2000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
@@ -329,6 +590,27 @@ fn test_subsecond_part() {
     assert_eq!(1234567, datetime.timestamp_subsec_nanos());
 }
 
+#[test]
+fn test_subsecond_part_leap_second() {
+    // 2016-12-31 23:59:60.5 UTC, seen from an offset where the leap second isn't at minute 59
+    let tz = FixedOffset::east(5 * 3600 + 1800);
+    let datetime = tz.ymd(2017, 1, 1).unwrap().and_hms_nano(5, 29, 59, 1_500_000_000).unwrap();
+    assert_eq!(datetime.naive_utc().second(), 59);
+
+    assert_eq!(1_500, datetime.timestamp_subsec_millis());
+    assert_eq!(1_500_000, datetime.timestamp_subsec_micros());
+    assert_eq!(1_500_000_000, datetime.timestamp_subsec_nanos());
+    assert_eq!(
+        datetime.timestamp(),
+        Utc.ymd(2016, 12, 31).unwrap().and_hms(23, 59, 59).unwrap().timestamp()
+    );
+
+    let datetime = Utc.ymd(2016, 12, 31).unwrap().and_hms_nano(23, 59, 59, 1_999_999_999).unwrap();
+    assert_eq!(1_999, datetime.timestamp_subsec_millis());
+    assert_eq!(1_999_999, datetime.timestamp_subsec_micros());
+    assert_eq!(1_999_999_999, datetime.timestamp_subsec_nanos());
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_from_system_time() {
@@ -341,7 +623,7 @@ fn test_from_system_time() {
     assert_eq!(DateTime::<Utc>::from(UNIX_EPOCH), epoch);
     assert_eq!(
         DateTime::<Utc>::from(UNIX_EPOCH + Duration::new(999_999_999, nanos)),
-        Utc.ymd(2001, 9, 9).and_hms_nano(1, 46, 39, nanos)
+        Utc.ymd(2001, 9, 9).unwrap().and_hms_nano(1, 46, 39, nanos).unwrap()
     );
     assert_eq!(
         DateTime::<Utc>::from(UNIX_EPOCH - Duration::new(999_999_999, nanos)),
@@ -351,7 +633,7 @@ fn test_from_system_time() {
     // DateTime<Utc> -> SystemTime
     assert_eq!(SystemTime::from(epoch), UNIX_EPOCH);
     assert_eq!(
-        SystemTime::from(Utc.ymd(2001, 9, 9).and_hms_nano(1, 46, 39, nanos)),
+        SystemTime::from(Utc.ymd(2001, 9, 9).unwrap().and_hms_nano(1, 46, 39, nanos).unwrap()),
         UNIX_EPOCH + Duration::new(999_999_999, nanos)
     );
     assert_eq!(
@@ -362,10 +644,24 @@ fn test_from_system_time() {
     // DateTime<any tz> -> SystemTime (via `with_timezone`)
     #[cfg(feature = "clock")]
     {
-        assert_eq!(SystemTime::from(epoch.with_timezone(&Local)), UNIX_EPOCH);
+        assert_eq!(SystemTime::from(epoch.with_timezone(&Local).unwrap()), UNIX_EPOCH);
+    }
+    assert_eq!(
+        SystemTime::from(epoch.with_timezone(&FixedOffset::east(32400)).unwrap()),
+        UNIX_EPOCH
+    );
+    assert_eq!(
+        SystemTime::from(epoch.with_timezone(&FixedOffset::west(28800)).unwrap()),
+        UNIX_EPOCH
+    );
+
+    // round trips across the epoch
+    for &secs in &[-86_400 * 365, -1, 0, 1, 86_400 * 365] {
+        for &nanos in &[0, 1, 999_999_999] {
+            let dt = Utc.timestamp(secs, nanos).unwrap();
+            assert_eq!(DateTime::<Utc>::from(SystemTime::from(dt)), dt);
+        }
     }
-    assert_eq!(SystemTime::from(epoch.with_timezone(&FixedOffset::east(32400))), UNIX_EPOCH);
-    assert_eq!(SystemTime::from(epoch.with_timezone(&FixedOffset::west(28800))), UNIX_EPOCH);
 }
 
 #[test]
@@ -457,15 +753,181 @@ fn test_datetime_from_local() {
     // 2000-01-12T10:00:00+8:00:00
     let timezone_east = FixedOffset::east(8 * 60 * 60);
     let naivedatetime_east = ymd!(2000, 1, 12).and_hms(10, 0, 0).unwrap();
-    let datetime_east = DateTime::<FixedOffset>::from_local(naivedatetime_east, timezone_east);
+    let datetime_east =
+        DateTime::<FixedOffset>::from_local(naivedatetime_east, timezone_east).unwrap();
 
     // 2000-01-11T19:00:00-7:00:00
     let timezone_west = FixedOffset::west(7 * 60 * 60);
     let naivedatetime_west = ymd!(2000, 1, 11).and_hms(19, 0, 0).unwrap();
-    let datetime_west = DateTime::<FixedOffset>::from_local(naivedatetime_west, timezone_west);
+    let datetime_west =
+        DateTime::<FixedOffset>::from_local(naivedatetime_west, timezone_west).unwrap();
 
     assert_eq!(datetime_east, datetime_utc.with_fixed_timezone(&timezone_east));
     assert_eq!(datetime_west, datetime_utc.with_fixed_timezone(&timezone_west));
+
+    // the UTC datetime must be in range
+    let offset = FixedOffset::east(3600);
+    assert!(DateTime::<FixedOffset>::from_local(NaiveDateTime::MIN, offset).is_err());
+    assert!(DateTime::<FixedOffset>::from_local(NaiveDateTime::MAX, offset).is_ok());
+    assert!(DateTime::<FixedOffset>::from_local(NaiveDateTime::MAX, -offset).is_err());
+}
+
+#[test]
+fn test_datetime_from_local_all() {
+    let ymdhms = |y, m, d, h, n, s| ymd!(y, m, d).and_hms(h, n, s).unwrap();
+
+    let local = ymdhms(2022, 10, 30, 2, 30, 0);
+    assert_eq!(
+        DateTime::from_local_all(local, &Utc),
+        Ok(LocalResult::Single(Utc.from_utc_datetime(&local).unwrap()))
+    );
+    let tz = FixedOffset::east(5 * 3600);
+    assert_eq!(
+        DateTime::from_local_all(local, &tz).map(|r| r.map(|dt| dt.naive_utc())),
+        Ok(LocalResult::Single(ymdhms(2022, 10, 29, 21, 30, 0)))
+    );
+
+    // summer and winter time
+    let summer = DateTime::from_local_all(ymdhms(2022, 7, 1, 12, 0, 0), &DstTester).unwrap();
+    assert_eq!(summer.map(|dt| dt.naive_utc()), LocalResult::Single(ymdhms(2022, 7, 1, 10, 0, 0)));
+    let winter = DateTime::from_local_all(ymdhms(2022, 12, 1, 12, 0, 0), &DstTester).unwrap();
+    assert_eq!(winter.map(|dt| *dt.offset()), LocalResult::Single(FixedOffset::east(3600)));
+
+    // both readings of the repeated hour, the earlier one first
+    match DateTime::from_local_all(local, &DstTester).unwrap() {
+        LocalResult::Ambiguous(earlier, later) => {
+            assert_eq!(earlier.naive_local(), local);
+            assert_eq!(later.naive_local(), local);
+            assert_eq!(earlier.offset(), &FixedOffset::east(2 * 3600));
+            assert_eq!(later.offset(), &FixedOffset::east(3600));
+            assert_eq!(later.signed_duration_since(earlier), TimeDelta::hours(1));
+        }
+        LocalResult::Single(_) => panic!("expected an ambiguous local time"),
+    }
+    assert_eq!(
+        DstTester.from_local_datetime(&local),
+        Err(ChronoError::new(ChronoErrorKind::AmbiguousDate))
+    );
+
+    // the skipped hour
+    assert_eq!(
+        DateTime::from_local_all(ymdhms(2022, 3, 27, 2, 30, 0), &DstTester),
+        Err(ChronoError::new(ChronoErrorKind::InvalidDateTime))
+    );
+
+    // the default `offset_from_local_datetime_all` finds the same offsets
+    for &local in &[local, ymdhms(2022, 7, 1, 12, 0, 0), ymdhms(2022, 3, 27, 2, 30, 0)] {
+        assert_eq!(
+            DefaultAll(DstTester).offset_from_local_datetime_all(&local),
+            DstTester.offset_from_local_datetime_all(&local)
+        );
+    }
+
+    // the UTC datetime must be in range
+    assert_eq!(
+        DateTime::from_local_all(NaiveDateTime::MIN, &tz),
+        Err(ChronoError::new(ChronoErrorKind::Overflow))
+    );
+}
+
+#[test]
+fn test_datetime_calendar_boundaries() {
+    let dt = FixedOffset::east(3600).ymd(2024, 2, 10).unwrap().and_hms(13, 15, 0).unwrap();
+    let boundaries = [
+        (dt.start_of_day(), "2024-02-10 00:00:00 +01:00"),
+        (dt.end_of_day(), "2024-02-10 23:59:59.999999999 +01:00"),
+        (dt.start_of_month(), "2024-02-01 00:00:00 +01:00"),
+        (dt.end_of_month(), "2024-02-29 23:59:59.999999999 +01:00"),
+        (dt.start_of_year(), "2024-01-01 00:00:00 +01:00"),
+        (dt.end_of_year(), "2024-12-31 23:59:59.999999999 +01:00"),
+    ];
+    for &(ref boundary, expected) in boundaries.iter() {
+        assert_eq!(boundary.as_ref().map(|dt| dt.to_string()), Ok(expected.to_string()));
+    }
+
+    // the boundaries are computed in local time, not in UTC
+    let dt = FixedOffset::west(5 * 3600).ymd(2022, 12, 31).unwrap().and_hms(22, 0, 0).unwrap();
+    assert_eq!(dt.start_of_year().unwrap().to_string(), "2022-01-01 00:00:00 -05:00");
+    assert_eq!(dt.end_of_month().unwrap().to_string(), "2022-12-31 23:59:59.999999999 -05:00");
+
+    // the offset is resolved again at the boundary
+    let dt = DstTester.from_local_datetime(&ymd!(2022, 3, 27).and_hms(15, 0, 0).unwrap()).unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east(2 * 3600));
+    assert_eq!(dt.start_of_day().unwrap().to_string(), "2022-03-27 00:00:00 +01:00");
+    assert_eq!(dt.end_of_day().unwrap().to_string(), "2022-03-27 23:59:59.999999999 +02:00");
+    assert_eq!(dt.end_of_year().unwrap().to_string(), "2022-12-31 23:59:59.999999999 +01:00");
+
+    // out of range
+    let dt = Utc.from_utc_datetime(&NaiveDateTime::MAX).unwrap();
+    assert!(dt.start_of_day().is_ok());
+    assert!(dt.end_of_day().is_err());
+    assert!(dt.end_of_year().is_err());
+}
+
+#[test]
+fn test_datetime_calendar_boundaries_skipped_midnight() {
+    // daylight saving time starts at local midnight on the first Sunday in
+    // November, and ends at local midnight on the third Sunday in February
+    let tz: crate::PosixTz = "BRT3BRST,M11.1.0/0,M2.3.0/0".parse().unwrap();
+
+    // 2018-11-04 00:00 to 01:00 doesn't exist, so the day starts at 01:00
+    let dt = tz.from_local_datetime(&ymd!(2018, 11, 4).and_hms(12, 0, 0).unwrap()).unwrap();
+    let start = dt.start_of_day().unwrap();
+    assert_eq!(start.to_string(), "2018-11-04 01:00:00 BRST");
+    assert_eq!(start.naive_utc(), ymd!(2018, 11, 4).and_hms(3, 0, 0).unwrap());
+    assert_eq!(dt.end_of_day().unwrap().to_string(), "2018-11-04 23:59:59.999999999 BRST");
+
+    // the previous day ends right before the gap
+    let dt = tz.from_local_datetime(&ymd!(2018, 11, 3).and_hms(12, 0, 0).unwrap()).unwrap();
+    let end = dt.end_of_day().unwrap();
+    assert_eq!(end.to_string(), "2018-11-03 23:59:59.999999999 BRT");
+    assert_eq!(end + TimeDelta::nanoseconds(1), start);
+
+    // 2019-02-16 23:00 to 24:00 occurs twice, the day ends with the later one
+    let dt = tz.from_local_datetime(&ymd!(2019, 2, 16).and_hms(12, 0, 0).unwrap()).unwrap();
+    let end = dt.end_of_day().unwrap();
+    assert_eq!(end.to_string(), "2019-02-16 23:59:59.999999999 BRT");
+    let start = tz.from_local_datetime(&ymd!(2019, 2, 17).and_hms(12, 0, 0).unwrap());
+    assert_eq!(start.unwrap().start_of_day(), Ok(end + TimeDelta::nanoseconds(1)));
+
+    // when daylight saving time ends at 01:00 instead, midnight occurs twice
+    // and the day starts with the earlier one, also for time zones relying on
+    // the default `offset_from_local_datetime_all`
+    let tz: crate::PosixTz = "BRT3BRST,M11.1.0/0,M2.3.0/1".parse().unwrap();
+    let noon = ymd!(2019, 2, 17).and_hms(12, 0, 0).unwrap();
+    let start = tz.from_local_datetime(&noon).unwrap().start_of_day().unwrap();
+    assert_eq!(start.to_string(), "2019-02-17 00:00:00 BRST");
+    assert_eq!(start.naive_utc(), ymd!(2019, 2, 17).and_hms(2, 0, 0).unwrap());
+    let dt = DefaultAll(tz).from_local_datetime(&noon).unwrap();
+    assert_eq!(dt.start_of_day().unwrap().naive_utc(), start.naive_utc());
+    assert_eq!(dt.start_of_month().unwrap().to_string(), "2019-02-01 00:00:00 BRST");
+
+    // without known transitions, the gap is skipped by its length
+    let local = ymd!(2022, 3, 27).and_hms(2, 30, 0).unwrap();
+    let dt = super::resolve_local_earliest(&DstTester, local).unwrap();
+    assert_eq!(dt.to_string(), "2022-03-27 03:30:00 +02:00");
+    let local = ymd!(2022, 10, 30).and_hms(2, 30, 0).unwrap();
+    let dt = super::resolve_local_earliest(&DstTester, local).unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east(2 * 3600));
+}
+
+#[test]
+fn test_datetime_from_naive_utc_and_offset() {
+    let utc = ymd!(1971, 12, 31).and_hms_milli(23, 59, 59, 500).unwrap();
+    let offset = FixedOffset::west(44 * 60 + 30);
+
+    let dt = DateTime::<FixedOffset>::from_naive_utc_and_offset(utc, offset);
+    assert_eq!(dt.naive_utc(), utc);
+    assert_eq!(dt.offset(), &offset);
+    assert_eq!(dt.timestamp_millis(), 63_071_999_500);
+    assert_eq!(dt, Utc.from_utc_datetime(&utc).unwrap());
+
+    // the same instant, built from the local datetime
+    let local = dt.naive_local();
+    assert_eq!(local, ymd!(1971, 12, 31).and_hms_milli(23, 15, 29, 500).unwrap());
+    let dt2 = DateTime::<FixedOffset>::from_local(local, offset).unwrap();
+    assert_eq!(dt2.naive_utc(), utc);
+    assert_eq!(dt2.offset(), &offset);
 }
 
 #[test]
@@ -488,6 +950,103 @@ fn test_years_elapsed() {
     assert_eq!(Utc::today().unwrap().years_since(future), None);
 }
 
+#[test]
+fn test_datetime_clamp() {
+    let min = Utc.ymd(2022, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    let max = Utc.ymd(2022, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+
+    let below = Utc.ymd(2021, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+    let within = Utc.ymd(2022, 6, 15).unwrap().and_hms(12, 0, 0).unwrap();
+    let above = Utc.ymd(2023, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(below.clamp(min, max), min);
+    assert_eq!(min.clamp(min, max), min);
+    assert_eq!(within.clamp(min, max), within);
+    assert_eq!(max.clamp(min, max), max);
+    assert_eq!(above.clamp(min, max), max);
+
+    // compared by instant, keeping the offset of the returned value
+    let tz = FixedOffset::east(9 * 3600);
+    let min = tz.ymd(2022, 1, 1).unwrap().and_hms(9, 0, 0).unwrap();
+    let max = FixedOffset::west(3600).ymd(2022, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    let dt = FixedOffset::east(0).ymd(2021, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+    assert_eq!(dt.clamp(min, max).offset(), &tz);
+    assert_eq!(dt.clamp(min, max), Utc.ymd(2022, 1, 1).unwrap().and_hms(0, 0, 0).unwrap());
+    let dt = FixedOffset::east(0).ymd(2022, 1, 1).unwrap().and_hms(0, 0, 1).unwrap();
+    assert_eq!(dt.clamp(min, max), dt);
+    assert_eq!(dt.clamp(min, max).offset(), &FixedOffset::east(0));
+    let dt = FixedOffset::east(0).ymd(2022, 1, 1).unwrap().and_hms(1, 0, 1).unwrap();
+    assert_eq!(dt.clamp(min, max).offset(), &FixedOffset::west(3600));
+}
+
+#[test]
+fn test_datetime_sql_parts() {
+    let tz = FixedOffset::west(5 * 3600);
+    let dt = tz.ymd(2022, 6, 15).unwrap().and_hms_nano(18, 59, 36, 123_456_789).unwrap();
+    assert_eq!(dt.to_sql_parts(), (2022, 6, 15, 18, 59, 36, 123_456_789));
+    assert_eq!(tz.from_sql_parts(dt.to_sql_parts()), Ok(dt));
+
+    // the parts are those of the local time
+    let utc = dt.with_timezone(&Utc).unwrap();
+    assert_eq!(utc.to_sql_parts(), (2022, 6, 15, 23, 59, 36, 123_456_789));
+    assert_eq!(Utc.from_sql_parts(utc.to_sql_parts()), Ok(utc));
+    assert_eq!(tz.from_sql_parts(utc.to_sql_parts()), Ok(dt + TimeDelta::hours(5)));
+
+    let leap = Utc.ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 1_500).unwrap();
+    assert_eq!(leap.to_sql_parts(), (2016, 12, 31, 23, 59, 59, 1_500_000_000));
+    assert_eq!(Utc.from_sql_parts(leap.to_sql_parts()), Ok(leap));
+
+    let dt = DstTester.ymd(2022, 7, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(DstTester.from_sql_parts(dt.to_sql_parts()), Ok(dt));
+    assert_eq!(
+        DstTester.from_sql_parts((2022, 3, 27, 2, 30, 0, 0)),
+        Err(ChronoError::new(ChronoErrorKind::InvalidDateTime))
+    );
+    assert_eq!(
+        DstTester.from_sql_parts((2022, 10, 30, 2, 30, 0, 0)),
+        Err(ChronoError::new(ChronoErrorKind::AmbiguousDate))
+    );
+
+    assert!(Utc.from_sql_parts((2022, 13, 1, 0, 0, 0, 0)).is_err());
+    assert!(Utc.from_sql_parts((2022, 1, 1, 24, 0, 0, 0)).is_err());
+    assert!(Utc.from_sql_parts((2022, 1, 1, 0, 0, 0, 2_000_000_000)).is_err());
+}
+
+#[test]
+fn test_datetime_get_field() {
+    // a Sunday in the last ISO week of 2020
+    let dt = FixedOffset::east(9 * 3600)
+        .ymd(2021, 1, 3)
+        .unwrap()
+        .and_hms_nano(23, 15, 30, 123_456_789)
+        .unwrap();
+    let fields = [
+        (DateField::Year, 2021),
+        (DateField::Month, 1),
+        (DateField::Day, 3),
+        (DateField::Hour, 23),
+        (DateField::Minute, 15),
+        (DateField::Second, 30),
+        (DateField::Nanosecond, 123_456_789),
+        (DateField::Ordinal, 3),
+        (DateField::Weekday, 7),
+        (DateField::IsoWeek, 53),
+    ];
+    for &(field, value) in &fields {
+        assert_eq!(dt.get_field(field), value, "{:?}", field);
+    }
+
+    // the fields are those of the local time
+    let utc = dt.with_timezone(&Utc).unwrap();
+    assert_eq!(utc.get_field(DateField::Hour), 14);
+    assert_eq!(utc.get_field(DateField::Weekday), 7);
+
+    let leap = ymd!(-44, 3, 15).and_hms_milli(23, 59, 59, 1_500).unwrap();
+    let dt = Utc.from_utc_datetime(&leap).unwrap();
+    assert_eq!(dt.get_field(DateField::Year), -44);
+    assert_eq!(dt.get_field(DateField::Second), 59);
+    assert_eq!(dt.get_field(DateField::Nanosecond), 1_500_000_000);
+}
+
 #[test]
 fn test_datetime_add_assign() {
     let naivedatetime = ymd!(2000, 1, 1).and_hms(0, 0, 0).unwrap();
@@ -561,3 +1120,585 @@ fn test_datetime_sub_assign_local() {
         assert_eq!(datetime_sub, datetime - TimeDelta::days(i))
     }
 }
+
+#[test]
+fn test_date_and_time_dst() {
+    let gap = Err(ChronoError::new(ChronoErrorKind::InvalidDateTime));
+    let ambiguous = Err(ChronoError::new(ChronoErrorKind::AmbiguousDate));
+    let hms = |h, m, s| NaiveTime::from_hms(h, m, s).unwrap();
+
+    let date = DstTester.ymd(2022, 3, 27).unwrap();
+    assert_eq!(date.and_time(hms(1, 30, 0)).unwrap().to_string(), "2022-03-27 01:30:00 +01:00");
+    assert_eq!(date.and_time(hms(2, 30, 0)), gap);
+    assert_eq!(date.and_hms(2, 0, 0), gap);
+    assert_eq!(date.and_hms_milli(2, 59, 59, 999), gap);
+    // the offset is resolved again rather than taken from the date
+    assert_eq!(date.and_time(hms(1, 30, 0)).unwrap().offset(), &FixedOffset::east(3600));
+    assert_eq!(date.and_time(hms(3, 30, 0)).unwrap().to_string(), "2022-03-27 03:30:00 +02:00");
+
+    let date = DstTester.ymd(2022, 10, 30).unwrap();
+    assert_eq!(date.and_time(hms(1, 30, 0)).unwrap().to_string(), "2022-10-30 01:30:00 +02:00");
+    assert_eq!(date.and_time(hms(2, 30, 0)), ambiguous);
+    assert_eq!(date.and_hms_micro(2, 0, 0, 0), ambiguous);
+    assert_eq!(date.and_hms_nano(2, 59, 59, 999_999_999), ambiguous);
+    assert_eq!(date.and_time(hms(3, 0, 0)).unwrap().to_string(), "2022-10-30 03:00:00 +01:00");
+
+    // invalid times are still reported as such
+    assert_eq!(date.and_hms(24, 0, 0), Err(ChronoError::new(ChronoErrorKind::InvalidTime)));
+}
+
+#[test]
+fn test_datetime_with_time() {
+    let dt = DstTester.ymd(2022, 3, 27).unwrap().and_hms(1, 30, 0).unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east(3600));
+
+    // spring forward: 02:00 to 03:00 doesn't exist
+    assert_eq!(dt.with_time(2, 30, 0), Err(ChronoError::new(ChronoErrorKind::InvalidDateTime)));
+
+    let later = dt.with_time(3, 30, 0).unwrap();
+    assert_eq!(later.offset(), &FixedOffset::east(2 * 3600));
+    assert_eq!(later.to_string(), "2022-03-27 03:30:00 +02:00");
+    assert_eq!(later - dt, TimeDelta::hours(1));
+
+    let earlier = later.with_time(0, 0, 0).unwrap();
+    assert_eq!(earlier.to_string(), "2022-03-27 00:00:00 +01:00");
+
+    // fall back: 02:00 to 03:00 occurs twice
+    let dt = DstTester.ymd(2022, 10, 30).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.with_time(2, 30, 0), Err(ChronoError::new(ChronoErrorKind::AmbiguousDate)));
+    assert_eq!(dt.with_time(3, 0, 0).unwrap().to_string(), "2022-10-30 03:00:00 +01:00");
+
+    assert!(dt.with_time(24, 0, 0).is_err());
+    assert!(dt.with_time(12, 60, 0).is_err());
+}
+
+#[test]
+fn test_datetime_add_signed_local() {
+    let dt = DstTester.ymd(2022, 10, 30).unwrap().and_hms(1, 30, 0).unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east(2 * 3600));
+
+    // lands in the first 02:30, which is repeated an hour later
+    let first = dt.add_signed_local(TimeDelta::hours(1)).unwrap();
+    let second = DstTester.from_utc_datetime(&(dt.naive_utc() + TimeDelta::hours(2))).unwrap();
+    assert_eq!(second.to_string(), "2022-10-30 02:30:00 +01:00");
+    let earlier = dt + TimeDelta::hours(1);
+    assert_eq!(earlier.to_string(), "2022-10-30 02:30:00 +02:00");
+    assert_eq!(first, LocalResult::Ambiguous(earlier, second));
+
+    // lands in the second 02:30, still reported earliest first
+    assert_eq!(
+        dt.add_signed_local(TimeDelta::hours(2)),
+        Ok(LocalResult::Ambiguous(earlier, second))
+    );
+
+    // outside of the repeated hour
+    assert_eq!(dt.add_signed_local(TimeDelta::zero()), Ok(LocalResult::Single(dt)));
+    let after = dt.add_signed_local(TimeDelta::hours(3)).unwrap();
+    assert_eq!(after.single().unwrap().to_string(), "2022-10-30 03:30:00 +01:00");
+    let before = dt.add_signed_local(TimeDelta::hours(-1)).unwrap();
+    assert_eq!(before.single().unwrap().to_string(), "2022-10-30 00:30:00 +02:00");
+
+    // spring forward never makes the result ambiguous
+    let dt = DstTester.ymd(2022, 3, 27).unwrap().and_hms(1, 30, 0).unwrap();
+    let result = dt.add_signed_local(TimeDelta::hours(1)).unwrap();
+    assert_eq!(result.single().unwrap().to_string(), "2022-03-27 03:30:00 +02:00");
+
+    let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+    assert_eq!(dt.add_signed_local(TimeDelta::max_value()), overflow);
+
+    // also for time zones relying on the default `offset_from_local_datetime_all`
+    let dt = DefaultAll(DstTester).ymd(2022, 10, 30).unwrap().and_hms(1, 30, 0).unwrap();
+    let result = dt.add_signed_local(TimeDelta::hours(1)).unwrap();
+    assert_eq!(
+        result.map(|dt| dt.to_string()),
+        LocalResult::Ambiguous(
+            "2022-10-30 02:30:00 +02:00".to_owned(),
+            "2022-10-30 02:30:00 +01:00".to_owned()
+        )
+    );
+}
+
+#[test]
+fn test_datetime_with_date_fields_dst() {
+    let gap = Err(ChronoError::new(ChronoErrorKind::InvalidDateTime));
+    let ambiguous = Err(ChronoError::new(ChronoErrorKind::AmbiguousDate));
+
+    // 02:30 doesn't exist on 2022-03-27, the 86th day of the year
+    let dt = DstTester.ymd(2022, 1, 27).unwrap().and_hms(2, 30, 0).unwrap();
+    assert_eq!(dt.with_ordinal(86), gap);
+    assert_eq!(dt.with_ordinal0(85), gap);
+    assert_eq!(dt.with_month(3), gap);
+    assert_eq!(dt.with_month0(2), gap);
+    assert_eq!(DstTester.ymd(2022, 3, 20).unwrap().and_hms(2, 30, 0).unwrap().with_day(27), gap);
+    assert_eq!(DstTester.ymd(2021, 3, 27).unwrap().and_hms(2, 30, 0).unwrap().with_year(2022), gap);
+
+    // the offset is resolved again on both sides of the gap
+    let dt = DstTester.ymd(2022, 3, 26).unwrap().and_hms(3, 30, 0).unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east(3600));
+    let next = dt.with_day(27).unwrap();
+    assert_eq!(next.offset(), &FixedOffset::east(2 * 3600));
+    assert_eq!(next.to_string(), "2022-03-27 03:30:00 +02:00");
+    assert_eq!(next - dt, TimeDelta::hours(23));
+    let next = dt.with_ordinal(100).unwrap();
+    assert_eq!(next.to_string(), "2022-04-10 03:30:00 +02:00");
+    assert_eq!(next.with_month(1).unwrap().to_string(), "2022-01-10 03:30:00 +01:00");
+
+    // 02:30 occurs twice on 2022-10-30
+    let dt = DstTester.ymd(2022, 10, 29).unwrap().and_hms(2, 30, 0).unwrap();
+    assert_eq!(dt.with_day(30), ambiguous);
+    assert_eq!(dt.with_ordinal(303), ambiguous);
+    assert_eq!(dt.with_day(31).unwrap().to_string(), "2022-10-31 02:30:00 +01:00");
+}
+
+#[test]
+fn test_datetime_with_nanosecond_checked() {
+    let invalid = ChronoError::new(ChronoErrorKind::InvalidTime);
+
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+    let leap = dt.with_nanosecond_checked(1_000_000_000).unwrap();
+    assert_eq!(leap.to_string(), "2016-12-31 23:59:60 UTC");
+    assert_eq!(leap.nanosecond(), 1_000_000_000);
+    assert_eq!(dt.with_nanosecond_checked(1_999_999_999).unwrap().nanosecond(), 1_999_999_999);
+    assert_eq!(dt.with_nanosecond_checked(2_000_000_000), Err(invalid));
+    assert_eq!(dt.with_nanosecond_checked(123).unwrap().nanosecond(), 123);
+
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.with_nanosecond_checked(1_000_000_000), Err(invalid));
+    assert_eq!(dt.with_nanosecond_checked(999_999_999).unwrap().nanosecond(), 999_999_999);
+
+    // the leap second is checked against UTC, and the offset is kept
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+    let dt = dt.with_timezone(&FixedOffset::east(5 * 3600 + 30 * 60)).unwrap();
+    let leap = dt.with_nanosecond_checked(1_500_000_000).unwrap();
+    assert_eq!(leap.to_string(), "2017-01-01 05:29:60.500 +05:30");
+    assert_eq!(leap.offset(), dt.offset());
+
+    // the local second is 59, but the UTC second is not
+    let dt = FixedOffset::east(3601).ymd(2016, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+    assert_eq!(dt.with_nanosecond_checked(1_500_000_000), Err(invalid));
+}
+
+#[test]
+fn test_datetime_date_naive() {
+    let ist = FixedOffset::east(5 * 3600 + 30 * 60);
+
+    // 18:30 UTC is midnight in +05:30
+    let utc = Utc.ymd(2022, 12, 31).unwrap().and_hms(18, 29, 59).unwrap();
+    assert_eq!(utc.with_timezone(&ist).unwrap().date_naive(), ymd!(2022, 12, 31));
+    let utc = Utc.ymd(2022, 12, 31).unwrap().and_hms(18, 30, 0).unwrap();
+    let dt = utc.with_timezone(&ist).unwrap();
+    assert_eq!(dt.date_naive(), ymd!(2023, 1, 1));
+    assert_eq!(utc.date_naive(), ymd!(2022, 12, 31));
+    assert_eq!(dt.date_naive(), dt.naive_local().date());
+    assert_eq!(dt.date_naive(), dt.date().naive_local());
+
+    let dt = FixedOffset::west(3600).ymd(2022, 1, 1).unwrap().and_hms(23, 30, 0).unwrap();
+    assert_eq!(dt.date_naive(), ymd!(2022, 1, 1));
+    assert_eq!(dt.naive_utc().date(), ymd!(2022, 1, 2));
+}
+
+#[test]
+fn test_datetime_with_second_checked() {
+    let invalid = ChronoError::new(ChronoErrorKind::InvalidTime);
+
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 12, 250).unwrap();
+    let leap = dt.with_second_checked(60).unwrap();
+    assert_eq!(leap.to_string(), "2016-12-31 23:59:60.250 UTC");
+    assert_eq!((leap.second(), leap.nanosecond()), (59, 1_250_000_000));
+    assert_eq!(leap.with_second_checked(60), Ok(leap));
+    assert_eq!(
+        leap + TimeDelta::milliseconds(750),
+        Utc.ymd(2017, 1, 1).unwrap().and_hms(0, 0, 0).unwrap()
+    );
+    assert_eq!(dt.with_second_checked(0).unwrap().to_string(), "2016-12-31 23:59:00.250 UTC");
+    assert_eq!(dt.with_second_checked(61), Err(invalid));
+
+    // only at the end of the day
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms(10, 0, 0).unwrap();
+    assert_eq!(dt.with_second_checked(60), Err(invalid));
+    assert_eq!(dt.with_second_checked(59).unwrap().to_string(), "2016-12-31 10:00:59 UTC");
+    let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms(23, 58, 59).unwrap();
+    assert_eq!(dt.with_second_checked(60), Err(invalid));
+
+    // the position is checked in UTC, and the offset is kept
+    let dt =
+        FixedOffset::east(5 * 3600 + 30 * 60).ymd(2017, 1, 1).unwrap().and_hms(5, 29, 0).unwrap();
+    let leap = dt.with_second_checked(60).unwrap();
+    assert_eq!(leap.to_string(), "2017-01-01 05:29:60 +05:30");
+    assert_eq!(leap.offset(), dt.offset());
+    let dt = FixedOffset::east(3600).ymd(2016, 12, 31).unwrap().and_hms(23, 59, 0).unwrap();
+    assert_eq!(dt.with_second_checked(60), Err(invalid));
+
+    // the local second 60 wouldn't be the UTC leap second
+    let dt = FixedOffset::east(30).ymd(2017, 1, 1).unwrap().and_hms(0, 0, 20).unwrap();
+    assert_eq!(dt.with_second_checked(60), Err(invalid));
+}
+
+#[test]
+fn test_datetime_step_by_duration() {
+    // 2022-03-27 has only 23 hours in the local time
+    let start = DstTester.ymd(2022, 3, 27).unwrap().and_hms(0, 0, 0).unwrap();
+    let end = DstTester.ymd(2022, 3, 28).unwrap().and_hms(0, 0, 0).unwrap();
+    let hours: Vec<_> = start.step_by_duration_until(end, TimeDelta::hours(1)).collect();
+    assert_eq!(hours.len(), 23);
+    assert_eq!(hours[1].to_string(), "2022-03-27 01:00:00 +01:00");
+    assert_eq!(hours[2].to_string(), "2022-03-27 03:00:00 +02:00");
+    assert_eq!(hours[22].to_string(), "2022-03-27 23:00:00 +02:00");
+    assert!(hours.windows(2).all(|w| w[1] - w[0] == TimeDelta::hours(1)));
+
+    // 2022-10-30 has 25 hours, with 02:00 occurring twice
+    let start = DstTester.ymd(2022, 10, 30).unwrap().and_hms(0, 0, 0).unwrap();
+    let hours: Vec<_> = start.step_by_duration(TimeDelta::hours(1)).take(25).collect();
+    let local: Vec<_> = hours.iter().map(|dt| dt.format("%H %:z").to_string()).collect();
+    assert_eq!(&local[..5], ["00 +02:00", "01 +02:00", "02 +02:00", "02 +01:00", "03 +01:00"]);
+    assert_eq!(hours[24].to_string(), "2022-10-30 23:00:00 +01:00");
+
+    // stepping backwards, with `end` excluded either way
+    let end = start - TimeDelta::hours(3);
+    let back: Vec<_> = start.step_by_duration_until(end, TimeDelta::hours(-1)).collect();
+    assert_eq!(back.len(), 3);
+    assert_eq!(back[2].to_string(), "2022-10-29 22:00:00 +02:00");
+    assert_eq!(start.step_by_duration_until(start, TimeDelta::hours(1)).count(), 0);
+    assert_eq!(start.step_by_duration_until(end, TimeDelta::hours(1)).count(), 0);
+
+    // the iteration ends gracefully at the end of the supported range
+    let last = DateTime::<Utc>::MAX_UTC - TimeDelta::minutes(150);
+    let mut iter = last.step_by_duration(TimeDelta::hours(1));
+    assert_eq!(iter.by_ref().count(), 3);
+    assert_eq!(iter.next(), None);
+    let first = DateTime::<Utc>::MIN_UTC + TimeDelta::seconds(1);
+    assert_eq!(first.step_by_duration(TimeDelta::seconds(-1)).count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "DateTime::step_by_duration step must not be zero")]
+fn test_datetime_step_by_zero_duration() {
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    let _ = dt.step_by_duration(TimeDelta::zero());
+}
+
+#[test]
+fn test_interval() {
+    let at = |h| Utc.ymd(2022, 6, 1).unwrap().and_hms(h, 0, 0).unwrap();
+    let interval = |start, end| Interval::new(at(start), at(end)).unwrap();
+
+    let morning = interval(8, 12);
+    assert_eq!(morning.start(), &at(8));
+    assert_eq!(morning.end(), &at(12));
+    assert_eq!(morning.duration(), TimeDelta::hours(4));
+    assert!(morning.contains(&at(8)));
+    assert!(morning.contains(&at(11)));
+    assert!(!morning.contains(&at(12)));
+    assert!(!morning.contains(&at(7)));
+
+    // overlapping
+    let meeting = interval(11, 13);
+    assert!(morning.overlaps(&meeting));
+    assert!(meeting.overlaps(&morning));
+    assert_eq!(morning.intersection(&meeting), Some(interval(11, 12)));
+    assert_eq!(meeting.intersection(&morning), Some(interval(11, 12)));
+    assert_eq!(morning.intersection(&interval(9, 10)), Some(interval(9, 10)));
+    assert_eq!(morning.intersection(&morning), Some(morning.clone()));
+
+    // adjacent
+    let afternoon = interval(12, 17);
+    assert!(!morning.overlaps(&afternoon));
+    assert!(!afternoon.overlaps(&morning));
+    assert_eq!(morning.intersection(&afternoon), None);
+
+    // disjoint
+    let evening = interval(18, 22);
+    assert!(!morning.overlaps(&evening));
+    assert_eq!(evening.intersection(&morning), None);
+
+    // empty
+    let empty = interval(10, 10);
+    assert!(empty.is_empty());
+    assert!(!morning.is_empty());
+    assert_eq!(empty.duration(), TimeDelta::zero());
+    assert!(!empty.contains(&at(10)));
+    assert!(!empty.overlaps(&morning));
+    assert!(!morning.overlaps(&empty));
+
+    assert_eq!(
+        Interval::new(at(12), at(8)).unwrap_err(),
+        ChronoError::new(ChronoErrorKind::InvalidInterval)
+    );
+
+    // compared by instant
+    let cest = FixedOffset::east(2 * 3600);
+    let local =
+        Interval::new(at(8).with_timezone(&cest).unwrap(), at(12).with_timezone(&cest).unwrap())
+            .unwrap();
+    assert_eq!(local, morning);
+    assert!(local.contains(&at(8)));
+    assert!(local.overlaps(&meeting));
+    assert_eq!(local.duration(), TimeDelta::hours(4));
+}
+
+#[test]
+fn test_datetime_utc_from_timestamp() {
+    assert_eq!(DateTime::<Utc>::from_timestamp(1_000_000_000, 5), Utc.timestamp(1_000_000_000, 5));
+    assert_eq!(
+        DateTime::<Utc>::from_timestamp(-1, 0).unwrap().to_string(),
+        "1969-12-31 23:59:59 UTC"
+    );
+    assert!(DateTime::<Utc>::from_timestamp(i64::MAX, 0).is_err());
+    assert!(DateTime::<Utc>::from_timestamp(0, 2_000_000_000).is_err());
+
+    assert_eq!(DateTime::<Utc>::from_timestamp_millis(1_500), Utc.timestamp(1, 500_000_000));
+    assert_eq!(DateTime::<Utc>::from_timestamp_millis(-1_500), Utc.timestamp(-2, 500_000_000));
+    assert!(DateTime::<Utc>::from_timestamp_millis(i64::MAX).is_err());
+}
+
+#[test]
+fn test_datetime_fixed_offset() {
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    let fixed = dt.fixed_offset();
+    assert_eq!(fixed.offset(), &FixedOffset::east(0));
+    assert_eq!(fixed.timestamp(), dt.timestamp());
+    assert_eq!(fixed.to_string(), "2022-06-01 12:00:00 +00:00");
+
+    let dt = FixedOffset::west(5 * 3600).ymd(2022, 6, 1).unwrap().and_hms(8, 0, 0).unwrap();
+    assert_eq!(dt.fixed_offset(), dt);
+    assert_eq!(dt.fixed_offset().offset(), dt.offset());
+
+    // the offset in effect at the instant is kept
+    let dt = DstTester.ymd(2022, 6, 1).unwrap().and_hms(14, 0, 0).unwrap();
+    let fixed = dt.fixed_offset();
+    assert_eq!(fixed.to_string(), "2022-06-01 14:00:00 +02:00");
+    assert_eq!(fixed.timestamp(), dt.timestamp());
+
+    let dt = DstTester.ymd(2022, 12, 1).unwrap().and_hms(14, 0, 0).unwrap();
+    assert_eq!(dt.fixed_offset().to_string(), "2022-12-01 14:00:00 +01:00");
+}
+
+#[test]
+fn test_datetime_cmp_instant() {
+    use std::cmp::Ordering;
+
+    let utc = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    let east =
+        FixedOffset::east(5 * 3600 + 1800).ymd(2022, 6, 1).unwrap().and_hms(17, 30, 0).unwrap();
+    let west = FixedOffset::west(10 * 3600).ymd(2022, 6, 1).unwrap().and_hms(2, 0, 0).unwrap();
+
+    assert!(utc.eq_instant(&east));
+    assert!(east.eq_instant(&west));
+    assert_eq!(utc.cmp_instant(&east), Ordering::Equal);
+    assert_eq!(west.cmp_instant(&utc), Ordering::Equal);
+
+    let later = west + TimeDelta::nanoseconds(1);
+    assert!(!utc.eq_instant(&later));
+    assert_eq!(utc.cmp_instant(&later), Ordering::Less);
+    assert_eq!(later.cmp_instant(&east), Ordering::Greater);
+
+    // the local wall clock doesn't matter
+    let dst = DstTester.ymd(2022, 6, 1).unwrap().and_hms(13, 0, 0).unwrap();
+    assert_eq!(dst.cmp_instant(&utc), Ordering::Less);
+    assert_eq!(dst.cmp_instant(&(utc - TimeDelta::hours(1))), Ordering::Equal);
+}
+
+#[test]
+fn test_datetime_timezone() {
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.timezone(), Utc);
+    let other = dt.timezone().ymd(2000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(other.to_string(), "2000-01-01 00:00:00 UTC");
+
+    let tz = FixedOffset::east(5 * 3600 + 1800);
+    let dt = tz.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.timezone(), tz);
+    let other = dt.timezone().ymd(2000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(other.to_string(), "2000-01-01 00:00:00 +05:30");
+    assert_eq!(other.offset(), dt.offset());
+}
+
+#[test]
+fn test_datetime_saturating_add_signed() {
+    let near_max = DateTime::<Utc>::MAX_UTC - TimeDelta::days(1);
+    assert_eq!(near_max.saturating_add_signed(TimeDelta::days(365)), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(near_max.saturating_add_signed(TimeDelta::max_value()), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(near_max.saturating_sub_signed(TimeDelta::min_value()), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(near_max.saturating_add_signed(TimeDelta::hours(1)), near_max + TimeDelta::hours(1));
+
+    let near_min = DateTime::<Utc>::MIN_UTC + TimeDelta::days(1);
+    assert_eq!(near_min.saturating_sub_signed(TimeDelta::days(365)), DateTime::<Utc>::MIN_UTC);
+    assert_eq!(near_min.saturating_add_signed(TimeDelta::min_value()), DateTime::<Utc>::MIN_UTC);
+    assert_eq!(near_min.saturating_sub_signed(TimeDelta::hours(1)), near_min - TimeDelta::hours(1));
+
+    // the offset is kept, and the local date and time stays in range
+    let tz = FixedOffset::east(9 * 3600);
+    let dt = tz.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    let max = dt.saturating_add_signed(TimeDelta::max_value());
+    assert_eq!(max.offset(), &tz);
+    assert_eq!(max.naive_local(), NaiveDateTime::MAX);
+    assert_eq!(max, DateTime::<Utc>::MAX_UTC - TimeDelta::hours(9));
+
+    let tz = FixedOffset::west(9 * 3600);
+    let dt = tz.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    let min = dt.saturating_sub_signed(TimeDelta::max_value());
+    assert_eq!(min.offset(), &tz);
+    assert_eq!(min.naive_local(), NaiveDateTime::MIN);
+    assert_eq!(min, DateTime::<Utc>::MIN_UTC + TimeDelta::hours(9));
+}
+
+#[test]
+fn test_datetime_checked_add_signed_overflow() {
+    let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+
+    let dt = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(dt.checked_add_signed(TimeDelta::nanoseconds(1)), overflow);
+    assert_eq!(dt.checked_sub_signed(TimeDelta::nanoseconds(-1)), overflow);
+    assert_eq!(dt.checked_add_signed(TimeDelta::max_value()), overflow);
+    assert_eq!(dt.checked_sub_signed(TimeDelta::days(1)), Ok(dt - TimeDelta::days(1)));
+
+    let dt = FixedOffset::east(3600).from_utc_datetime(&NaiveDateTime::MIN).unwrap();
+    assert_eq!(
+        dt.checked_sub_signed(TimeDelta::seconds(1)).map_err(|e| e.to_string()),
+        Err("date out of range".to_string())
+    );
+    assert_eq!(
+        dt.checked_add_signed(TimeDelta::min_value()),
+        Err(ChronoError::new(ChronoErrorKind::Overflow))
+    );
+}
+
+#[test]
+fn test_datetime_to_utc() {
+    let fixed_dt = FixedOffset::west(4 * 3600).ymd(2022, 6, 1).unwrap().and_hms(22, 0, 0).unwrap();
+    let utc = fixed_dt.to_utc();
+    assert_eq!(utc.timestamp(), fixed_dt.timestamp());
+    assert_eq!(utc.to_string(), "2022-06-02 02:00:00 UTC");
+    assert_eq!(utc, fixed_dt);
+    assert_eq!(utc.naive_utc(), fixed_dt.naive_utc());
+
+    let dt = DstTester.ymd(2022, 6, 1).unwrap().and_hms(1, 0, 0).unwrap();
+    assert_eq!(dt.to_utc().to_string(), "2022-05-31 23:00:00 UTC");
+
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.to_utc(), dt);
+}
+
+#[test]
+fn test_datetime_hash_by_instant() {
+    use std::collections::HashSet;
+
+    let utc = FixedOffset::east(0).ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    let ist =
+        FixedOffset::east(5 * 3600 + 1800).ymd(2022, 6, 1).unwrap().and_hms(17, 30, 0).unwrap();
+    assert_eq!(utc, ist);
+
+    let mut set = HashSet::new();
+    assert!(set.insert(utc));
+    assert!(!set.insert(ist));
+    assert_eq!(set.len(), 1);
+
+    // the same wall clock time in another offset is a different instant
+    let other =
+        FixedOffset::east(5 * 3600 + 1800).ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert!(set.insert(other));
+    assert_eq!(set.len(), 2);
+
+    // `ByInstant` gives the same results
+    let set: HashSet<_> = [utc, ist, other].iter().cloned().map(ByInstant).collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // a fixed xorshift sequence, so failures can be reproduced
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let bytes: Vec<u8> = (0..64 * 1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+    let mut u = Unstructured::new(&bytes);
+
+    for _ in 0..1000 {
+        let date = NaiveDate::arbitrary(&mut u).unwrap();
+        assert_eq!(NaiveDate::from_ymd(date.year(), date.month(), date.day()), Ok(date));
+
+        let time = NaiveTime::arbitrary(&mut u).unwrap();
+        let (secs, nano) = (time.num_seconds_from_midnight(), time.nanosecond());
+        assert_eq!(NaiveTime::from_num_seconds_from_midnight(secs, nano), Ok(time));
+        assert!(nano < 1_000_000_000 || time.second() == 59);
+
+        let offset = FixedOffset::arbitrary(&mut u).unwrap();
+        assert_eq!(FixedOffset::east_opt(offset.local_minus_utc()), Some(offset));
+
+        let dt = NaiveDateTime::arbitrary(&mut u).unwrap();
+        assert!(dt >= NaiveDateTime::MIN && dt <= NaiveDateTime::MAX);
+        assert_eq!(dt.date().and_time(dt.time()), dt);
+
+        let dt = DateTime::<Utc>::arbitrary(&mut u).unwrap();
+        assert_eq!(Utc.timestamp(dt.timestamp(), dt.timestamp_subsec_nanos()), Ok(dt));
+    }
+    assert!(!u.is_empty());
+}
+
+#[test]
+fn test_datetime_write_to() {
+    use crate::format::StrftimeItems;
+    use core::fmt;
+
+    /// A fixed-size buffer, as used without an allocator.
+    struct Buf {
+        bytes: [u8; 40],
+        len: usize,
+    }
+
+    impl Buf {
+        fn new() -> Buf {
+            Buf { bytes: [0; 40], len: 0 }
+        }
+
+        fn as_str(&self) -> &str {
+            std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let utc = Utc.ymd(2022, 6, 1).unwrap().and_hms_micro(12, 0, 0, 250).unwrap();
+    let ist = utc.with_timezone(&FixedOffset::east(5 * 3600 + 1800)).unwrap();
+    let leap = Utc.ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 1_500).unwrap();
+
+    for dt in &[utc.fixed_offset(), ist, leap.fixed_offset()] {
+        let mut buf = Buf::new();
+        dt.write_rfc3339(&mut buf).unwrap();
+        assert_eq!(buf.as_str(), dt.to_rfc3339());
+
+        let mut buf = Buf::new();
+        let fmt = "%a %d %b %Y %H:%M:%S%.3f %:z";
+        dt.write_to(&mut buf, StrftimeItems::new(fmt)).unwrap();
+        assert_eq!(buf.as_str(), dt.format(fmt).to_string());
+    }
+
+    // %Z prints the same name as with `format`
+    let mut buf = Buf::new();
+    utc.write_to(&mut buf, StrftimeItems::new("%Z %z")).unwrap();
+    assert_eq!(buf.as_str(), "UTC +0000");
+    assert_eq!(buf.as_str(), utc.format("%Z %z").to_string());
+
+    // errors from the writer and from the items are passed on
+    let mut buf = Buf::new();
+    assert!(utc.write_to(&mut buf, StrftimeItems::new("%Y%Y%Y%Y%Y%Y%Y%Y%Y%Y%Y")).is_err());
+    assert!(utc.write_to(&mut Buf::new(), StrftimeItems::new("%Q")).is_err());
+}
@@ -8,6 +8,12 @@ pub(crate) enum ChronoErrorKind {
     InvalidDateTime,
     AmbiguousDate,
     SystemTimeBeforeEpoch,
+    InvalidFormat,
+    InvalidDuration,
+    UnsupportedDurationUnit,
+    InvalidTimeZone,
+    InvalidInterval,
+    Overflow,
 }
 
 /// The error raised for an invalid date time.
@@ -32,6 +38,14 @@ impl fmt::Display for ChronoError {
             ChronoErrorKind::InvalidDateTime => write!(f, "invalid date time"),
             ChronoErrorKind::AmbiguousDate => write!(f, "tried to operate over ambiguous date"),
             ChronoErrorKind::SystemTimeBeforeEpoch => write!(f, "system time before Unix epoch"),
+            ChronoErrorKind::InvalidFormat => write!(f, "invalid or unsupported format string"),
+            ChronoErrorKind::InvalidDuration => write!(f, "invalid ISO 8601 duration"),
+            ChronoErrorKind::UnsupportedDurationUnit => {
+                write!(f, "years and months are not supported in durations")
+            }
+            ChronoErrorKind::InvalidTimeZone => write!(f, "invalid time zone string"),
+            ChronoErrorKind::InvalidInterval => write!(f, "interval ends before it starts"),
+            ChronoErrorKind::Overflow => write!(f, "date out of range"),
         }
     }
 }
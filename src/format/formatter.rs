@@ -0,0 +1,118 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A reusable, pre-compiled formatter.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{write_items, Item, OffsetName, StrftimeItems};
+use crate::error::ChronoErrorKind;
+use crate::offset::{Offset, TimeZone};
+use crate::{ChronoError, DateTime};
+
+/// A format string which has been compiled once into formatting items.
+///
+/// Formatting through [`DateTime::format`] re-parses the format string every
+/// time the value is displayed. A `Formatter` does that work up front, so it
+/// can be stored and reused to format many values with the same pattern.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::Formatter;
+/// use chrono::{TimeZone, Utc};
+///
+/// let formatter = Formatter::new("%Y-%m-%d %H:%M:%S")?;
+///
+/// let dt = Utc.ymd(2020, 11, 10)?.and_hms(0, 1, 32)?;
+/// assert_eq!(formatter.format(&dt)?, "2020-11-10 00:01:32");
+///
+/// assert!(Formatter::new("%Y-%Q").is_err());
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formatter {
+    items: Vec<Item<'static>>,
+}
+
+impl Formatter {
+    /// Compiles the given `strftime`-like format string.
+    ///
+    /// See the [`crate::format::strftime`] module on the supported escape
+    /// sequences.
+    ///
+    /// Returns `Err(ChronoError)` if the format string contains an invalid or
+    /// unsupported specifier.
+    pub fn new(fmt: &str) -> Result<Formatter, ChronoError> {
+        let items = StrftimeItems::new(fmt)
+            .parse_to_owned()
+            .map_err(|_| ChronoError::new(ChronoErrorKind::InvalidFormat))?;
+        Ok(Formatter { items })
+    }
+
+    /// Returns the compiled formatting items.
+    pub fn items(&self) -> &[Item<'static>] {
+        &self.items
+    }
+
+    /// Formats the given date and time using the compiled items.
+    pub fn format<Tz>(&self, dt: &DateTime<Tz>) -> Result<String, ChronoError>
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        let local = dt.naive_local();
+        let (date, time) = (local.date(), local.time());
+        let name = OffsetName(dt.offset());
+        let off = (&name as &dyn fmt::Display, dt.offset().fix());
+
+        let mut result = String::new();
+        write_items(&mut result, Some(&date), Some(&time), Some(off), self.items.iter())
+            .map_err(|_| ChronoError::new(ChronoErrorKind::InvalidFormat))?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Formatter;
+    use crate::{FixedOffset, TimeZone, Utc};
+
+    #[test]
+    fn test_formatter_reuse() {
+        let formatter = Formatter::new("%Y-%m-%dT%H:%M:%S%.3f %:z").unwrap();
+
+        let dt = Utc.ymd(2001, 7, 8).unwrap().and_hms_milli(0, 34, 59, 26).unwrap();
+        assert_eq!(formatter.format(&dt).unwrap(), "2001-07-08T00:34:59.026 +00:00");
+
+        let dt = FixedOffset::east(34200).ymd(2015, 2, 18).unwrap().and_hms(23, 16, 9).unwrap();
+        assert_eq!(formatter.format(&dt).unwrap(), "2015-02-18T23:16:09.000 +09:30");
+
+        let dt = Utc.ymd(-1, 12, 31).unwrap().and_hms(23, 59, 59).unwrap();
+        assert_eq!(formatter.format(&dt).unwrap(), "-0001-12-31T23:59:59.000 +00:00");
+    }
+
+    #[test]
+    fn test_formatter_matches_format() {
+        let dt = FixedOffset::west(3600).ymd(2022, 3, 4).unwrap().and_hms(5, 6, 7).unwrap();
+
+        for fmt in &["%c", "%+", "%a %b %e %T %Y", "%s", "100%% %j"] {
+            let formatter = Formatter::new(fmt).unwrap();
+            assert_eq!(formatter.format(&dt).unwrap(), dt.format(fmt).to_string());
+        }
+    }
+
+    #[test]
+    fn test_formatter_invalid_pattern() {
+        assert!(Formatter::new("%").is_err());
+        assert!(Formatter::new("%Y-%Q").is_err());
+        assert!(Formatter::new("%-Z").is_err());
+    }
+}
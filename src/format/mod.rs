@@ -14,6 +14,7 @@
 //! They are generated from more readable **format strings**;
 //! currently Chrono supports a built-in syntax closely resembling
 //! C's `strftime` format. The available options can be found [here](./strftime/index.html).
+//! Format strings for common log timestamps are in the [`presets`](./presets/index.html) module.
 //!
 //! # Example
 //! ```rust
@@ -37,25 +38,23 @@ extern crate alloc;
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::string::{String, ToString};
-#[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::fmt;
 use core::str::FromStr;
 #[cfg(any(feature = "std", test))]
 use std::error::Error;
 
-#[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::naive::{NaiveDate, NaiveTime};
-#[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::offset::{FixedOffset, Offset};
-#[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::{Datelike, Timelike};
 use crate::{Month, ParseMonthError, ParseWeekdayError, Weekday};
 
 #[cfg(feature = "unstable-locales")]
 pub(crate) mod locales;
 
-pub use parse::parse;
+#[cfg(any(feature = "alloc", feature = "std", test))]
+pub use parse::parse_collect_errors;
+pub use parse::{parse, parse_and_remainder, parse_with_mode, ParseMode};
 pub use parsed::Parsed;
 /// L10n locales.
 #[cfg(feature = "unstable-locales")]
@@ -140,6 +139,7 @@ pub enum Numeric {
     Nanosecond,
     /// The number of non-leap seconds since the midnight UTC on January 1, 1970 (FW=1, PW=∞).
     /// For formatting, it assumes UTC upon the absence of time zone offset.
+    /// May be negative for times before the epoch, given an initial sign.
     Timestamp,
 
     /// Internal uses only.
@@ -272,9 +272,8 @@ enum InternalInternal {
     /// Same as [`TimezoneOffsetColonZ`](#variant.TimezoneOffsetColonZ), but
     /// allows missing minutes (per [ISO 8601][iso8601]).
     ///
-    /// # Panics
-    ///
-    /// If you try to use this for printing.
+    /// When printing, this uses the shortest form which represents the offset
+    /// exactly, e.g. `+09`, `+09:30` or `+09:30:15`.
     ///
     /// [iso8601]: https://en.wikipedia.org/wiki/ISO_8601#Time_offsets_from_UTC
     TimezoneOffsetPermissive,
@@ -286,13 +285,13 @@ enum InternalInternal {
     Nanosecond9NoDot,
 }
 
-#[cfg(any(feature = "alloc", feature = "std", test))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Colons {
     None,
     Single,
     Double,
     Triple,
+    Shortest,
 }
 
 /// A single formatting item. This is used for both formatting and parsing.
@@ -319,6 +318,64 @@ pub enum Item<'a> {
     Error,
 }
 
+impl<'a> Item<'a> {
+    /// Makes a literally printed and parsed [`Item::Literal`].
+    ///
+    /// The constructors make it easy to assemble formatting items without a
+    /// format string, e.g. for dynamically chosen formats:
+    ///
+    /// ```
+    /// use chrono::format::{Item, Numeric, Pad};
+    /// use chrono::NaiveDate;
+    ///
+    /// let items = [
+    ///     Item::numeric(Numeric::Year, Pad::Zero),
+    ///     Item::literal("-"),
+    ///     Item::numeric(Numeric::Month, Pad::Zero),
+    /// ];
+    /// let d = NaiveDate::from_ymd(2022, 3, 4)?;
+    /// assert_eq!(d.format_with_items(items.iter()).to_string(), "2022-03");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub const fn literal(s: &'a str) -> Item<'a> {
+        Item::Literal(s)
+    }
+
+    /// Makes an [`Item::Space`], which prints literally but reads zero or more
+    /// whitespace.
+    pub const fn space(s: &'a str) -> Item<'a> {
+        Item::Space(s)
+    }
+
+    /// Makes an [`Item::Numeric`] with the given padding.
+    pub const fn numeric(numeric: Numeric, pad: Pad) -> Item<'a> {
+        Item::Numeric(numeric, pad)
+    }
+
+    /// Makes an [`Item::Fixed`].
+    pub const fn fixed(fixed: Fixed) -> Item<'a> {
+        Item::Fixed(fixed)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<'a> Item<'a> {
+    /// Converts this item into an owned `Item<'static>`, copying any borrowed
+    /// literal or whitespace text.
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_owned(self) -> Item<'static> {
+        match self {
+            Item::Literal(s) => Item::OwnedLiteral(Box::from(s)),
+            Item::OwnedLiteral(s) => Item::OwnedLiteral(s),
+            Item::Space(s) => Item::OwnedSpace(Box::from(s)),
+            Item::OwnedSpace(s) => Item::OwnedSpace(s),
+            Item::Numeric(spec, pad) => Item::Numeric(spec, pad),
+            Item::Fixed(spec) => Item::Fixed(spec),
+            Item::Error => Item::Error,
+        }
+    }
+}
+
 macro_rules! lit {
     ($x:expr) => {
         Item::Literal($x)
@@ -397,6 +454,17 @@ pub enum ParseErrorKind {
     /// There was an error on the formatting string, or there were non-supported formating items.
     BadFormat,
 
+    /// The input has an offset which doesn't match the offset of the time zone
+    /// at the parsed date and time, e.g. when parsing a string with `+01:00`
+    /// via [`TimeZone::datetime_from_str`](../offset/trait.TimeZone.html#method.datetime_from_str)
+    /// on [`Utc`](../offset/struct.Utc.html).
+    OffsetMismatch {
+        /// The offset in the input.
+        parsed: FixedOffset,
+        /// The offset of the time zone.
+        expected: FixedOffset,
+    },
+
     // TODO: Change this to `#[non_exhaustive]` (on the enum) when MSRV is increased
     #[doc(hidden)]
     __Nonexhaustive,
@@ -415,6 +483,9 @@ impl fmt::Display for ParseError {
             ParseErrorKind::TooShort => write!(f, "premature end of input"),
             ParseErrorKind::TooLong => write!(f, "trailing input"),
             ParseErrorKind::BadFormat => write!(f, "bad or unsupported format string"),
+            ParseErrorKind::OffsetMismatch { parsed, expected } => {
+                write!(f, "offset {} doesn't match the time zone offset {}", parsed, expected)
+            }
             _ => unreachable!(),
         }
     }
@@ -449,16 +520,43 @@ pub fn format_item<'a>(
     item: &Item<'a>,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, None)?;
+    format_inner(&mut result, date, time, name_and_offset(off), item, None)?;
     w.pad(&result)
 }
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
-fn format_inner<'a>(
-    result: &mut String,
+fn name_and_offset(
+    off: Option<&(String, FixedOffset)>,
+) -> Option<(&dyn fmt::Display, FixedOffset)> {
+    off.map(|&(ref name, off)| (name as &dyn fmt::Display, off))
+}
+
+/// Writes the given formatting items into `w`, without allocating.
+///
+/// The offset is given as the name `%Z` prints and the offset itself.
+pub(crate) fn write_items<'a, W, I, B>(
+    w: &mut W,
     date: Option<&NaiveDate>,
     time: Option<&NaiveTime>,
-    off: Option<&(String, FixedOffset)>,
+    off: Option<(&dyn fmt::Display, FixedOffset)>,
+    items: I,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    for item in items {
+        format_inner(w, date, time, off, item.borrow(), None)?;
+    }
+    Ok(())
+}
+
+fn format_inner<'a, W: fmt::Write>(
+    result: &mut W,
+    date: Option<&NaiveDate>,
+    time: Option<&NaiveTime>,
+    off: Option<(&dyn fmt::Display, FixedOffset)>,
     item: &Item<'a>,
     _locale: Option<Locale>,
 ) -> fmt::Result {
@@ -500,13 +598,12 @@ fn format_inner<'a>(
         )
     };
 
-    use core::fmt::Write;
     use num_integer::{div_floor, mod_floor};
 
     match *item {
-        Item::Literal(s) | Item::Space(s) => result.push_str(s),
+        Item::Literal(s) | Item::Space(s) => result.write_str(s)?,
         #[cfg(any(feature = "alloc", feature = "std", test))]
-        Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s) => result.push_str(s),
+        Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s) => result.write_str(s)?,
 
         Item::Numeric(ref spec, ref pad) => {
             use self::Numeric::*;
@@ -542,7 +639,7 @@ fn format_inner<'a>(
                     1,
                     match (date, time, off) {
                         (Some(d), Some(t), None) => Some(d.and_time(*t).timestamp()),
-                        (Some(d), Some(t), Some(&(_, off))) => {
+                        (Some(d), Some(t), Some((_, off))) => {
                             Some((d.and_time(*t) - off).timestamp())
                         }
                         (_, _, _) => None,
@@ -578,8 +675,8 @@ fn format_inner<'a>(
 
             /// Prints an offset from UTC in the format of `+HHMM` or `+HH:MM`.
             /// `Z` instead of `+00[:]00` is allowed when `allow_zulu` is true.
-            fn write_local_minus_utc(
-                result: &mut String,
+            fn write_local_minus_utc<W: fmt::Write>(
+                result: &mut W,
                 off: FixedOffset,
                 allow_zulu: bool,
                 colon_type: Colons,
@@ -608,141 +705,142 @@ fn format_inner<'a>(
                         Colons::Triple => {
                             write!(result, "{}{:02}", sign, off / 3600)
                         }
+                        Colons::Shortest => {
+                            let (hour, min, sec) = (off / 3600, off / 60 % 60, off % 60);
+                            if sec != 0 {
+                                write!(result, "{}{:02}:{:02}:{:02}", sign, hour, min, sec)
+                            } else if min != 0 {
+                                write!(result, "{}{:02}:{:02}", sign, hour, min)
+                            } else {
+                                write!(result, "{}{:02}", sign, hour)
+                            }
+                        }
                     }
                 } else {
-                    result.push('Z');
-                    Ok(())
+                    result.write_char('Z')
                 }
             }
 
-            let ret =
-                match *spec {
-                    ShortMonthName => date.map(|d| {
-                        result.push_str(short_months[d.month0() as usize]);
-                        Ok(())
-                    }),
-                    LongMonthName => date.map(|d| {
-                        result.push_str(long_months[d.month0() as usize]);
-                        Ok(())
-                    }),
-                    ShortWeekdayName => date.map(|d| {
-                        result
-                            .push_str(short_weekdays[d.weekday().num_days_from_sunday() as usize]);
-                        Ok(())
-                    }),
-                    LongWeekdayName => date.map(|d| {
-                        result.push_str(long_weekdays[d.weekday().num_days_from_sunday() as usize]);
-                        Ok(())
-                    }),
-                    LowerAmPm => time.map(|t| {
-                        #[cfg_attr(feature = "cargo-clippy", allow(clippy::useless_asref))]
-                        {
-                            result.push_str(if t.hour12().0 {
-                                am_pm_lowercase[1].as_ref()
-                            } else {
-                                am_pm_lowercase[0].as_ref()
-                            });
-                        }
-                        Ok(())
-                    }),
-                    UpperAmPm => time.map(|t| {
-                        result.push_str(if t.hour12().0 { am_pm[1] } else { am_pm[0] });
-                        Ok(())
-                    }),
-                    Nanosecond => time.map(|t| {
-                        let nano = t.nanosecond() % 1_000_000_000;
-                        if nano == 0 {
-                            Ok(())
-                        } else if nano % 1_000_000 == 0 {
-                            write!(result, ".{:03}", nano / 1_000_000)
-                        } else if nano % 1_000 == 0 {
-                            write!(result, ".{:06}", nano / 1_000)
+            let ret = match *spec {
+                ShortMonthName => date.map(|d| result.write_str(short_months[d.month0() as usize])),
+                LongMonthName => date.map(|d| result.write_str(long_months[d.month0() as usize])),
+                ShortWeekdayName => date.map(|d| {
+                    result.write_str(short_weekdays[d.weekday().num_days_from_sunday() as usize])
+                }),
+                LongWeekdayName => date.map(|d| {
+                    result.write_str(long_weekdays[d.weekday().num_days_from_sunday() as usize])
+                }),
+                LowerAmPm => time.map(|t| {
+                    #[cfg_attr(feature = "cargo-clippy", allow(clippy::useless_asref))]
+                    {
+                        result.write_str(if t.hour12().0 {
+                            am_pm_lowercase[1].as_ref()
                         } else {
-                            write!(result, ".{:09}", nano)
-                        }
-                    }),
-                    Nanosecond3 => time.map(|t| {
-                        let nano = t.nanosecond() % 1_000_000_000;
+                            am_pm_lowercase[0].as_ref()
+                        })
+                    }
+                }),
+                UpperAmPm => {
+                    time.map(|t| result.write_str(if t.hour12().0 { am_pm[1] } else { am_pm[0] }))
+                }
+                Nanosecond => time.map(|t| {
+                    let nano = t.nanosecond() % 1_000_000_000;
+                    if nano == 0 {
+                        Ok(())
+                    } else if nano % 1_000_000 == 0 {
                         write!(result, ".{:03}", nano / 1_000_000)
-                    }),
-                    Nanosecond6 => time.map(|t| {
-                        let nano = t.nanosecond() % 1_000_000_000;
+                    } else if nano % 1_000 == 0 {
                         write!(result, ".{:06}", nano / 1_000)
-                    }),
-                    Nanosecond9 => time.map(|t| {
-                        let nano = t.nanosecond() % 1_000_000_000;
+                    } else {
                         write!(result, ".{:09}", nano)
-                    }),
-                    Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => time
-                        .map(|t| {
-                            let nano = t.nanosecond() % 1_000_000_000;
-                            write!(result, "{:03}", nano / 1_000_000)
-                        }),
-                    Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => time
-                        .map(|t| {
-                            let nano = t.nanosecond() % 1_000_000_000;
-                            write!(result, "{:06}", nano / 1_000)
-                        }),
-                    Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => time
-                        .map(|t| {
-                            let nano = t.nanosecond() % 1_000_000_000;
-                            write!(result, "{:09}", nano)
-                        }),
-                    TimezoneName => off.map(|&(ref name, _)| {
-                        result.push_str(name);
-                        Ok(())
-                    }),
-                    TimezoneOffsetColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Single)),
-                    TimezoneOffsetDoubleColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Double)),
-                    TimezoneOffsetTripleColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Triple)),
-                    TimezoneOffsetColonZ => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, true, Colons::Single)),
-                    TimezoneOffset => {
-                        off.map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::None))
-                    }
-                    TimezoneOffsetZ => {
-                        off.map(|&(_, off)| write_local_minus_utc(result, off, true, Colons::None))
                     }
-                    Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
-                        panic!("Do not try to write %#z it is undefined")
-                    }
-                    RFC2822 =>
-                    // same as `%a, %d %b %Y %H:%M:%S %z`
-                    {
-                        if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
-                            let sec = t.second() + t.nanosecond() / 1_000_000_000;
-                            write!(
-                                result,
-                                "{}, {:02} {} {:04} {:02}:{:02}:{:02} ",
-                                short_weekdays[d.weekday().num_days_from_sunday() as usize],
-                                d.day(),
-                                short_months[d.month0() as usize],
-                                d.year(),
-                                t.hour(),
-                                t.minute(),
-                                sec
-                            )?;
-                            Some(write_local_minus_utc(result, off, false, Colons::None))
-                        } else {
-                            None
-                        }
+                }),
+                Nanosecond3 => time.map(|t| {
+                    let nano = t.nanosecond() % 1_000_000_000;
+                    write!(result, ".{:03}", nano / 1_000_000)
+                }),
+                Nanosecond6 => time.map(|t| {
+                    let nano = t.nanosecond() % 1_000_000_000;
+                    write!(result, ".{:06}", nano / 1_000)
+                }),
+                Nanosecond9 => time.map(|t| {
+                    let nano = t.nanosecond() % 1_000_000_000;
+                    write!(result, ".{:09}", nano)
+                }),
+                Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
+                    time.map(|t| {
+                        let nano = t.nanosecond() % 1_000_000_000;
+                        write!(result, "{:03}", nano / 1_000_000)
+                    })
+                }
+                Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => {
+                    time.map(|t| {
+                        let nano = t.nanosecond() % 1_000_000_000;
+                        write!(result, "{:06}", nano / 1_000)
+                    })
+                }
+                Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => {
+                    time.map(|t| {
+                        let nano = t.nanosecond() % 1_000_000_000;
+                        write!(result, "{:09}", nano)
+                    })
+                }
+                TimezoneName => off.map(|(name, _)| write!(result, "{}", name)),
+                TimezoneOffsetColon => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Single))
+                }
+                TimezoneOffsetDoubleColon => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Double))
+                }
+                TimezoneOffsetTripleColon => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Triple))
+                }
+                TimezoneOffsetColonZ => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, true, Colons::Single))
+                }
+                TimezoneOffset => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::None))
+                }
+                TimezoneOffsetZ => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, true, Colons::None))
+                }
+                Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
+                    off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Shortest))
+                }
+                RFC2822 =>
+                // same as `%a, %d %b %Y %H:%M:%S %z`
+                {
+                    if let (Some(d), Some(t), Some((_, off))) = (date, time, off) {
+                        let sec = t.second() + t.nanosecond() / 1_000_000_000;
+                        write!(
+                            result,
+                            "{}, {:02} {} {:04} {:02}:{:02}:{:02} ",
+                            short_weekdays[d.weekday().num_days_from_sunday() as usize],
+                            d.day(),
+                            short_months[d.month0() as usize],
+                            d.year(),
+                            t.hour(),
+                            t.minute(),
+                            sec
+                        )?;
+                        Some(write_local_minus_utc(result, off, false, Colons::None))
+                    } else {
+                        None
                     }
-                    RFC3339 =>
-                    // same as `%Y-%m-%dT%H:%M:%S%.f%:z`
-                    {
-                        if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
-                            // reuse `Debug` impls which already print ISO 8601 format.
-                            // this is faster in this way.
-                            write!(result, "{:?}T{:?}", d, t)?;
-                            Some(write_local_minus_utc(result, off, false, Colons::Single))
-                        } else {
-                            None
-                        }
+                }
+                RFC3339 =>
+                // same as `%Y-%m-%dT%H:%M:%S%.f%:z`
+                {
+                    if let (Some(d), Some(t), Some((_, off))) = (date, time, off) {
+                        // reuse `Debug` impls which already print ISO 8601 format.
+                        // this is faster in this way.
+                        write!(result, "{:?}T{:?}", d, t)?;
+                        Some(write_local_minus_utc(result, off, false, Colons::Single))
+                    } else {
+                        None
                     }
-                };
+                }
+            };
 
             match ret {
                 Some(ret) => ret?,
@@ -772,17 +870,46 @@ where
 {
     let mut result = String::new();
     for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), None)?;
+        format_inner(&mut result, date, time, name_and_offset(off), item.borrow(), None)?;
     }
     w.pad(&result)
 }
 
+/// Escapes `%` in the given text, so it can be embedded in a format string
+/// and is formatted as-is.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::escape_literal;
+/// use chrono::NaiveDate;
+///
+/// let fmt = format!("{} %Y-%m-%d", escape_literal("100% done on"));
+/// assert_eq!(fmt, "100%% done on %Y-%m-%d");
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 1)?;
+/// assert_eq!(date.format(&fmt).to_string(), "100% done on 2022-06-01");
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+#[cfg(any(feature = "alloc", feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn escape_literal(s: &str) -> String {
+    s.replace('%', "%%")
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+mod formatter;
+#[cfg(any(feature = "alloc", feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub use formatter::Formatter;
+
 mod parsed;
 
 // due to the size of parsing routines, they are in separate modules.
 mod parse;
 mod scan;
 
+pub mod presets;
 pub mod strftime;
 
 /// A *temporary* object which can be used as an argument to `format!` or others.
@@ -830,7 +957,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     where
         Off: Offset + fmt::Display,
     {
-        let name_and_diff = (offset.to_string(), offset.fix());
+        let name_and_diff = (offset_name(offset), offset.fix());
         DelayedFormat {
             date,
             time,
@@ -866,11 +993,30 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     where
         Off: Offset + fmt::Display,
     {
-        let name_and_diff = (offset.to_string(), offset.fix());
+        let name_and_diff = (offset_name(offset), offset.fix());
         DelayedFormat { date, time, off: Some(name_and_diff), items, locale: Some(locale) }
     }
 }
 
+/// Returns the name `%Z` prints for the offset.
+#[cfg(any(feature = "alloc", feature = "std", test))]
+fn offset_name<Off: Offset + fmt::Display>(offset: &Off) -> String {
+    OffsetName(offset).to_string()
+}
+
+/// Displays the name `%Z` prints for an offset: its abbreviation if it has
+/// one, or its `Display` output otherwise.
+pub(crate) struct OffsetName<'a, Off>(pub(crate) &'a Off);
+
+impl<'a, Off: Offset + fmt::Display> fmt::Display for OffsetName<'a, Off> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.abbreviation() {
+            Some(name) => f.write_str(name),
+            None => fmt::Display::fmt(self.0, f),
+        }
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std", test))]
 impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> fmt::Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -942,7 +1088,7 @@ pub fn format_item_localized<'a>(
     locale: Locale,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, Some(locale))?;
+    format_inner(&mut result, date, time, name_and_offset(off), item, Some(locale))?;
     w.pad(&result)
 }
 
@@ -964,7 +1110,7 @@ where
 {
     let mut result = String::new();
     for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), Some(locale))?;
+        format_inner(&mut result, date, time, name_and_offset(off), item.borrow(), Some(locale))?;
     }
     w.pad(&result)
 }
@@ -6,6 +6,11 @@
 
 #![allow(deprecated)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::str;
 use core::usize;
@@ -224,6 +229,26 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     Ok((s, ()))
 }
 
+/// Skips leading whitespace, unless parsing in [`ParseMode::Strict`].
+fn trim_left_lenient(s: &str, mode: ParseMode) -> &str {
+    match mode {
+        ParseMode::Strict => s,
+        ParseMode::Lenient => s.trim_left(),
+    }
+}
+
+/// Picks the separator between the hours and minutes of an offset: any colons or whitespace
+/// by default, or exactly the formatted `strict` separator in [`ParseMode::Strict`].
+fn offset_colon(
+    mode: ParseMode,
+    strict: fn(&str) -> ParseResult<&str>,
+) -> fn(&str) -> ParseResult<&str> {
+    match mode {
+        ParseMode::Strict => strict,
+        ParseMode::Lenient => scan::colon_or_space,
+    }
+}
+
 /// Tries to parse given string into `parsed` with given formatting items.
 /// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
 /// There should be no trailing string after parsing;
@@ -245,18 +270,195 @@ where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
-    parse_internal(parsed, s, items).map(|_| ()).map_err(|(_s, e)| e)
+    parse_with_mode(parsed, s, items, ParseMode::Lenient)
+}
+
+/// How strictly the input has to match the formatting items when parsing.
+///
+/// See [`parse_with_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// The input has to look exactly like the formatted output of the items.
+    ///
+    /// - Numeric items padded with zeroes (like `%d` or `%Y`) require exactly
+    ///   as many digits as their width, so `5` is rejected for `%d` while
+    ///   `05` is accepted. Years outside of `0000`--`9999` still need an
+    ///   explicit sign, as they are formatted with one.
+    /// - Numeric items padded with spaces (like `%e`) require their full
+    ///   width, made up of leading spaces followed by digits, like ` 5`.
+    /// - Numeric items without padding (like `%-d` or `%s`) accept one up to
+    ///   their maximum width of digits.
+    /// - No whitespace is skipped before numeric items or offsets, and
+    ///   whitespace in the format has to match the input exactly.
+    /// - Offsets need the same colons as their formatted output, so `%:z`
+    ///   accepts `+09:00` but not `+0900` or `+09 00`.
+    Strict,
+    /// The default parsing mode used by [`parse`].
+    ///
+    /// Padding is ignored for numeric items, so `5`, `05` and ` 5` are all
+    /// accepted for `%d`, and any amount of whitespace (including none) is
+    /// accepted where the format has whitespace or before a number.
+    Lenient,
+}
+
+/// Tries to parse given string into `parsed` with given formatting items,
+/// using the given [`ParseMode`].
+///
+/// [`parse`] is the same as this with [`ParseMode::Lenient`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_with_mode, ParseMode, Parsed, StrftimeItems};
+///
+/// let mut parsed = Parsed::new();
+/// assert!(parse_with_mode(&mut parsed, "05", StrftimeItems::new("%d"), ParseMode::Strict).is_ok());
+///
+/// let mut parsed = Parsed::new();
+/// assert!(parse_with_mode(&mut parsed, "5", StrftimeItems::new("%d"), ParseMode::Strict).is_err());
+///
+/// let mut parsed = Parsed::new();
+/// assert!(parse_with_mode(&mut parsed, "5", StrftimeItems::new("%d"), ParseMode::Lenient).is_ok());
+/// ```
+pub fn parse_with_mode<'a, I, B>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+    mode: ParseMode,
+) -> ParseResult<()>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    parse_internal(parsed, s, items, mode).map(|_| ()).map_err(|(_s, e)| e)
+}
+
+/// Tries to parse given string into `parsed` with given formatting items.
+/// Returns `Ok` with a slice of the unparsed remainder.
+///
+/// This particular date and time parser is the same as [`parse`], except it
+/// doesn't require the string to be fully consumed by the items. Any trailing
+/// input is returned instead of causing an error.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_and_remainder, Parsed, StrftimeItems};
+///
+/// let mut parsed = Parsed::new();
+/// let remainder = parse_and_remainder(&mut parsed, "2020-01-02 rest", StrftimeItems::new("%Y-%m-%d"))?;
+/// assert_eq!(remainder, " rest");
+/// assert_eq!(parsed.to_naive_date()?.to_string(), "2020-01-02");
+/// # Ok::<_, chrono::ParseError>(())
+/// ```
+pub fn parse_and_remainder<'a, 'b, I, B>(
+    parsed: &mut Parsed,
+    s: &'b str,
+    items: I,
+) -> ParseResult<&'b str>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    match parse_internal(parsed, s, items, ParseMode::Lenient) {
+        Ok(s) => Ok(s),
+        Err((s, e)) if e.0 == ParseErrorKind::TooLong => Ok(s),
+        Err((_s, e)) => Err(e),
+    }
+}
+
+/// Tries to parse given string with given formatting items, collecting all
+/// errors instead of stopping at the first one.
+///
+/// Each error comes with the byte offset in `s` of the item that failed.
+/// Parsing continues after a value that could be read but was rejected, like
+/// month `13`. It stops at an error where the input doesn't match the
+/// formatting items, as the position of the next item is unknown then.
+///
+/// Returns the [`Parsed`] fields if there were no errors at all.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_collect_errors, ParseErrorKind, StrftimeItems};
+///
+/// let errors = parse_collect_errors("2022-13-32", StrftimeItems::new("%Y-%m-%d")).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].0, 5);
+/// assert_eq!(errors[0].1.kind(), ParseErrorKind::OutOfRange);
+/// assert_eq!(errors[1].0, 8);
+/// assert_eq!(errors[1].1.kind(), ParseErrorKind::OutOfRange);
+///
+/// let parsed = parse_collect_errors("2022-12-31", StrftimeItems::new("%Y-%m-%d")).unwrap();
+/// assert_eq!(parsed.month, Some(12));
+/// ```
+#[cfg(any(feature = "alloc", feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn parse_collect_errors<'a, I, B>(s: &str, items: I) -> Result<Parsed, Vec<(usize, ParseError)>>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    let mut parsed = Parsed::new();
+    let mut errors = Vec::new();
+    let mut rest = s;
+    for item in items {
+        match parse_item(&mut parsed, rest, item.borrow(), ParseMode::Lenient) {
+            Ok(s_) => rest = s_,
+            Err((s_, e)) => {
+                errors.push((s.len() - rest.len(), e));
+                let rejected_value = match e.0 {
+                    ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => true,
+                    _ => false,
+                };
+                if !rejected_value || s_.len() == rest.len() {
+                    return Err(errors);
+                }
+                rest = s_;
+            }
+        }
+    }
+
+    // if there are trailling chars, it is an error
+    if !rest.is_empty() {
+        errors.push((s.len() - rest.len(), TOO_LONG));
+    }
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
 }
 
 fn parse_internal<'a, 'b, I, B>(
     parsed: &mut Parsed,
     mut s: &'b str,
     items: I,
+    mode: ParseMode,
 ) -> Result<&'b str, (&'b str, ParseError)>
 where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
+    for item in items {
+        s = parse_item(parsed, s, item.borrow(), mode)?;
+    }
+
+    // if there are trailling chars, it is an error
+    if !s.is_empty() {
+        Err((s, TOO_LONG))
+    } else {
+        Ok(s)
+    }
+}
+
+/// Parses a single item at the start of `s`, returning the rest of the input.
+fn parse_item<'a, 'b>(
+    parsed: &mut Parsed,
+    mut s: &'b str,
+    item: &Item<'a>,
+    mode: ParseMode,
+) -> Result<&'b str, (&'b str, ParseError)> {
     macro_rules! try_consume {
         ($e:expr) => {{
             match $e {
@@ -269,202 +471,231 @@ where
         }};
     }
 
-    for item in items {
-        match *item.borrow() {
-            Item::Literal(prefix) => {
-                if s.len() < prefix.len() {
-                    return Err((s, TOO_SHORT));
-                }
-                if !s.starts_with(prefix) {
-                    return Err((s, INVALID));
-                }
-                s = &s[prefix.len()..];
+    match *item {
+        Item::Literal(prefix) => {
+            if s.len() < prefix.len() {
+                return Err((s, TOO_SHORT));
             }
-
-            #[cfg(any(feature = "alloc", feature = "std", test))]
-            Item::OwnedLiteral(ref prefix) => {
-                if s.len() < prefix.len() {
-                    return Err((s, TOO_SHORT));
-                }
-                if !s.starts_with(&prefix[..]) {
-                    return Err((s, INVALID));
-                }
-                s = &s[prefix.len()..];
+            if !s.starts_with(prefix) {
+                return Err((s, INVALID));
             }
+            s = &s[prefix.len()..];
+        }
 
-            Item::Space(_) => {
-                s = s.trim_left();
+        #[cfg(any(feature = "alloc", feature = "std", test))]
+        Item::OwnedLiteral(ref prefix) => {
+            if s.len() < prefix.len() {
+                return Err((s, TOO_SHORT));
             }
-
-            #[cfg(any(feature = "alloc", feature = "std", test))]
-            Item::OwnedSpace(_) => {
-                s = s.trim_left();
+            if !s.starts_with(&prefix[..]) {
+                return Err((s, INVALID));
             }
+            s = &s[prefix.len()..];
+        }
 
-            Item::Numeric(ref spec, ref _pad) => {
-                use super::Numeric::*;
-                type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
-
-                let (width, signed, set): (usize, bool, Setter) = match *spec {
-                    Year => (4, true, Parsed::set_year),
-                    YearDiv100 => (2, false, Parsed::set_year_div_100),
-                    YearMod100 => (2, false, Parsed::set_year_mod_100),
-                    IsoYear => (4, true, Parsed::set_isoyear),
-                    IsoYearDiv100 => (2, false, Parsed::set_isoyear_div_100),
-                    IsoYearMod100 => (2, false, Parsed::set_isoyear_mod_100),
-                    Month => (2, false, Parsed::set_month),
-                    Day => (2, false, Parsed::set_day),
-                    WeekFromSun => (2, false, Parsed::set_week_from_sun),
-                    WeekFromMon => (2, false, Parsed::set_week_from_mon),
-                    IsoWeek => (2, false, Parsed::set_isoweek),
-                    NumDaysFromSun => (1, false, set_weekday_with_num_days_from_sunday),
-                    WeekdayFromMon => (1, false, set_weekday_with_number_from_monday),
-                    Ordinal => (3, false, Parsed::set_ordinal),
-                    Hour => (2, false, Parsed::set_hour),
-                    Hour12 => (2, false, Parsed::set_hour12),
-                    Minute => (2, false, Parsed::set_minute),
-                    Second => (2, false, Parsed::set_second),
-                    Nanosecond => (9, false, Parsed::set_nanosecond),
-                    Timestamp => (usize::MAX, false, Parsed::set_timestamp),
-
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
-                };
-
-                s = s.trim_left();
-                let v = if signed {
-                    if s.starts_with('-') {
-                        let v = try_consume!(scan::number(&s[1..], 1, usize::MAX));
-                        0i64.checked_sub(v).ok_or((s, OUT_OF_RANGE))?
-                    } else if s.starts_with('+') {
-                        try_consume!(scan::number(&s[1..], 1, usize::MAX))
-                    } else {
-                        // if there is no explicit sign, we respect the original `width`
-                        try_consume!(scan::number(s, 1, width))
+        Item::Space(space) => match mode {
+            ParseMode::Strict => try_consume!(scan::exact(s, space)),
+            ParseMode::Lenient => s = s.trim_left(),
+        },
+
+        #[cfg(any(feature = "alloc", feature = "std", test))]
+        Item::OwnedSpace(ref space) => match mode {
+            ParseMode::Strict => try_consume!(scan::exact(s, space)),
+            ParseMode::Lenient => s = s.trim_left(),
+        },
+
+        Item::Numeric(ref spec, ref pad) => {
+            use super::Numeric::*;
+            type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
+
+            let (width, signed, set): (usize, bool, Setter) = match *spec {
+                Year => (4, true, Parsed::set_year),
+                YearDiv100 => (2, false, Parsed::set_year_div_100),
+                YearMod100 => (2, false, Parsed::set_year_mod_100),
+                IsoYear => (4, true, Parsed::set_isoyear),
+                IsoYearDiv100 => (2, false, Parsed::set_isoyear_div_100),
+                IsoYearMod100 => (2, false, Parsed::set_isoyear_mod_100),
+                Month => (2, false, Parsed::set_month),
+                Day => (2, false, Parsed::set_day),
+                WeekFromSun => (2, false, Parsed::set_week_from_sun),
+                WeekFromMon => (2, false, Parsed::set_week_from_mon),
+                IsoWeek => (2, false, Parsed::set_isoweek),
+                NumDaysFromSun => (1, false, set_weekday_with_num_days_from_sunday),
+                WeekdayFromMon => (1, false, set_weekday_with_number_from_monday),
+                Ordinal => (3, false, Parsed::set_ordinal),
+                Hour => (2, false, Parsed::set_hour),
+                Hour12 => (2, false, Parsed::set_hour12),
+                Minute => (2, false, Parsed::set_minute),
+                Second => (2, false, Parsed::set_second),
+                Nanosecond => (9, false, Parsed::set_nanosecond),
+                Timestamp => (usize::MAX, true, Parsed::set_timestamp),
+
+                // for the future expansion
+                Internal(ref int) => match int._dummy {},
+            };
+
+            // the range of the number of digits without an explicit sign
+            let (min_width, width) = match mode {
+                ParseMode::Strict if width != usize::MAX => match *pad {
+                    Pad::Zero => (width, width),
+                    Pad::Space => {
+                        let spaces = s.bytes().take(width - 1).take_while(|&b| b == b' ');
+                        let spaces = spaces.count();
+                        s = &s[spaces..];
+                        (width - spaces, width - spaces)
                     }
+                    Pad::None => (1, width),
+                },
+                ParseMode::Strict => (1, width),
+                ParseMode::Lenient => {
+                    s = s.trim_left();
+                    (1, width)
+                }
+            };
+            let v = if signed {
+                if s.starts_with('-') {
+                    let v = try_consume!(scan::number(&s[1..], 1, usize::MAX));
+                    0i64.checked_sub(v).ok_or((s, OUT_OF_RANGE))?
+                } else if s.starts_with('+') {
+                    try_consume!(scan::number(&s[1..], 1, usize::MAX))
                 } else {
-                    try_consume!(scan::number(s, 1, width))
-                };
-                set(parsed, v).map_err(|e| (s, e))?;
-            }
-
-            Item::Fixed(ref spec) => {
-                use super::Fixed::*;
+                    // if there is no explicit sign, we respect the original `width`
+                    try_consume!(scan::number(s, min_width, width))
+                }
+            } else {
+                try_consume!(scan::number(s, min_width, width))
+            };
+            set(parsed, v).map_err(|e| (s, e))?;
+        }
 
-                match spec {
-                    &ShortMonthName => {
-                        let month0 = try_consume!(scan::short_month0(s));
-                        parsed.set_month(i64::from(month0) + 1).map_err(|e| (s, e))?;
-                    }
+        Item::Fixed(ref spec) => {
+            use super::Fixed::*;
 
-                    &LongMonthName => {
-                        let month0 = try_consume!(scan::short_or_long_month0(s));
-                        parsed.set_month(i64::from(month0) + 1).map_err(|e| (s, e))?;
-                    }
+            match spec {
+                &ShortMonthName => {
+                    let month0 = try_consume!(scan::short_month0(s));
+                    parsed.set_month(i64::from(month0) + 1).map_err(|e| (s, e))?;
+                }
 
-                    &ShortWeekdayName => {
-                        let weekday = try_consume!(scan::short_weekday(s));
-                        parsed.set_weekday(weekday).map_err(|e| (s, e))?;
-                    }
+                &LongMonthName => {
+                    let month0 = try_consume!(scan::short_or_long_month0(s));
+                    parsed.set_month(i64::from(month0) + 1).map_err(|e| (s, e))?;
+                }
 
-                    &LongWeekdayName => {
-                        let weekday = try_consume!(scan::short_or_long_weekday(s));
-                        parsed.set_weekday(weekday).map_err(|e| (s, e))?;
-                    }
+                &ShortWeekdayName => {
+                    let weekday = try_consume!(scan::short_weekday(s));
+                    parsed.set_weekday(weekday).map_err(|e| (s, e))?;
+                }
 
-                    &LowerAmPm | &UpperAmPm => {
-                        if s.len() < 2 {
-                            return Err((s, TOO_SHORT));
-                        }
-                        let ampm = match (s.as_bytes()[0] | 32, s.as_bytes()[1] | 32) {
-                            (b'a', b'm') => false,
-                            (b'p', b'm') => true,
-                            _ => return Err((s, INVALID)),
-                        };
-                        parsed.set_ampm(ampm).map_err(|e| (s, e))?;
-                        s = &s[2..];
-                    }
+                &LongWeekdayName => {
+                    let weekday = try_consume!(scan::short_or_long_weekday(s));
+                    parsed.set_weekday(weekday).map_err(|e| (s, e))?;
+                }
 
-                    &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
-                        if s.starts_with('.') {
-                            let nano = try_consume!(scan::nanosecond(&s[1..]));
-                            parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
-                        }
+                &LowerAmPm | &UpperAmPm => {
+                    if s.len() < 2 {
+                        return Err((s, TOO_SHORT));
                     }
+                    let ampm = match (s.as_bytes()[0] | 32, s.as_bytes()[1] | 32) {
+                        (b'a', b'm') => false,
+                        (b'p', b'm') => true,
+                        _ => return Err((s, INVALID)),
+                    };
+                    parsed.set_ampm(ampm).map_err(|e| (s, e))?;
+                    s = &s[2..];
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
-                        if s.len() < 3 {
-                            return Err((s, TOO_SHORT));
-                        }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 3));
+                &Nanosecond => {
+                    if s.starts_with('.') {
+                        let nano = try_consume!(scan::nanosecond(&s[1..]));
                         parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
                     }
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => {
-                        if s.len() < 6 {
+                &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
+                    if s.starts_with('.') {
+                        let digits = match *spec {
+                            Nanosecond3 => 3,
+                            Nanosecond6 => 6,
+                            _ => 9,
+                        };
+                        if s.len() <= digits {
                             return Err((s, TOO_SHORT));
                         }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 6));
+                        let nano = try_consume!(scan::nanosecond_fixed(&s[1..], digits));
                         parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
                     }
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => {
-                        if s.len() < 9 {
-                            return Err((s, TOO_SHORT));
-                        }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 9));
-                        parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
+                    if s.len() < 3 {
+                        return Err((s, TOO_SHORT));
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 3));
+                    parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
+                }
 
-                    &TimezoneName => {
-                        try_consume!(scan::timezone_name_skip(s));
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => {
+                    if s.len() < 6 {
+                        return Err((s, TOO_SHORT));
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 6));
+                    parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
+                }
 
-                    &TimezoneOffsetColon
-                    | &TimezoneOffsetDoubleColon
-                    | &TimezoneOffsetTripleColon
-                    | &TimezoneOffset => {
-                        let offset = try_consume!(scan::timezone_offset(
-                            s.trim_left(),
-                            scan::colon_or_space
-                        ));
-                        parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => {
+                    if s.len() < 9 {
+                        return Err((s, TOO_SHORT));
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 9));
+                    parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
+                }
 
-                    &TimezoneOffsetColonZ | &TimezoneOffsetZ => {
-                        let offset = try_consume!(scan::timezone_offset_zulu(
-                            s.trim_left(),
-                            scan::colon_or_space
-                        ));
-                        parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
-                    }
-                    &Internal(InternalFixed {
-                        val: InternalInternal::TimezoneOffsetPermissive,
-                    }) => {
-                        let offset = try_consume!(scan::timezone_offset_permissive(
-                            s.trim_left(),
-                            scan::colon_or_space
-                        ));
-                        parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
-                    }
+                &TimezoneName => {
+                    try_consume!(scan::timezone_name_skip(s));
+                }
 
-                    &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
-                    &RFC3339 => try_consume!(parse_rfc3339(parsed, s)),
+                &TimezoneOffsetColon
+                | &TimezoneOffsetDoubleColon
+                | &TimezoneOffsetTripleColon
+                | &TimezoneOffset => {
+                    let colon = match *spec {
+                        TimezoneOffset => offset_colon(mode, scan::no_colon),
+                        _ => offset_colon(mode, scan::colon),
+                    };
+                    let offset =
+                        try_consume!(scan::timezone_offset(trim_left_lenient(s, mode), colon));
+                    parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
+                }
+
+                &TimezoneOffsetColonZ | &TimezoneOffsetZ => {
+                    let colon = match *spec {
+                        TimezoneOffsetZ => offset_colon(mode, scan::no_colon),
+                        _ => offset_colon(mode, scan::colon),
+                    };
+                    let offset =
+                        try_consume!(scan::timezone_offset_zulu(trim_left_lenient(s, mode), colon));
+                    parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
+                }
+                &Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
+                    let offset = try_consume!(scan::timezone_offset_permissive(
+                        trim_left_lenient(s, mode),
+                        offset_colon(mode, scan::optional_colon)
+                    ));
+                    parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
                 }
-            }
 
-            Item::Error => {
-                return Err((s, BAD_FORMAT));
+                &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
+                &RFC3339 => try_consume!(parse_rfc3339(parsed, s)),
             }
         }
-    }
 
-    // if there are trailling chars, it is an error
-    if !s.is_empty() {
-        Err((s, TOO_LONG))
-    } else {
-        Ok(s)
+        Item::Error => {
+            return Err((s, BAD_FORMAT));
+        }
     }
+
+    Ok(s)
 }
 
 /// Accepts a relaxed form of RFC3339.
@@ -506,7 +737,7 @@ impl str::FromStr for DateTime<FixedOffset> {
         ];
 
         let mut parsed = Parsed::new();
-        match parse_internal(&mut parsed, s, DATE_ITEMS.iter()) {
+        match parse_internal(&mut parsed, s, DATE_ITEMS.iter(), ParseMode::Lenient) {
             Err((remainder, e)) if e.0 == ParseErrorKind::TooLong => {
                 if remainder.starts_with('T') || remainder.starts_with(' ') {
                     parse(&mut parsed, &remainder[1..], TIME_ITEMS.iter())?;
@@ -702,6 +933,22 @@ fn test_parse() {
     check!(".  4",          [fix!(Nanosecond)]; INVALID);
     check!("  .4",          [fix!(Nanosecond)]; TOO_LONG); // no automatic trimming
 
+    // fixed-width fractions need exactly as many digits after the dot
+    check!("",              [fix!(Nanosecond3)]; ); // no field set, but not an error
+    check!(".421",          [fix!(Nanosecond3)]; nanosecond: 421_000_000);
+    check!(".42",           [fix!(Nanosecond3)]; TOO_SHORT);
+    check!(".",             [fix!(Nanosecond3)]; TOO_SHORT);
+    check!(".4219",         [fix!(Nanosecond3)]; TOO_LONG);
+    check!(".42143",        [fix!(Nanosecond3), num!(Second)]; nanosecond: 421_000_000, second: 43);
+    check!(".42x",          [fix!(Nanosecond3)]; INVALID);
+    check!(".421950",       [fix!(Nanosecond6)]; nanosecond: 421_950_000);
+    check!(".42195",        [fix!(Nanosecond6)]; TOO_SHORT);
+    check!(".4219508",      [fix!(Nanosecond6)]; TOO_LONG);
+    check!(".421950803",    [fix!(Nanosecond9)]; nanosecond: 421_950_803);
+    check!(".000000003",    [fix!(Nanosecond9)]; nanosecond: 3);
+    check!(".42195080",     [fix!(Nanosecond9)]; TOO_SHORT);
+    check!(".421950803547", [fix!(Nanosecond9)]; TOO_LONG);
+
     // fixed: nanoseconds without the dot
     check!("",             [internal_fix!(Nanosecond3NoDot)]; TOO_SHORT);
     check!("0",            [internal_fix!(Nanosecond3NoDot)]; TOO_SHORT);
@@ -777,6 +1024,26 @@ fn test_parse() {
     check!("z",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 0);
     check!("+12:00",    [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
     check!("+12",       [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
+    check!("+1230",     [internal_fix!(TimezoneOffsetPermissive)]; offset: 750 * 60);
+    check!("-05",       [internal_fix!(TimezoneOffsetPermissive)]; offset: -5 * 60 * 60);
+    check!("+05T",      [internal_fix!(TimezoneOffsetPermissive), lit!("T")]; offset: 5 * 60 * 60);
+    check!("+05]",      [internal_fix!(TimezoneOffsetPermissive), lit!("]")]; offset: 5 * 60 * 60);
+    check!("+05:",      [internal_fix!(TimezoneOffsetPermissive)]; offset: 5 * 60 * 60);
+    check!("+053",      [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+053x",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+0560",     [internal_fix!(TimezoneOffsetPermissive)]; OUT_OF_RANGE);
+    check!("+0",        [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+05",       [fix!(TimezoneOffset)]; TOO_SHORT);
+    check!("+05T",      [fix!(TimezoneOffset), lit!("T")]; TOO_SHORT);
+    check!("+00:44:30", [fix!(TimezoneOffset)]; offset: 44 * 60 + 30);
+    check!("-00:44:30", [fix!(TimezoneOffset)]; offset: -(44 * 60 + 30));
+    check!("+05:30:15", [fix!(TimezoneOffsetZ)]; offset: 330 * 60 + 15);
+    check!("+0530:15",  [fix!(TimezoneOffset)]; offset: 330 * 60 + 15);
+    check!("+05:30:59", [internal_fix!(TimezoneOffsetPermissive)]; offset: 330 * 60 + 59);
+    check!("+05:30:60", [fix!(TimezoneOffset)]; OUT_OF_RANGE);
+    check!("+05:30:1",  [fix!(TimezoneOffset)]; TOO_LONG);
+    check!("+05:30:",   [fix!(TimezoneOffset), lit!(":")]; offset: 330 * 60);
+    check!("+05:30 15", [fix!(TimezoneOffset), lit!(" "), num!(Day)]; offset: 330 * 60, day: 15);
     check!("CEST 5",    [fix!(TimezoneName), lit!(" "), num!(Day)]; day: 5);
 
     // some practical examples
@@ -814,6 +1081,12 @@ fn test_parse() {
     check!("12345678901234.56789",
            [num!(Timestamp), fix!(Nanosecond)];
            nanosecond: 567_890_000, timestamp: 12_345_678_901_234);
+
+    // signed timestamps
+    check!("-1500000000", [num!(Timestamp)]; timestamp: -1_500_000_000);
+    check!("+1500000000", [num!(Timestamp)]; timestamp: 1_500_000_000);
+    check!(" -1",         [num!(Timestamp)]; timestamp: -1);
+    check!("- 1",         [num!(Timestamp)]; INVALID);
 }
 
 #[cfg(test)]
@@ -884,6 +1157,170 @@ fn test_rfc2822() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_parse_with_mode() {
+    use super::StrftimeItems;
+
+    fn parse_day(s: &str, fmt: &str, mode: ParseMode) -> ParseResult<Option<u32>> {
+        let mut parsed = Parsed::new();
+        parse_with_mode(&mut parsed, s, StrftimeItems::new(fmt), mode)?;
+        Ok(parsed.day)
+    }
+    use self::ParseMode::{Lenient, Strict};
+
+    // zero padded
+    assert_eq!(parse_day("5", "%d", Lenient), Ok(Some(5)));
+    assert_eq!(parse_day("5", "%d", Strict), Err(TOO_SHORT));
+    assert_eq!(parse_day("05", "%d", Lenient), Ok(Some(5)));
+    assert_eq!(parse_day("05", "%d", Strict), Ok(Some(5)));
+    assert_eq!(parse_day(" 5", "%d", Lenient), Ok(Some(5)));
+    assert_eq!(parse_day(" 5", "%d", Strict), Err(INVALID));
+    assert_eq!(parse_day("055", "%d", Strict), Err(TOO_LONG));
+
+    // space padded
+    assert_eq!(parse_day(" 5", "%e", Strict), Ok(Some(5)));
+    assert_eq!(parse_day("15", "%e", Strict), Ok(Some(15)));
+    assert_eq!(parse_day("5", "%e", Strict), Err(TOO_SHORT));
+    assert_eq!(parse_day("  5", "%e", Strict), Err(INVALID));
+
+    // not padded
+    assert_eq!(parse_day("5", "%-d", Strict), Ok(Some(5)));
+    assert_eq!(parse_day("15", "%-d", Strict), Ok(Some(15)));
+    assert_eq!(parse_day(" 5", "%-d", Strict), Err(INVALID));
+
+    // whitespace has to match exactly
+    assert_eq!(parse_day("x 05", "x %d", Strict), Ok(Some(5)));
+    assert_eq!(parse_day("x  05", "x %d", Lenient), Ok(Some(5)));
+    assert_eq!(parse_day("x  05", "x %d", Strict), Err(INVALID));
+    assert_eq!(parse_day("x05", "x %d", Lenient), Ok(Some(5)));
+    assert_eq!(parse_day("x05", "x %d", Strict), Err(INVALID));
+
+    // years need four digits or an explicit sign
+    let parse_year = |s: &str, mode| {
+        let mut parsed = Parsed::new();
+        parse_with_mode(&mut parsed, s, StrftimeItems::new("%Y"), mode).map(|_| parsed.year)
+    };
+    assert_eq!(parse_year("0987", Strict), Ok(Some(987)));
+    assert_eq!(parse_year("987", Strict), Err(TOO_SHORT));
+    assert_eq!(parse_year("987", Lenient), Ok(Some(987)));
+    assert_eq!(parse_year("+12345", Strict), Ok(Some(12345)));
+    assert_eq!(parse_year("-0001", Strict), Ok(Some(-1)));
+
+    // offsets don't skip leading whitespace
+    let parse_offset = |s: &str, mode| {
+        let mut parsed = Parsed::new();
+        parse_with_mode(&mut parsed, s, StrftimeItems::new("%z"), mode).map(|_| parsed.offset)
+    };
+    assert_eq!(parse_offset("+0900", Strict), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset(" +0900", Lenient), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset(" +0900", Strict), Err(INVALID));
+
+    // the separator between hours and minutes has to match exactly
+    let parse_offset = |s: &str, fmt: &str, mode| {
+        let mut parsed = Parsed::new();
+        parse_with_mode(&mut parsed, s, StrftimeItems::new(fmt), mode).map(|_| parsed.offset)
+    };
+    assert_eq!(parse_offset("+09:00", "%:z", Strict), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset("+09 00", "%:z", Lenient), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset("+09 00", "%:z", Strict), Err(INVALID));
+    assert_eq!(parse_offset("+0900", "%:z", Strict), Err(INVALID));
+    assert_eq!(parse_offset("+09:00", "%z", Lenient), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset("+09:00", "%z", Strict), Err(INVALID));
+    assert_eq!(parse_offset("+09", "%#z", Strict), Ok(Some(9 * 3600)));
+    assert_eq!(parse_offset("+09:30", "%#z", Strict), Ok(Some(9 * 3600 + 30 * 60)));
+    assert_eq!(parse_offset("+09 30", "%#z", Strict), Err(TOO_LONG));
+
+    // the entire formatted output is accepted by the strict mode
+    let mut parsed = Parsed::new();
+    let fmt = "%a, %e %b %Y %H:%M:%S%.3f %z";
+    assert_eq!(
+        parse_with_mode(
+            &mut parsed,
+            "Sun,  3 Jul 2022 04:05:06.007 +0100",
+            StrftimeItems::new(fmt),
+            Strict
+        ),
+        Ok(())
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_and_remainder() {
+    use super::StrftimeItems;
+    use crate::NaiveDate;
+
+    let mut parsed = Parsed::new();
+    let remainder =
+        parse_and_remainder(&mut parsed, "2020-01-02 rest", StrftimeItems::new("%Y-%m-%d"));
+    assert_eq!(remainder, Ok(" rest"));
+    assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_ymd(2020, 1, 2).unwrap()));
+
+    // nothing left over
+    let mut parsed = Parsed::new();
+    let remainder = parse_and_remainder(&mut parsed, "2020-01-02", StrftimeItems::new("%Y-%m-%d"));
+    assert_eq!(remainder, Ok(""));
+
+    // errors before the end of the items are still reported
+    let mut parsed = Parsed::new();
+    let remainder = parse_and_remainder(&mut parsed, "2020-01", StrftimeItems::new("%Y-%m-%d"));
+    assert_eq!(remainder, Err(TOO_SHORT));
+    let mut parsed = Parsed::new();
+    let remainder = parse_and_remainder(&mut parsed, "2020/01/02", StrftimeItems::new("%Y-%m-%d"));
+    assert_eq!(remainder, Err(INVALID));
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_collect_errors() {
+    use super::StrftimeItems;
+    use super::IMPOSSIBLE;
+
+    let items = || StrftimeItems::new("%Y-%m-%d %H:%M");
+
+    // two bad fields
+    assert_eq!(
+        parse_collect_errors("2022-13-32 12:00", items()),
+        Err(vec![(5, OUT_OF_RANGE), (8, OUT_OF_RANGE)])
+    );
+    assert_eq!(
+        parse_collect_errors("2022-13-01 25:61", items()),
+        Err(vec![(5, OUT_OF_RANGE), (11, OUT_OF_RANGE), (14, OUT_OF_RANGE)])
+    );
+
+    // no errors
+    let parsed = parse_collect_errors("2022-12-31 23:59", items()).unwrap();
+    assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2022), Some(12), Some(31)));
+    assert_eq!(
+        (parsed.hour_div_12, parsed.hour_mod_12, parsed.minute),
+        (Some(1), Some(11), Some(59))
+    );
+
+    // input not matching the items stops at the first such error
+    assert_eq!(
+        parse_collect_errors("2022-13-xx 12:00", items()),
+        Err(vec![(5, OUT_OF_RANGE), (8, INVALID)])
+    );
+    assert_eq!(parse_collect_errors("2022/13/32 12:00", items()), Err(vec![(4, INVALID)]));
+    assert_eq!(
+        parse_collect_errors("2022-13-31", items()),
+        Err(vec![(5, OUT_OF_RANGE), (10, TOO_SHORT)])
+    );
+
+    // trailing input
+    assert_eq!(
+        parse_collect_errors("2022-13-31 12:00 UTC", items()),
+        Err(vec![(5, OUT_OF_RANGE), (16, TOO_LONG)])
+    );
+
+    // conflicting fields
+    assert_eq!(
+        parse_collect_errors("2022 2021 13", StrftimeItems::new("%Y %Y %m")),
+        Err(vec![(5, IMPOSSIBLE), (10, OUT_OF_RANGE)])
+    );
+}
+
 #[cfg(test)]
 #[test]
 fn parse_rfc850() {
@@ -955,6 +1392,8 @@ fn test_rfc3339() {
         ("2015-01-20T17:65:20-08:00", Err(OUT_OF_RANGE)), // bad minute
         ("2015-01-20T17:35:90-08:00", Err(OUT_OF_RANGE)), // bad second
         ("2015-01-20T17:35:20-24:00", Err(OUT_OF_RANGE)), // bad offset
+        ("1971-12-31T23:59:59-00:44:30", Ok("1971-12-31T23:59:59-00:44")), // offset with seconds
+        ("1971-12-31T23:59:59-00:44:60", Err(OUT_OF_RANGE)), // bad offset seconds
     ];
 
     fn rfc3339_to_datetime(date: &str) -> ParseResult<DateTime<FixedOffset>> {
@@ -967,6 +1406,11 @@ fn test_rfc3339() {
         dt.format_with_items([Item::Fixed(Fixed::RFC3339)].iter()).to_string()
     }
 
+    // the seconds of the offset aren't lost, even if they aren't formatted
+    let dt = rfc3339_to_datetime("1971-12-31T23:59:59-00:44:30").unwrap();
+    assert_eq!(dt.offset().local_minus_utc(), -(44 * 60 + 30));
+    assert_eq!("1971-12-31T23:59:59-00:44:30".parse::<DateTime<FixedOffset>>(), Ok(dt));
+
     // Test against test data above
     for &(date, checkdate) in testdates.iter() {
         let d = rfc3339_to_datetime(date); // parse a date
@@ -8,7 +8,7 @@ use core::convert::TryFrom;
 
 use num_integer::div_rem;
 
-use super::{ParseResult, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
+use super::{ParseError, ParseErrorKind, ParseResult, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::offset::{FixedOffset, Offset, TimeZone};
 use crate::{DateTime, Datelike, TimeDelta, Timelike, Weekday};
@@ -16,10 +16,33 @@ use crate::{DateTime, Datelike, TimeDelta, Timelike, Weekday};
 /// Parsed parts of date and time. There are two classes of methods:
 ///
 /// - `set_*` methods try to set given field(s) while checking for the consistency.
-///   It may or may not check for the range constraint immediately (for efficiency reasons).
+///   Fields with a fixed range like the month or minute are checked immediately,
+///   others like the year or offset are only checked once they are used.
 ///
 /// - `to_*` methods try to make a concrete date and time value out of set fields.
 ///   It fully checks any remaining out-of-range conditions and inconsistent/impossible fields.
+///
+/// Besides being filled in by [`parse`](./fn.parse.html), a `Parsed` can be
+/// assembled by hand from fields computed elsewhere:
+///
+/// ```
+/// use chrono::format::Parsed;
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// let mut parsed = Parsed::new();
+/// parsed.set_year(2022)?;
+/// parsed.set_ordinal(60)?;
+/// parsed.set_hour(13)?;
+/// parsed.set_minute(30)?;
+/// parsed.set_second(0)?;
+/// parsed.set_offset(3600)?;
+///
+/// let dt = FixedOffset::east(3600).ymd(2022, 3, 1).unwrap().and_hms(13, 30, 0).unwrap();
+/// assert_eq!(parsed.to_datetime()?, dt);
+///
+/// assert!(parsed.set_minute(60).is_err());
+/// # Ok::<_, chrono::ParseError>(())
+/// ```
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub struct Parsed {
     /// Year.
@@ -184,27 +207,47 @@ impl Parsed {
     }
 
     /// Tries to set the [`month`](#structfield.month) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 1--12.
     #[inline]
     pub fn set_month(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.month, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(1..=12).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.month, value as u32)
     }
 
     /// Tries to set the [`week_from_sun`](#structfield.week_from_sun) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--53.
     #[inline]
     pub fn set_week_from_sun(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.week_from_sun, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(0..=53).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.week_from_sun, value as u32)
     }
 
     /// Tries to set the [`week_from_mon`](#structfield.week_from_mon) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--53.
     #[inline]
     pub fn set_week_from_mon(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.week_from_mon, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(0..=53).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.week_from_mon, value as u32)
     }
 
     /// Tries to set the [`isoweek`](#structfield.isoweek) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 1--53.
     #[inline]
     pub fn set_isoweek(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.isoweek, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(1..=53).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.isoweek, value as u32)
     }
 
     /// Tries to set the [`weekday`](#structfield.weekday) field from given value.
@@ -214,15 +257,25 @@ impl Parsed {
     }
 
     /// Tries to set the [`ordinal`](#structfield.ordinal) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 1--366.
     #[inline]
     pub fn set_ordinal(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.ordinal, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(1..=366).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.ordinal, value as u32)
     }
 
     /// Tries to set the [`day`](#structfield.day) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 1--31.
     #[inline]
     pub fn set_day(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.day, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(1..=31).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.day, value as u32)
     }
 
     /// Tries to set the [`hour_div_12`](#structfield.hour_div_12) field from given value.
@@ -244,30 +297,50 @@ impl Parsed {
 
     /// Tries to set both [`hour_div_12`](#structfield.hour_div_12) and
     /// [`hour_mod_12`](#structfield.hour_mod_12) fields from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--23.
     #[inline]
     pub fn set_hour(&mut self, value: i64) -> ParseResult<()> {
-        let v = u32::try_from(value).map_err(|_| OUT_OF_RANGE)?;
+        if !(0..=23).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        let v = value as u32;
         set_if_consistent(&mut self.hour_div_12, v / 12)?;
         set_if_consistent(&mut self.hour_mod_12, v % 12)?;
         Ok(())
     }
 
     /// Tries to set the [`minute`](#structfield.minute) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--59.
     #[inline]
     pub fn set_minute(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.minute, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(0..=59).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.minute, value as u32)
     }
 
     /// Tries to set the [`second`](#structfield.second) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--60.
     #[inline]
     pub fn set_second(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.second, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(0..=60).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.second, value as u32)
     }
 
     /// Tries to set the [`nanosecond`](#structfield.nanosecond) field from given value.
+    ///
+    /// Returns `OUT_OF_RANGE` if the value is not in the range 0--999,999,999.
     #[inline]
     pub fn set_nanosecond(&mut self, value: i64) -> ParseResult<()> {
-        set_if_consistent(&mut self.nanosecond, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+        if !(0..=999_999_999).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.nanosecond, value as u32)
     }
 
     /// Tries to set the [`timestamp`](#structfield.timestamp) field from given value.
@@ -293,6 +366,21 @@ impl Parsed {
     ///
     /// Gregorian year and ISO week date year can have their century number (`*_div_100`) omitted,
     /// the two-digit year is used to guess the century number then.
+    ///
+    /// All other given fields have to be consistent with the resulting date,
+    /// for example a day of the year which disagrees with the month and day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "2020-W05-3", StrftimeItems::new("%G-W%V-%u"))?;
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd(2020, 1, 29)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn to_naive_date(&self) -> ParseResult<NaiveDate> {
         fn resolve_year(
             y: Option<i32>,
@@ -501,6 +589,22 @@ impl Parsed {
     /// - Hour, minute, second, nanosecond.
     ///
     /// It is able to handle leap seconds when given second is 60.
+    ///
+    /// The hour can be given either on a 24-hour clock, or on a 12-hour clock
+    /// together with AM/PM. If both are given they have to agree, which is
+    /// checked when the fields are set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveTime;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "23:56:04", StrftimeItems::new("%H:%M:%S"))?;
+    /// assert_eq!(parsed.to_naive_time()?, NaiveTime::from_hms(23, 56, 4)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn to_naive_time(&self) -> ParseResult<NaiveTime> {
         let hour_div_12 = match self.hour_div_12 {
             Some(v @ 0..=1) => v,
@@ -661,32 +765,28 @@ impl Parsed {
                 tz.offset_from_utc_datetime(&dt).map_err(|_| OUT_OF_RANGE)?.fix().local_minus_utc();
         }
 
-        // checks if the given `DateTime` has a consistent `Offset` with given `self.offset`.
-        let check_offset = |dt: &DateTime<Tz>| {
-            if let Some(offset) = self.offset {
-                dt.offset().fix().local_minus_utc() == offset
-            } else {
-                true
-            }
-        };
-
         // `guessed_offset` should be correct when `self.timestamp` is given.
         // it will be 0 otherwise, but this is fine as the algorithm ignores offset for that case.
         let datetime = self.to_naive_datetime_with_offset(guessed_offset)?;
 
         let t = tz.from_local_datetime(&datetime).map_err(|_| OUT_OF_RANGE)?;
 
-        if check_offset(&t) {
-            Ok(t)
-        } else {
-            Err(IMPOSSIBLE)
+        // checks if the given `DateTime` has a consistent `Offset` with given `self.offset`.
+        if let Some(offset) = self.offset {
+            let expected = t.offset().fix();
+            if expected.local_minus_utc() != offset {
+                let parsed = FixedOffset::east_opt(offset).ok_or(OUT_OF_RANGE)?;
+                return Err(ParseError(ParseErrorKind::OffsetMismatch { parsed, expected }));
+            }
         }
+
+        Ok(t)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::{IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
+    use super::super::{ParseError, ParseErrorKind, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
     use super::Parsed;
     use crate::naive::{NaiveDate, NaiveTime};
     use crate::offset::{FixedOffset, TimeZone, Utc};
@@ -743,6 +843,35 @@ mod tests {
         assert_eq!(p.set_month(8), Ok(()));
         assert_eq!(p.set_month(0x1_0000_0008), Err(OUT_OF_RANGE));
 
+        // fields with a fixed range are checked immediately
+        let mut p = Parsed::new();
+        assert_eq!(p.set_month(0), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_month(13), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_week_from_sun(54), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_week_from_mon(-1), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_isoweek(0), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_isoweek(54), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_ordinal(0), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_ordinal(367), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_day(0), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_day(32), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_hour(24), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_hour(-1), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_minute(60), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_second(61), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_nanosecond(1_000_000_000), Err(OUT_OF_RANGE));
+        assert_eq!(p, Parsed::new());
+        assert_eq!(p.set_month(12), Ok(()));
+        assert_eq!(p.set_week_from_sun(53), Ok(()));
+        assert_eq!(p.set_week_from_mon(0), Ok(()));
+        assert_eq!(p.set_isoweek(53), Ok(()));
+        assert_eq!(p.set_ordinal(366), Ok(()));
+        assert_eq!(p.set_day(31), Ok(()));
+        assert_eq!(p.set_hour(23), Ok(()));
+        assert_eq!(p.set_minute(59), Ok(()));
+        assert_eq!(p.set_second(60), Ok(()));
+        assert_eq!(p.set_nanosecond(999_999_999), Ok(()));
+
         // hour
         let mut p = Parsed::new();
         assert_eq!(p.set_hour(12), Ok(()));
@@ -961,6 +1090,31 @@ mod tests {
         // technically unique (2014-12-31) but Chrono gives up
     }
 
+    #[test]
+    fn test_parsed_to_naive_date_from_str() {
+        use crate::format::{parse, StrftimeItems};
+
+        let parse = |s, fmt| {
+            let mut parsed = Parsed::new();
+            parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+            parsed.to_naive_date()
+        };
+
+        assert_eq!(parse("2020-W05-3", "%G-W%V-%u"), Ok(ymd!(2020, 1, 29)));
+        assert_eq!(parse("2020-W01-1", "%G-W%V-%u"), Ok(ymd!(2019, 12, 30)));
+        assert_eq!(parse("2020-W53-7", "%G-W%V-%u"), Ok(ymd!(2021, 1, 3)));
+        assert_eq!(parse("2021-W53-1", "%G-W%V-%u"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("2020-W05", "%G-W%V"), Err(NOT_ENOUGH));
+
+        // the ISO week date has to agree with other fields
+        assert_eq!(parse("2020-W05-3 2020-01-29", "%G-W%V-%u %F"), Ok(ymd!(2020, 1, 29)));
+        assert_eq!(parse("2020-W05-3 2020-01-30", "%G-W%V-%u %F"), Err(IMPOSSIBLE));
+
+        // so do the month and day with the day of the year
+        assert_eq!(parse("2020-02-01 032", "%F %j"), Ok(ymd!(2020, 2, 1)));
+        assert_eq!(parse("2020-02-01 033", "%F %j"), Err(IMPOSSIBLE));
+    }
+
     #[test]
     fn test_parsed_to_naive_time() {
         macro_rules! parse {
@@ -1214,8 +1368,46 @@ mod tests {
         ); // `FixedOffset` does not support such huge offset
     }
 
+    #[test]
+    fn test_parsed_manual_assembly() {
+        let mut p = Parsed::new();
+        assert_eq!(p.set_year(2022), Ok(()));
+        assert_eq!(p.set_ordinal(60), Ok(()));
+        assert_eq!(p.set_hour(13), Ok(()));
+        assert_eq!(p.set_minute(30), Ok(()));
+        assert_eq!(p.set_second(15), Ok(()));
+        assert_eq!(p.set_offset(-5 * 3600), Ok(()));
+
+        let offset = FixedOffset::west(5 * 3600);
+        let expected = offset.ymd(2022, 3, 1).unwrap().and_hms(13, 30, 15).unwrap();
+        assert_eq!(p.to_datetime(), Ok(expected));
+        assert_eq!(p.to_fixed_offset(), Ok(offset));
+
+        // the offset is only checked when it is used
+        let mut p = p.clone();
+        p.offset = None;
+        assert_eq!(p.set_offset(86_400), Ok(()));
+        assert_eq!(p.to_datetime(), Err(OUT_OF_RANGE));
+
+        // fields are only checked for consistency among themselves once combined
+        let mut p = Parsed::new();
+        assert_eq!(p.set_year(2021), Ok(()));
+        assert_eq!(p.set_ordinal(366), Ok(()));
+        assert_eq!(p.set_hour(0), Ok(()));
+        assert_eq!(p.set_minute(0), Ok(()));
+        assert_eq!(p.set_offset(0), Ok(()));
+        assert_eq!(p.to_datetime(), Err(OUT_OF_RANGE));
+    }
+
     #[test]
     fn test_parsed_to_datetime_with_timezone() {
+        let mismatch = |parsed, expected| {
+            ParseError(ParseErrorKind::OffsetMismatch {
+                parsed: FixedOffset::east(parsed),
+                expected: FixedOffset::east(expected),
+            })
+        };
+
         macro_rules! parse {
             ($tz:expr; $($k:ident: $v:expr),*) => (
                 Parsed { $($k: Some($v),)* ..Parsed::new() }.to_datetime_with_timezone(&$tz)
@@ -1233,13 +1425,13 @@ mod tests {
             parse!(Utc;
                           year: 2014, ordinal: 365, hour_div_12: 1, hour_mod_12: 1,
                           minute: 26, second: 40, nanosecond: 12_345_678, offset: 32400),
-            Err(IMPOSSIBLE)
+            Err(mismatch(32400, 0))
         );
         assert_eq!(
             parse!(FixedOffset::east(32400);
                           year: 2014, ordinal: 365, hour_div_12: 0, hour_mod_12: 4,
                           minute: 26, second: 40, nanosecond: 12_345_678, offset: 0),
-            Err(IMPOSSIBLE)
+            Err(mismatch(0, 32400))
         );
         assert_eq!(
             parse!(FixedOffset::east(32400);
@@ -1257,10 +1449,10 @@ mod tests {
             parse!(Utc; timestamp: 1_420_000_000, offset: 0),
             Ok(Utc.ymd(2014, 12, 31).unwrap().and_hms(4, 26, 40).unwrap())
         );
-        assert_eq!(parse!(Utc; timestamp: 1_420_000_000, offset: 32400), Err(IMPOSSIBLE));
+        assert_eq!(parse!(Utc; timestamp: 1_420_000_000, offset: 32400), Err(mismatch(32400, 0)));
         assert_eq!(
             parse!(FixedOffset::east(32400); timestamp: 1_420_000_000, offset: 0),
-            Err(IMPOSSIBLE)
+            Err(mismatch(0, 32400))
         );
         assert_eq!(
             parse!(FixedOffset::east(32400); timestamp: 1_420_000_000, offset: 32400),
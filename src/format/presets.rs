@@ -0,0 +1,238 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Format strings for timestamps commonly found in log files.
+//!
+//! The constants in this module can be used with any of the `parse_from_str`
+//! and `format` methods. [`parse_preset`] and [`parse_preset_with_year`] parse
+//! them into a [`PresetDateTime`], which also keeps the offset when the format
+//! has one, and fills in the year for formats without one, like [`SYSLOG`].
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::format::presets::{parse_preset_with_year, Preset};
+//! use chrono::{FixedOffset, NaiveDate};
+//!
+//! let line = r#"127.0.0.1 - - [10/Oct/2000:13:55:36 -0700] "GET / HTTP/1.0" 200 2326"#;
+//! let start = line.find('[').unwrap() + 1;
+//! let end = line.find(']').unwrap();
+//!
+//! let parsed = parse_preset_with_year(&line[start..end], Preset::ApacheClf, 1970)?;
+//! assert_eq!(parsed.datetime, NaiveDate::from_ymd(2000, 10, 10)?.and_hms(13, 55, 36)?);
+//! assert_eq!(parsed.offset, Some(FixedOffset::west(7 * 3600)));
+//! assert!(!parsed.year_assumed);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use super::{parse, ParseResult, Parsed, StrftimeItems};
+use crate::naive::NaiveDateTime;
+use crate::offset::FixedOffset;
+
+/// The timestamp of the Apache Common Log Format, e.g. `10/Oct/2000:13:55:36 -0700`.
+pub const APACHE_CLF: &str = "%d/%b/%Y:%H:%M:%S %z";
+
+/// The timestamp of the BSD syslog format (RFC 3164), e.g. `Oct  5 13:55:36`.
+///
+/// Note that it has neither a year nor an offset.
+pub const SYSLOG: &str = "%b %e %H:%M:%S";
+
+/// An ISO 8601 / RFC 3339 timestamp, e.g. `2000-10-10T13:55:36.123-07:00`.
+///
+/// The fractional seconds are optional when parsing.
+pub const ISO8601: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+
+/// The log timestamp formats supported by [`parse_preset`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Preset {
+    /// The Apache Common Log Format, see [`APACHE_CLF`].
+    ApacheClf,
+    /// The BSD syslog format, see [`SYSLOG`].
+    Syslog,
+    /// ISO 8601, see [`ISO8601`].
+    Iso8601,
+}
+
+impl Preset {
+    /// Returns the format string of this preset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::presets::{Preset, SYSLOG};
+    ///
+    /// assert_eq!(Preset::Syslog.format_str(), SYSLOG);
+    /// ```
+    pub fn format_str(self) -> &'static str {
+        match self {
+            Preset::ApacheClf => APACHE_CLF,
+            Preset::Syslog => SYSLOG,
+            Preset::Iso8601 => ISO8601,
+        }
+    }
+}
+
+/// A timestamp parsed with [`parse_preset`] or [`parse_preset_with_year`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct PresetDateTime {
+    /// The local date and time, as written in the input.
+    pub datetime: NaiveDateTime,
+    /// The offset from UTC, if the format has one.
+    pub offset: Option<FixedOffset>,
+    /// Whether the year was missing from the input and has been filled in.
+    pub year_assumed: bool,
+}
+
+/// Parses a log timestamp with the given preset.
+///
+/// If the format has no year, like [`SYSLOG`], the current year in the
+/// [`Local`](../../offset/struct.Local.html) time zone is assumed and
+/// [`PresetDateTime::year_assumed`] is set. Use [`parse_preset_with_year`]
+/// to pick the year instead, e.g. when reading old log files.
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+pub fn parse_preset(s: &str, preset: Preset) -> ParseResult<PresetDateTime> {
+    use crate::{Datelike, Local};
+
+    parse_preset_or(s, preset, || Ok(Local::now().map_err(|_| super::OUT_OF_RANGE)?.year()))
+}
+
+/// Parses a log timestamp with the given preset, using `year` if the format has no year.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::presets::{parse_preset_with_year, Preset};
+/// use chrono::NaiveDate;
+///
+/// let parsed = parse_preset_with_year("Oct  5 13:55:36", Preset::Syslog, 2021)?;
+/// assert_eq!(parsed.datetime, NaiveDate::from_ymd(2021, 10, 5)?.and_hms(13, 55, 36)?);
+/// assert_eq!(parsed.offset, None);
+/// assert!(parsed.year_assumed);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse_preset_with_year(s: &str, preset: Preset, year: i32) -> ParseResult<PresetDateTime> {
+    parse_preset_or(s, preset, || Ok(year))
+}
+
+/// Parses a log timestamp with the given preset, calling `current_year` only
+/// if the format has no year.
+fn parse_preset_or<F>(s: &str, preset: Preset, current_year: F) -> ParseResult<PresetDateTime>
+where
+    F: FnOnce() -> ParseResult<i32>,
+{
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, s, StrftimeItems::new(preset.format_str()))?;
+    let year_assumed = parsed.year.is_none();
+    if year_assumed {
+        parsed.set_year(i64::from(current_year()?))?;
+    }
+    let offset = match parsed.offset {
+        Some(_) => Some(parsed.to_fixed_offset()?),
+        None => None,
+    };
+    let datetime = parsed.to_naive_datetime_with_offset(0)?;
+    Ok(PresetDateTime { datetime, offset, year_assumed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_preset_with_year, Preset, PresetDateTime, APACHE_CLF, ISO8601, SYSLOG};
+    use crate::format::ParseErrorKind;
+    use crate::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+    fn ymdhms(y: i32, m: u32, d: u32, h: u32, n: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(y, m, d).unwrap().and_hms(h, n, s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_preset_clf() {
+        let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326"#;
+        let timestamp = line.split(|c| c == '[' || c == ']').nth(1).unwrap();
+        assert_eq!(
+            parse_preset_with_year(timestamp, Preset::ApacheClf, 2022),
+            Ok(PresetDateTime {
+                datetime: ymdhms(2000, 10, 10, 13, 55, 36),
+                offset: Some(FixedOffset::west(7 * 3600)),
+                year_assumed: false,
+            })
+        );
+        assert_eq!(
+            DateTime::parse_from_str(timestamp, APACHE_CLF).unwrap().to_rfc3339(),
+            "2000-10-10T13:55:36-07:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_preset_syslog() {
+        let line = "Oct  5 13:55:36 myhost sshd[4242]: Accepted publickey for frank";
+        let timestamp = &line[..15];
+        assert_eq!(
+            parse_preset_with_year(timestamp, Preset::Syslog, 2021),
+            Ok(PresetDateTime {
+                datetime: ymdhms(2021, 10, 5, 13, 55, 36),
+                offset: None,
+                year_assumed: true,
+            })
+        );
+        assert_eq!(
+            parse_preset_with_year("Dec 24 23:59:59", Preset::Syslog, 2020).map(|p| p.datetime),
+            Ok(ymdhms(2020, 12, 24, 23, 59, 59))
+        );
+
+        // the assumed year must still make the date valid
+        let err = parse_preset_with_year("Feb 29 00:00:00", Preset::Syslog, 2021).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+        assert!(parse_preset_with_year("Feb 29 00:00:00", Preset::Syslog, 2020).is_ok());
+
+        assert_eq!(
+            NaiveDateTime::parse_from_str("2021 Oct  5 13:55:36", &format!("%Y {}", SYSLOG)),
+            Ok(ymdhms(2021, 10, 5, 13, 55, 36))
+        );
+    }
+
+    #[test]
+    fn test_parse_preset_iso8601() {
+        let parsed = parse_preset_with_year("2000-10-10T13:55:36.250+02:00", Preset::Iso8601, 1970);
+        assert_eq!(
+            parsed,
+            Ok(PresetDateTime {
+                datetime: ymdhms(2000, 10, 10, 13, 55, 36) + crate::TimeDelta::milliseconds(250),
+                offset: Some(FixedOffset::east(2 * 3600)),
+                year_assumed: false,
+            })
+        );
+        let parsed = parse_preset_with_year("2000-10-10T13:55:36-07:00", Preset::Iso8601, 1970);
+        assert_eq!(parsed.map(|p| p.offset), Ok(Some(FixedOffset::west(7 * 3600))));
+        assert_eq!(ISO8601, Preset::Iso8601.format_str());
+
+        assert!(
+            parse_preset_with_year("10/Oct/2000:13:55:36 -0700", Preset::Iso8601, 1970).is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_preset_current_year() {
+        use super::parse_preset_or;
+        use crate::Datelike;
+
+        let parsed = parse_preset_or("Jan  1 00:00:00", Preset::Syslog, || Ok(2022)).unwrap();
+        assert!(parsed.year_assumed);
+        assert_eq!(parsed.datetime.year(), 2022);
+
+        // the current year is only looked up when the input has none
+        let parsed =
+            parse_preset_or("10/Oct/2000:13:55:36 -0700", Preset::ApacheClf, || unreachable!())
+                .unwrap();
+        assert!(!parsed.year_assumed);
+        assert_eq!(parsed.datetime.year(), 2000);
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_preset() {
+        use super::parse_preset;
+
+        assert!(parse_preset("Jan  1 00:00:00", Preset::Syslog).unwrap().year_assumed);
+    }
+}
@@ -65,6 +65,17 @@ pub(super) fn number(s: &str, min: usize, max: usize) -> ParseResult<(&str, i64)
     Ok((&s[core::cmp::min(max, bytes.len())..], n))
 }
 
+/// Tries to consume exactly the given string.
+pub(super) fn exact<'a>(s: &'a str, expected: &str) -> ParseResult<(&'a str, ())> {
+    if s.len() < expected.len() {
+        Err(TOO_SHORT)
+    } else if s.starts_with(expected) {
+        Ok((&s[expected.len()..], ()))
+    } else {
+        Err(INVALID)
+    }
+}
+
 /// Tries to consume at least one digits as a fractional second.
 /// Returns the number of whole nanoseconds (0--999,999,999).
 pub(super) fn nanosecond(s: &str) -> ParseResult<(&str, i64)> {
@@ -203,10 +214,27 @@ pub(super) fn colon_or_space(s: &str) -> ParseResult<&str> {
     Ok(s.trim_left_matches(|c: char| c == ':' || c.is_whitespace()))
 }
 
-/// Tries to parse `[-+]\d\d` continued by `\d\d`. Return an offset in seconds if possible.
+/// Tries to consume exactly one colon.
+pub(super) fn colon(s: &str) -> ParseResult<&str> {
+    char(s, b':')
+}
+
+/// Consumes a single colon if there is one.
+pub(super) fn optional_colon(s: &str) -> ParseResult<&str> {
+    Ok(if s.starts_with(':') { &s[1..] } else { s })
+}
+
+/// Consumes nothing, for offsets written without a colon.
+pub(super) fn no_colon(s: &str) -> ParseResult<&str> {
+    Ok(s)
+}
+
+/// Tries to parse `[-+]\d\d` continued by `\d\d`, optionally followed by `:\d\d`.
+/// Return an offset in seconds if possible.
 ///
 /// The additional `colon` may be used to parse a mandatory or optional `:`
 /// between hours and minutes, and should return either a new suffix or `Err` when parsing fails.
+/// The seconds, as found in historical offsets like `+00:44:30`, always need a `:`.
 pub(super) fn timezone_offset<F>(s: &str, consume_colon: F) -> ParseResult<(&str, i32)>
 where
     F: FnMut(&str) -> ParseResult<&str>,
@@ -250,24 +278,35 @@ where
 
     // minutes (00--59)
     // if the next two items are digits then we have to add minutes
-    let minutes = if let Ok(ds) = digits(s) {
-        match ds {
-            (m1 @ b'0'..=b'5', m2 @ b'0'..=b'9') => i32::from((m1 - b'0') * 10 + (m2 - b'0')),
-            (b'6'..=b'9', b'0'..=b'9') => return Err(OUT_OF_RANGE),
-            _ => return Err(INVALID),
+    // if the minutes are allowed to be missing, anything not starting with a
+    // digit is left for the following items
+    let starts_with_digit = s.as_bytes().first().map_or(false, u8::is_ascii_digit);
+    let minutes = match digits(s) {
+        Ok((m1 @ b'0'..=b'5', m2 @ b'0'..=b'9')) => {
+            s = &s[2..];
+            i32::from((m1 - b'0') * 10 + (m2 - b'0'))
         }
-    } else if allow_missing_minutes {
-        0
-    } else {
-        return Err(TOO_SHORT);
-    };
-    s = match s.len() {
-        len if len >= 2 => &s[2..],
-        len if len == 0 => s,
-        _ => return Err(TOO_SHORT),
+        Ok((b'6'..=b'9', b'0'..=b'9')) => return Err(OUT_OF_RANGE),
+        _ if allow_missing_minutes && !starts_with_digit => 0,
+        Ok(_) => return Err(INVALID),
+        Err(e) => return Err(e),
     };
 
-    let seconds = hours * 3600 + minutes * 60;
+    // seconds (00--59), only if the minutes were present
+    let mut seconds = 0;
+    if starts_with_digit && s.starts_with(':') {
+        match digits(&s[1..]) {
+            Ok((s1 @ b'0'..=b'5', s2 @ b'0'..=b'9')) => {
+                s = &s[3..];
+                seconds = i32::from((s1 - b'0') * 10 + (s2 - b'0'));
+            }
+            Ok((b'6'..=b'9', b'0'..=b'9')) => return Err(OUT_OF_RANGE),
+            // anything else after the `:` is left for the following items
+            _ => {}
+        }
+    }
+
+    let seconds = hours * 3600 + minutes * 60 + seconds;
     Ok((s, if negative { -seconds } else { seconds }))
 }
 
@@ -73,7 +73,7 @@ The following specifiers are available both to formatting and parsing.
 | `%:z` | `+09:30` | Same as `%z` but with a colon.                                             |
 |`%::z`|`+09:30:00`| Offset from the local time to UTC with seconds.                            |
 |`%:::z`| `+09`    | Offset from the local time to UTC without minutes.                         |
-| `%#z` | `+09`    | *Parsing only:* Same as `%z` but allows minutes to be missing or present.  |
+| `%#z` | `+09:30` | Shortest form of the offset, omitting zero minutes and seconds. [^9]      |
 |       |          |                                                                            |
 |       |          | **DATE & TIME SPECIFIERS:**                                                |
 |`%c`|`Sun Jul  8 00:34:60 2001`|Locale's date and time (e.g., Thu Mar  3 23:05:25 2005).       |
@@ -86,6 +86,9 @@ The following specifiers are available both to formatting and parsing.
 | `%n`  |          | Literal newline (`\n`).                                                    |
 | `%%`  |          | Literal percent sign.                                                      |
 
+Text from elsewhere can be embedded in a format string by escaping its `%` signs
+with [`escape_literal`](../fn.escape_literal.html).
+
 It is possible to override the default padding behavior of numeric specifiers `%?`.
 This is not allowed for other specifiers and will result in the `BAD_FORMAT` error.
 
@@ -95,6 +98,29 @@ Modifier | Description
 `%_?`    | Uses spaces as a padding. (e.g. `%j` = `012`, `%_j` = ` 12`)
 `%0?`    | Uses zeroes as a padding. (e.g. `%e` = ` 9`, `%0e` = `09`)
 
+## Strict parsing
+
+By default parsing is lenient about padding and whitespace. When parsing with
+[`ParseMode::Strict`](../enum.ParseMode.html) through
+[`parse_with_mode`](../fn.parse_with_mode.html), the input has to look like
+the formatted output instead:
+
+Specifiers | Strict parsing
+---------- | --------------
+`%Y`, `%G` | Exactly 4 digits (`0987`), or a sign followed by any number of digits (`+12345`, `-0001`).
+`%C`, `%y`, `%m`, `%d`, `%U`, `%W`, `%g`, `%V`, `%H`, `%I`, `%M`, `%S` | Exactly 2 digits, so `5` is rejected and `05` is required.
+`%j` | Exactly 3 digits.
+`%f` | Exactly 9 digits.
+`%e`, `%k`, `%l` | Exactly 2 characters: leading spaces followed by digits, like ` 5` or `15`.
+`%w`, `%u` | Exactly 1 digit.
+`%s` | One or more digits, with an optional sign.
+`%-?` | One up to the maximum number of digits of the specifier, like `5` or `05` for `%-d`.
+`%_?`, `%0?` | Same as space and zero padded specifiers above.
+`%z`, `%:z`, `%::z`, `%:::z`, `%#z` | No whitespace is skipped before the offset.
+`%t`, `%n`, whitespace | The whitespace has to match exactly; in lenient mode any amount is accepted.
+
+All other specifiers are parsed the same in both modes.
+
 Notes:
 
 [^1]: `%C`, `%y`:
@@ -107,6 +133,9 @@ Notes:
 [^3]: `%G`, `%g`, `%V`:
    Week 1 is the first week with at least 4 days in that year.
    Week 0 does not exist, so this should be used with `%G` or `%g`.
+   When parsing, `%G-W%V-%u` is enough to determine a date. If `%Y` is given as
+   well it has to agree with the resulting date, so `2020-W53-5` (2021-01-01)
+   is accepted with `%Y` = `2021` but not with `2020`.
 
 [^4]: `%S`:
    It accounts for leap seconds, so `60` is possible.
@@ -152,7 +181,7 @@ Notes:
    The variant `%.3f`, `%.6f` and `%.9f` are left-aligned and print 3, 6 or 9 fractional digits
    according to the number preceding `f`.
    E.g. 70ms after the last second under `%.3f` will print `.070` (note: not `.07`),
-   and parsing `.07`, `.070000` etc. will yield the same.
+   and parsing needs the same number of digits, so only `.070` will yield the same.
    Note that they can read nothing if the fractional part is zero or
    the next character is not `.` however will print with the specified length.
    <br>
@@ -160,11 +189,15 @@ Notes:
    The variant `%3f`, `%6f` and `%9f` are left-aligned and print 3, 6 or 9 fractional digits
    according to the number preceding `f`, but without the leading dot.
    E.g. 70ms after the last second under `%3f` will print `070` (note: not `07`),
-   and parsing `07`, `070000` etc. will yield the same.
-   Note that they can read nothing if the fractional part is zero.
+   and parsing needs the same number of digits, so only `070` will yield the same.
 
 [^8]: `%Z`:
-   Offset will not be populated from the parsed data, nor will it be validated.
+   When formatting, this prints the [abbreviation](../../offset/trait.Offset.html#method.abbreviation)
+   of the offset if it has one, like `UTC`, or the offset's `Display` output otherwise,
+   like `+09:30` for a `FixedOffset`.
+   <br>
+   <br>
+   When parsing, the offset will not be populated from the parsed data, nor will it be validated.
    Timezone is completely ignored. Similar to the glibc `strptime` treatment of
    this format code.
    <br>
@@ -172,11 +205,27 @@ Notes:
    It is not possible to reliably convert from an abbreviation to an offset,
    for example CDT can mean either Central Daylight Time (North America) or
    China Daylight Time.
+
+[^9]: `%#z`:
+   Prints the hours, then the minutes and seconds only if they are not zero,
+   so `+09:00` prints `+09` and `+09:30` prints `+09:30`.
+   When parsing this accepts the same input as `%z`, but allows the minutes
+   to be missing or present, and accepts `Z` for a zero offset.
+   So `+05`, `+0500`, `+05:00` and `Z` are all accepted, while `%z` only
+   accepts the first two forms with minutes.
 */
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
 #[cfg(feature = "unstable-locales")]
 use super::{locales, Locale};
 use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad};
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use super::{ParseError, BAD_FORMAT};
 
 #[cfg(feature = "unstable-locales")]
 type Fmt<'a> = Vec<Item<'a>>;
@@ -236,6 +285,39 @@ impl<'a> StrftimeItems<'a> {
         StrftimeItems { remainder: s, recons: Vec::new(), d_fmt, d_t_fmt, t_fmt }
     }
 
+    /// Parses the whole format string into owned formatting items.
+    ///
+    /// Unlike the items yielded by the iterator, the returned items do not
+    /// borrow from the format string, so they can be stored and reused after
+    /// the format string has been dropped.
+    ///
+    /// Returns `Err(ParseError)` if the format string contains an invalid or
+    /// unsupported specifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{Item, StrftimeItems};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let fmt = String::from("%Y-%m-%d");
+    /// let items: Vec<Item<'static>> = StrftimeItems::new(&fmt).parse_to_owned()?;
+    /// drop(fmt);
+    ///
+    /// let dt = Utc.ymd(2023, 6, 1)?.and_hms(0, 0, 0)?;
+    /// assert_eq!(dt.format_with_items(items.iter()).to_string(), "2023-06-01");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn parse_to_owned(self) -> Result<Vec<Item<'static>>, ParseError> {
+        self.map(|item| match item {
+            Item::Error => Err(BAD_FORMAT),
+            item => Ok(item.into_owned()),
+        })
+        .collect()
+    }
+
     #[cfg(not(feature = "unstable-locales"))]
     fn with_remainer(s: &'a str) -> StrftimeItems<'a> {
         static FMT_NONE: &[Item<'static>; 0] = &[];
@@ -547,6 +629,39 @@ fn test_strftime_items() {
     assert_eq!(parse_and_collect("%#m"), [Item::Error]);
 }
 
+#[cfg(test)]
+#[test]
+fn test_strftime_parse_to_owned() {
+    use crate::{TimeZone, Utc};
+
+    let items = {
+        let fmt = String::from("%Y-%m-%d %H:%M:%S");
+        StrftimeItems::new(&fmt).parse_to_owned().unwrap()
+    };
+    assert_eq!(
+        items,
+        [
+            num0!(Year),
+            Item::OwnedLiteral("-".into()),
+            num0!(Month),
+            Item::OwnedLiteral("-".into()),
+            num0!(Day),
+            Item::OwnedSpace(" ".into()),
+            num0!(Hour),
+            Item::OwnedLiteral(":".into()),
+            num0!(Minute),
+            Item::OwnedLiteral(":".into()),
+            num0!(Second),
+        ]
+    );
+
+    let dt = Utc.ymd(2001, 7, 8).unwrap().and_hms(0, 34, 59).unwrap();
+    assert_eq!(dt.format_with_items(items.iter()).to_string(), "2001-07-08 00:34:59");
+
+    assert_eq!(StrftimeItems::new("%Y-%Q").parse_to_owned(), Err(BAD_FORMAT));
+    assert_eq!(StrftimeItems::new("%").parse_to_owned(), Err(BAD_FORMAT));
+}
+
 #[cfg(test)]
 #[test]
 fn test_strftime_docs() {
@@ -610,11 +725,12 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%r").to_string(), "12:34:60 AM");
 
     // time zone specifiers
-    //assert_eq!(dt.format("%Z").to_string(), "ACST");
+    assert_eq!(dt.format("%Z").to_string(), "+09:30");
     assert_eq!(dt.format("%z").to_string(), "+0930");
     assert_eq!(dt.format("%:z").to_string(), "+09:30");
     assert_eq!(dt.format("%::z").to_string(), "+09:30:00");
     assert_eq!(dt.format("%:::z").to_string(), "+09");
+    assert_eq!(dt.format("%#z").to_string(), "+09:30");
 
     // date & time specifiers
     assert_eq!(dt.format("%c").to_string(), "Sun Jul  8 00:34:60 2001");
@@ -649,6 +765,156 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%%").to_string(), "%");
 }
 
+#[cfg(test)]
+#[test]
+fn test_strftime_offset_variants() {
+    use crate::{FixedOffset, TimeZone};
+
+    let format = |offset: FixedOffset, fmt: &str| {
+        offset.ymd(2001, 7, 8).unwrap().and_hms(0, 34, 59).unwrap().format(fmt).to_string()
+    };
+
+    let offset = FixedOffset::east(5 * 3600 + 30 * 60);
+    assert_eq!(format(offset, "%:z"), "+05:30");
+    assert_eq!(format(offset, "%::z"), "+05:30:00");
+    assert_eq!(format(offset, "%#z"), "+05:30");
+
+    let offset = FixedOffset::east(30);
+    assert_eq!(format(offset, "%:z"), "+00:00");
+    assert_eq!(format(offset, "%::z"), "+00:00:30");
+    assert_eq!(format(offset, "%#z"), "+00:00:30");
+
+    let offset = FixedOffset::west(5 * 3600);
+    assert_eq!(format(offset, "%:z"), "-05:00");
+    assert_eq!(format(offset, "%::z"), "-05:00:00");
+    assert_eq!(format(offset, "%#z"), "-05");
+
+    let offset = FixedOffset::east(0);
+    assert_eq!(format(offset, "%:z"), "+00:00");
+    assert_eq!(format(offset, "%::z"), "+00:00:00");
+    assert_eq!(format(offset, "%#z"), "+00");
+}
+
+#[cfg(test)]
+#[test]
+fn test_strftime_items_by_hand() {
+    use super::{Fixed, Item, Numeric, Pad};
+    use crate::{FixedOffset, NaiveDate, TimeZone};
+
+    let date = [
+        Item::numeric(Numeric::Year, Pad::Zero),
+        Item::literal("-"),
+        Item::numeric(Numeric::Month, Pad::Zero),
+        Item::literal("-"),
+        Item::numeric(Numeric::Day, Pad::Zero),
+    ];
+    assert_eq!(StrftimeItems::new("%Y-%m-%d").collect::<Vec<_>>(), date);
+
+    let d = NaiveDate::from_ymd(2022, 3, 4).unwrap();
+    assert_eq!(d.format_with_items(date.iter()).to_string(), "2022-03-04");
+    assert_eq!(d.format_with_items(date.iter()).to_string(), d.format("%Y-%m-%d").to_string());
+
+    let mut datetime = date.to_vec();
+    datetime.extend_from_slice(&[
+        Item::space(" "),
+        Item::numeric(Numeric::Hour, Pad::Space),
+        Item::literal(":"),
+        Item::numeric(Numeric::Minute, Pad::Zero),
+        Item::space(" "),
+        Item::fixed(Fixed::TimezoneOffsetColon),
+    ]);
+    let dt = FixedOffset::east(3600).ymd(2022, 3, 4).unwrap().and_hms(5, 6, 7).unwrap();
+    assert_eq!(dt.format_with_items(datetime.iter()).to_string(), "2022-03-04  5:06 +01:00");
+
+    // the same items can be used for parsing
+    let mut parsed = super::Parsed::new();
+    super::parse(&mut parsed, "2022-03-04 5:06 +01:00", datetime.iter()).unwrap();
+    assert_eq!(parsed.to_naive_date(), Ok(d));
+    assert_eq!((parsed.minute, parsed.offset), (Some(6), Some(3600)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_strftime_timezone_name() {
+    use crate::{
+        ChronoError, FixedOffset, NaiveDate, NaiveDateTime, Offset, PosixTz, TimeZone, Utc,
+    };
+    use core::fmt;
+
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%Z").to_string(), "UTC");
+    assert_eq!(dt.date().format("%Z").to_string(), "UTC");
+
+    // without an abbreviation, the `Display` output is used
+    let dt = FixedOffset::east(9 * 3600 + 1800).ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%Z").to_string(), "+09:30");
+
+    let tz: PosixTz = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+    let dt = tz.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%H:%M %Z (%:z)").to_string(), "12:00 CEST (+02:00)");
+
+    // a custom offset whose abbreviation differs from its `Display` output
+    #[derive(Clone, Debug)]
+    struct Acst;
+
+    impl Offset for Acst {
+        fn fix(&self) -> FixedOffset {
+            FixedOffset::east(9 * 3600 + 1800)
+        }
+
+        fn abbreviation(&self) -> Option<&str> {
+            Some("ACST")
+        }
+    }
+
+    impl fmt::Display for Acst {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("Australian Central Standard Time")
+        }
+    }
+
+    impl TimeZone for Acst {
+        type Offset = Acst;
+
+        fn from_offset(_: &Acst) -> Acst {
+            Acst
+        }
+        fn offset_from_local_date(&self, _: &NaiveDate) -> Result<Acst, ChronoError> {
+            Ok(Acst)
+        }
+        fn offset_from_local_datetime(&self, _: &NaiveDateTime) -> Result<Acst, ChronoError> {
+            Ok(Acst)
+        }
+        fn offset_from_utc_date(&self, _: &NaiveDate) -> Result<Acst, ChronoError> {
+            Ok(Acst)
+        }
+        fn offset_from_utc_datetime(&self, _: &NaiveDateTime) -> Result<Acst, ChronoError> {
+            Ok(Acst)
+        }
+    }
+
+    let dt = Acst.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%Z %z").to_string(), "ACST +0930");
+    assert_eq!(dt.to_string(), "2022-06-01 12:00:00 Australian Central Standard Time");
+}
+
+#[cfg(test)]
+#[test]
+fn test_strftime_escape_literal() {
+    use super::escape_literal;
+    use crate::{TimeZone, Utc};
+
+    let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+    assert_eq!(escape_literal("100%"), "100%%");
+    assert_eq!(dt.format(&escape_literal("100%")).to_string(), "100%");
+
+    for &text in &["", "%", "%%", "%Y-%m-%d", "100% of %%", "%%%", "naïve % ünïcode"] {
+        let fmt = escape_literal(text);
+        assert_eq!(dt.format(&fmt).to_string(), text);
+        assert_eq!(dt.format(&format!("{} %Y", fmt)).to_string(), format!("{} 2022", text));
+    }
+}
+
 #[cfg(feature = "unstable-locales")]
 #[test]
 fn test_strftime_docs_localized() {
@@ -685,3 +951,26 @@ fn test_strftime_docs_localized() {
         "dim 08 jui 2001 00:34:60 +09:30"
     );
 }
+
+#[cfg(feature = "unstable-locales")]
+#[test]
+fn test_strftime_localized_names() {
+    use crate::{TimeZone, Utc};
+
+    let dt = Utc.ymd(2001, 1, 8).unwrap().and_hms(0, 34, 59).unwrap();
+
+    assert_eq!(dt.format_localized("%B", Locale::fr_FR).to_string(), "janvier");
+    assert_eq!(dt.format_localized("%A %B", Locale::en_US).to_string(), "Monday January");
+    assert_eq!(dt.format_localized("%A %B", Locale::fr_FR).to_string(), "lundi janvier");
+    assert_eq!(dt.format_localized("%A %B", Locale::de_DE).to_string(), "Montag Januar");
+    assert_eq!(dt.format_localized("%A %B", Locale::es_ES).to_string(), "lunes enero");
+    assert_eq!(dt.format_localized("%A %B", Locale::ja_JP).to_string(), "月曜日 1月");
+
+    // numeric and offset specifiers do not depend on the locale
+    for &locale in &[Locale::en_US, Locale::fr_FR, Locale::de_DE, Locale::es_ES, Locale::ja_JP] {
+        assert_eq!(
+            dt.format_localized("%Y-%m-%d %H:%M:%S %z", locale).to_string(),
+            "2001-01-08 00:34:59 +0000"
+        );
+    }
+}
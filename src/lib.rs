@@ -23,7 +23,9 @@
 //!
 //! Default features:
 //!
-//! - `alloc`: Enable features that depend on allocation (primarily string formatting)
+//! - `alloc`: Enable features that depend on allocation (primarily string formatting).
+//!   Formatting, e.g. [`DateTime::format`] and [`DateTime::to_rfc3339`], only
+//!   needs this feature and works without the standard library.
 //! - `std`: Enables functionality that depends on the standard library. This
 //!   is a superset of `alloc` and adds interoperation with standard library types
 //!   and traits.
@@ -36,8 +38,14 @@
 //! - `unstable-locales`: Enable localization. This adds various methods with a
 //!   `_localized` suffix. The implementation and API may change or even be
 //!   removed in a patch release. Feedback welcome.
+//! - [`arbitrary`][]: Implement `arbitrary::Arbitrary` for the date and time
+//!   types, generating only valid values. Useful for fuzzing.
+//! - [`proptest`][]: Add strategies for property testing in the
+//!   `chrono::proptest` module.
 //!
 //! [`serde`]: https://github.com/serde-rs/serde
+//! [`arbitrary`]: https://github.com/rust-fuzz/arbitrary
+//! [`proptest`]: https://github.com/proptest-rs/proptest
 //! [wasm-bindgen]: https://github.com/rustwasm/wasm-bindgen
 //!
 //! See the [cargo docs][] for examples of specifying features.
@@ -49,7 +57,9 @@
 //! ### Duration
 //!
 //! Chrono currently uses its own [`TimeDelta`] type to represent the magnitude
-//! of a time span. Note that this is an "accurate" duration represented as seconds and
+//! of a time span. It is also available under its former name [`Duration`],
+//! but `TimeDelta` is preferred to avoid confusion with the standard type.
+//! Note that this is an "accurate" duration represented as seconds and
 //! nanoseconds and does not represent "nominal" components such as days or
 //! months.
 //!
@@ -57,7 +67,9 @@
 //! the standard [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html) type,
 //! but it will be supported in the future.
 //! Meanwhile you can convert between two types with
-//! [`TimeDelta::from_std`] and [`TimeDelta::to_std`] methods.
+//! [`TimeDelta::from_std`] and [`TimeDelta::to_std`] methods, or through their
+//! `TryFrom` implementations. Converting a negative `TimeDelta` fails, as does
+//! converting a standard `Duration` longer than `i64::MAX` milliseconds.
 //!
 //! ### Date and Time
 //!
@@ -396,7 +408,21 @@
 use core::fmt;
 
 mod time_delta;
-pub use time_delta::TimeDelta;
+pub use time_delta::{HumanizedTimeDelta, OutOfRangeError, TimeDelta};
+
+/// The former name of [`TimeDelta`], kept so existing code continues to work.
+///
+/// `TimeDelta` is the preferred name, as it can't be confused with
+/// [`std::time::Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html).
+/// Both names refer to the same type, so they can be mixed freely.
+///
+/// ```
+/// use chrono::{Duration, TimeDelta};
+///
+/// let sum: TimeDelta = Duration::minutes(1) + TimeDelta::seconds(30);
+/// assert_eq!(sum, Duration::seconds(90));
+/// ```
+pub type Duration = TimeDelta;
 
 #[cfg(feature = "__doctest")]
 #[cfg_attr(feature = "__doctest", cfg(doctest))]
@@ -438,7 +464,9 @@ pub use date::{Date, MAX_DATE, MIN_DATE};
 
 mod datetime;
 #[allow(deprecated)]
-pub use datetime::{DateTime, SecondsFormat, MAX_DATETIME, MIN_DATETIME};
+pub use datetime::{
+    ByInstant, DateTime, DateTimeStepIterator, Interval, SecondsFormat, MAX_DATETIME, MIN_DATETIME,
+};
 
 mod error;
 pub use self::error::ChronoError;
@@ -455,12 +483,12 @@ pub mod naive;
 pub use naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveWeek};
 
 pub mod offset;
+#[doc(no_inline)]
+pub use offset::{Clock, FixedOffset, LocalResult, Offset, PosixTz, PosixTzOffset, TimeZone, Utc};
 #[cfg(feature = "clock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
 #[doc(no_inline)]
-pub use offset::Local;
-#[doc(no_inline)]
-pub use offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+pub use offset::{Local, SystemClock};
 
 mod round;
 pub use round::{DurationRound, RoundingError, SubsecRound};
@@ -472,7 +500,7 @@ mod month;
 pub use month::{Month, Months, ParseMonthError};
 
 mod traits;
-pub use traits::{Datelike, Timelike};
+pub use traits::{DateField, Datelike, Timelike};
 
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
@@ -493,6 +521,10 @@ pub mod serde {
     pub use super::datetime::serde::*;
 }
 
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+
 /// Out of range error type used in various converting APIs
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub struct OutOfRange {
@@ -3,7 +3,8 @@ use core::{convert::TryFrom, fmt};
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
-use crate::OutOfRange;
+use crate::error::ChronoErrorKind;
+use crate::{ChronoError, OutOfRange};
 
 /// The month of the year.
 ///
@@ -61,6 +62,26 @@ pub enum Month {
 }
 
 impl Month {
+    /// Makes a `Month` from its number, from 1 for January to 12 for December.
+    ///
+    /// This accepts the result of [`Datelike::month`](./trait.Datelike.html#tymethod.month)
+    /// directly. Returns `Err(ChronoError)` if `month` is out of range.
+    ///
+    /// ```
+    /// use chrono::{Datelike, Month, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd(2019, 10, 28)?;
+    /// assert_eq!(Month::from_u32(date.month())?, Month::October);
+    /// assert!(Month::from_u32(13).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn from_u32(month: u32) -> Result<Month, ChronoError> {
+        match month {
+            1..=12 => Ok(MONTHS[month as usize - 1]),
+            _ => Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+        }
+    }
+
     /// The next month.
     ///
     /// `m`:        | `January`  | `February` | `...` | `December`
@@ -155,6 +176,21 @@ impl Month {
     }
 }
 
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
 impl TryFrom<u8> for Month {
     type Error = OutOfRange;
 
@@ -334,4 +370,27 @@ mod tests {
         assert_eq!(Month::January.pred(), Month::December);
         assert_eq!(Month::February.pred(), Month::January);
     }
+
+    #[test]
+    fn test_month_enum_from_u32_and_names() {
+        assert_eq!(Month::from_u32(1), Ok(Month::January));
+        assert_eq!(Month::from_u32(12), Ok(Month::December));
+        assert!(Month::from_u32(0).is_err());
+        assert!(Month::from_u32(13).is_err());
+
+        let mut month = Month::January;
+        for m in 1..=12 {
+            assert_eq!(Month::from_u32(m), Ok(month));
+            assert_eq!(month.number_from_month(), m);
+            assert_eq!(month.name().parse::<Month>(), Ok(month));
+            assert_eq!(month.name()[..3].to_lowercase().parse::<Month>(), Ok(month));
+            month = month.succ();
+        }
+        assert_eq!(month, Month::January);
+
+        assert_eq!("sep".parse::<Month>(), Ok(Month::September));
+        assert_eq!("DECEMBER".parse::<Month>(), Ok(Month::December));
+        assert!("Sept".parse::<Month>().is_err());
+        assert!("Smarch".parse::<Month>().is_err());
+    }
 }
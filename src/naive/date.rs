@@ -18,7 +18,7 @@ use crate::error::ChronoErrorKind;
 use crate::format::DelayedFormat;
 use crate::format::{parse, ParseError, ParseResult, Parsed, StrftimeItems};
 use crate::format::{Item, Numeric, Pad};
-use crate::month::Months;
+use crate::month::{Month, Months};
 use crate::naive::{IsoWeek, NaiveDateTime, NaiveTime};
 use crate::{ChronoError, Datelike, TimeDelta, Weekday};
 
@@ -28,6 +28,9 @@ use super::isoweek;
 const MAX_YEAR: i32 = internals::MAX_YEAR;
 const MIN_YEAR: i32 = internals::MIN_YEAR;
 
+// The Julian day number of 0000-12-31, which is day 0 in `from_num_days_from_ce`.
+const JDN_OF_DAY_0_FROM_CE: i64 = 1_721_425;
+
 //   MAX_YEAR-12-31 minus 0000-01-01
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + (0001-01-01 minus 0000-01-01) - 1 day
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + 365 days
@@ -67,9 +70,10 @@ impl NaiveWeek {
     /// ```
     /// use chrono::{NaiveDate, Weekday};
     ///
-    /// let date = NaiveDate::from_ymd(2022, 4, 18);
+    /// let date = NaiveDate::from_ymd(2022, 4, 18)?;
     /// let week = date.week(Weekday::Mon);
     /// assert!(week.first_day() <= date);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn first_day(&self) -> NaiveDate {
@@ -86,9 +90,10 @@ impl NaiveWeek {
     /// ```
     /// use chrono::{NaiveDate, Weekday};
     ///
-    /// let date = NaiveDate::from_ymd(2022, 4, 18);
+    /// let date = NaiveDate::from_ymd(2022, 4, 18)?;
     /// let week = date.week(Weekday::Mon);
     /// assert!(week.last_day() >= date);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn last_day(&self) -> NaiveDate {
@@ -104,10 +109,11 @@ impl NaiveWeek {
     /// ```
     /// use chrono::{NaiveDate, Weekday};
     ///
-    /// let date = NaiveDate::from_ymd(2022, 4, 18);
+    /// let date = NaiveDate::from_ymd(2022, 4, 18)?;
     /// let week = date.week(Weekday::Mon);
     /// let days = week.days();
     /// assert!(days.contains(&date));
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn days(&self) -> RangeInclusive<NaiveDate> {
@@ -198,16 +204,23 @@ impl NaiveDate {
     /// The maximum possible `NaiveDate` (December 31, 262143 CE).
     pub const MAX: NaiveDate = NaiveDate { ymdf: (MAX_YEAR << 13) | (365 << 4) | 0o17 /*F*/ };
     /// Date that corresponds to the start of the unix epoch.
-    pub(crate) const UNIX_EPOCH: NaiveDate = NaiveDate { ymdf: (1970 << 13) | (1 << 4) | 1 };
+    pub(crate) const UNIX_EPOCH: NaiveDate = NaiveDate::from_ymd_const(1970, 1, 1);
 
     /// Makes a new `NaiveDate` from year and packed ordinal-flags, with a verification.
+    ///
+    /// An invalid ordinal is reported as `InvalidDate`, while a valid date
+    /// outside of the supported range of years is reported as `Overflow`.
     fn from_of(year: i32, of: Of) -> Result<NaiveDate, ChronoError> {
-        if (MIN_YEAR..=MAX_YEAR).contains(&year) && of.valid() {
-            let Of(of) = of;
-            Ok(NaiveDate { ymdf: (year << 13) | (of as DateImpl) })
-        } else {
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate))
+        if !of.valid() {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+        }
+
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(ChronoError::new(ChronoErrorKind::Overflow));
         }
+
+        let Of(of) = of;
+        Ok(NaiveDate { ymdf: (year << 13) | (of as DateImpl) })
     }
 
     /// Makes a new `NaiveDate` from year and packed month-day-flags, with a verification.
@@ -250,6 +263,88 @@ impl NaiveDate {
         NaiveDate::from_mdf(year, Mdf::new(month, day, flags))
     }
 
+    /// Makes a new `NaiveDate` from the [calendar date](#calendar-date) (year,
+    /// month and day) in a `const` context.
+    ///
+    /// This is the `const` counterpart of [`from_ymd`](#method.from_ymd).
+    ///
+    /// # Panics
+    ///
+    /// Panics on the out-of-range date, invalid month and/or day. When used to
+    /// initialize a `const` or `static` item this is a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// const EPOCH: NaiveDate = NaiveDate::from_ymd_const(1970, 1, 1);
+    /// assert_eq!(EPOCH, NaiveDate::from_ymd(1970, 1, 1)?);
+    /// assert_eq!(EPOCH.ordinal(), 1);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    ///
+    /// An invalid date fails to compile:
+    ///
+    /// ```compile_fail
+    /// use chrono::NaiveDate;
+    ///
+    /// const INVALID: NaiveDate = NaiveDate::from_ymd_const(2015, 2, 29);
+    /// let _date = INVALID;
+    /// ```
+    pub const fn from_ymd_const(year: i32, month: u32, day: u32) -> NaiveDate {
+        const CUMUL_DAYS: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+        const MONTH_DAYS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+        // branches aren't available in a `const fn` on our minimum supported
+        // Rust version, so this is computed without them.
+        let leap = (year % 4 == 0) & ((year % 100 != 0) | (year % 400 == 0));
+        // always in bounds, an invalid month is rejected below
+        let month0 = (month.wrapping_sub(1) % 12) as usize;
+        let ndays = MONTH_DAYS[month0] + (leap & (month == 2)) as u32;
+        let valid = (year >= MIN_YEAR)
+            & (year <= MAX_YEAR)
+            & (month >= 1)
+            & (month <= 12)
+            & (day >= 1)
+            & (day <= ndays);
+
+        let ordinal = CUMUL_DAYS[month0] + (leap & (month > 2)) as u32 + day;
+        // the weekday of December 31 in the previous year, counting from
+        // Monday as 0, which repeats every 400 years
+        let prev = (year % 400 + 399) % 400;
+        let weekday = (prev * 365 + prev / 4 - prev / 100 + 6) % 7;
+        // see `YearFlags` for the encoding
+        let flags = (((!leap) as DateImpl) << 3) | ((weekday + 6) % 7 + 1);
+        let ymdf = (year << 13) | ((ordinal << 4) as DateImpl) | flags;
+
+        // indexing out of bounds is how an invalid date panics
+        NaiveDate { ymdf: [ymdf][(!valid) as usize] }
+    }
+
+    /// Makes a new `NaiveDate` from the [calendar date](#calendar-date), like
+    /// [`from_ymd`](#method.from_ymd) but with the month given as a [`Month`].
+    ///
+    /// Returns `Err(ChronoError)` on the out-of-range date and/or invalid day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Month, NaiveDate};
+    ///
+    /// let d = NaiveDate::from_year_month_day(2015, Month::March, 14)?;
+    /// assert_eq!(d, NaiveDate::from_ymd(2015, 3, 14)?);
+    /// assert!(NaiveDate::from_year_month_day(2015, Month::February, 29).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn from_year_month_day(
+        year: i32,
+        month: Month,
+        day: u32,
+    ) -> Result<NaiveDate, ChronoError> {
+        NaiveDate::from_ymd(year, month.number_from_month(), day)
+    }
+
     /// Makes a new `NaiveDate` from the [ordinal date](#ordinal-date) (year and
     /// day of the year).
     ///
@@ -382,9 +477,9 @@ impl NaiveDate {
     }
 
     /// Makes a new `NaiveDate` from a day's number in the proleptic Gregorian calendar, with
-    /// January 1, 1 being day 1.
+    /// January 1, 1 being day 1. This is the inverse of [`Datelike::num_days_from_ce`].
     ///
-    /// Panics if the date is out of range.
+    /// Returns `Err(ChronoError)` on the out-of-range date.
     ///
     /// # Example
     ///
@@ -403,29 +498,28 @@ impl NaiveDate {
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
-    /// While not directly supported by Chrono,
-    /// it is easy to convert from the Julian day number
+    /// Converting from the Julian day number
     /// (January 1, 4713 BCE in the *Julian* calendar being Day 0)
-    /// to Gregorian with this method.
-    /// (Note that this panics when `jd` is out of range.)
+    /// is a matter of shifting the day number,
+    /// which is what [`NaiveDate::from_jdn`] does.
     ///
     /// ```
-    /// use chrono::NaiveDate;
+    /// use chrono::{ChronoError, NaiveDate};
     /// let from_num_days_from_ce = NaiveDate::from_num_days_from_ce;
     /// let from_ymd = NaiveDate::from_ymd;
     ///
-    /// fn jd_to_date(jd: i32) -> NaiveDate {
+    /// fn jd_to_date(jd: i32) -> Result<NaiveDate, ChronoError> {
     ///     // keep in mind that the Julian day number is 0-based
     ///     // while this method requires an 1-based number.
     ///     NaiveDate::from_num_days_from_ce(jd - 1721425)
     /// }
     ///
     /// // January 1, 4713 BCE in Julian = November 24, 4714 BCE in Gregorian
-    /// assert_eq!(jd_to_date(0), NaiveDate::from_ymd(-4713, 11, 24));
+    /// assert_eq!(jd_to_date(0), from_ymd(-4713, 11, 24));
     ///
-    /// assert_eq!(jd_to_date(1721426), NaiveDate::from_ymd(1, 1, 1));
-    /// assert_eq!(jd_to_date(2450000), NaiveDate::from_ymd(1995, 10, 9));
-    /// assert_eq!(jd_to_date(2451545), NaiveDate::from_ymd(2000, 1, 1));
+    /// assert_eq!(jd_to_date(1721426), from_ymd(1, 1, 1));
+    /// assert_eq!(jd_to_date(2450000), from_ymd(1995, 10, 9));
+    /// assert_eq!(jd_to_date(2451545), from_ymd(2000, 1, 1));
     ///
     /// assert_eq!(from_num_days_from_ce(730_000),      Ok(from_ymd(1999, 9, 3)?));
     /// assert_eq!(from_num_days_from_ce(1),            Ok(from_ymd(1, 1, 1)?));
@@ -433,16 +527,67 @@ impl NaiveDate {
     /// assert_eq!(from_num_days_from_ce(-1),           Ok(from_ymd(0, 12, 30)?));
     /// assert!(from_num_days_from_ce(100_000_000).is_err());
     /// assert!(from_num_days_from_ce(-100_000_000).is_err());
+    /// # Ok::<_, ChronoError>(())
     /// ```
     #[inline]
     pub fn from_num_days_from_ce(days: i32) -> Result<NaiveDate, ChronoError> {
-        let days = days + 365; // make December 31, 1 BCE equal to day 0
+        // make December 31, 1 BCE equal to day 0
+        let days = days.checked_add(365).ok_or(ChronoErrorKind::Overflow)?;
         let (year_div_400, cycle) = div_mod_floor(days, 146_097);
         let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
         let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
         NaiveDate::from_of(year_div_400 * 400 + year_mod_400 as i32, Of::new(ordinal, flags))
     }
 
+    /// Makes a new `NaiveDate` from a [Julian day number][jdn], which counts
+    /// the days since January 1, 4713 BCE in the proleptic *Julian* calendar.
+    ///
+    /// A Julian day starts at noon, so the date returned here is the one on
+    /// which the Julian day with the given number starts.
+    ///
+    /// Returns `Err(ChronoError)` on the out-of-range date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_jdn(2_451_545)?, NaiveDate::from_ymd(2000, 1, 1)?);
+    /// assert_eq!(NaiveDate::from_jdn(2_299_161)?, NaiveDate::from_ymd(1582, 10, 15)?);
+    /// // November 24, 4714 BCE in the proleptic Gregorian calendar
+    /// assert_eq!(NaiveDate::from_jdn(0)?, NaiveDate::from_ymd(-4713, 11, 24)?);
+    /// assert!(NaiveDate::from_jdn(i64::MAX).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    ///
+    /// [jdn]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn from_jdn(jdn: i64) -> Result<NaiveDate, ChronoError> {
+        let days = jdn
+            .checked_sub(JDN_OF_DAY_0_FROM_CE)
+            .and_then(|days| i32::try_from(days).ok())
+            .ok_or(ChronoErrorKind::Overflow)?;
+        NaiveDate::from_num_days_from_ce(days)
+    }
+
+    /// Returns the [Julian day number][jdn] of the Julian day starting at
+    /// noon on this date. This is the inverse of [`NaiveDate::from_jdn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1)?.num_days_from_jdn(), 2_451_545);
+    /// assert_eq!(NaiveDate::from_ymd(1970, 1, 1)?.num_days_from_jdn(), 2_440_588);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    ///
+    /// [jdn]: https://en.wikipedia.org/wiki/Julian_day
+    #[inline]
+    pub fn num_days_from_jdn(&self) -> i64 {
+        i64::from(self.num_days_from_ce()) + JDN_OF_DAY_0_FROM_CE
+    }
+
     /// Makes a new `NaiveDate` by counting the number of occurrences of a
     /// particular day-of-week since the beginning of the given month.  For
     /// instance, if you want the 2nd Friday of March 2017, you would use
@@ -465,7 +610,11 @@ impl NaiveDate {
     /// assert_eq!(from_weekday_of_month(2018, 8, Weekday::Tue, 2), from_ymd(2018, 8, 14));
     /// assert_eq!(from_weekday_of_month(2018, 8, Weekday::Fri, 4), from_ymd(2018, 8, 24));
     /// assert_eq!(from_weekday_of_month(2018, 8, Weekday::Fri, 5), from_ymd(2018, 8, 31));
-    /// assert_eq!(NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)?, NaiveDate::from_ymd(2017, 3, 10)?)
+    /// assert_eq!(NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)?, NaiveDate::from_ymd(2017, 3, 10)?);
+    ///
+    /// // there is no 5th Friday in February 2021
+    /// assert!(from_weekday_of_month(2021, 2, Weekday::Fri, 5).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     pub fn from_weekday_of_month(
         year: i32,
@@ -482,6 +631,100 @@ impl NaiveDate {
         NaiveDate::from_ymd(year, month, day)
     }
 
+    /// Makes a new `NaiveDate` for the last day of the given month.
+    ///
+    /// Returns `Err(ChronoError)` on the out-of-range date and/or invalid month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::last_day_of_month(2020, 2)?, NaiveDate::from_ymd(2020, 2, 29)?);
+    /// assert_eq!(NaiveDate::last_day_of_month(2021, 2)?, NaiveDate::from_ymd(2021, 2, 28)?);
+    /// assert_eq!(NaiveDate::last_day_of_month(2021, 12)?, NaiveDate::from_ymd(2021, 12, 31)?);
+    /// assert!(NaiveDate::last_day_of_month(2021, 13).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn last_day_of_month(year: i32, month: u32) -> Result<NaiveDate, ChronoError> {
+        let day = NaiveDate::num_days_in_month(year, month)?;
+        NaiveDate::from_ymd(year, month, u32::from(day))
+    }
+
+    /// Makes a new `NaiveDate` for the first day of the given quarter of the
+    /// year, where quarters are numbered from 1 to 4.
+    ///
+    /// Returns `Err(ChronoError)` on the out-of-range date and/or invalid quarter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::first_day_of_quarter(2022, 1)?, NaiveDate::from_ymd(2022, 1, 1)?);
+    /// assert_eq!(NaiveDate::first_day_of_quarter(2022, 3)?, NaiveDate::from_ymd(2022, 7, 1)?);
+    /// assert!(NaiveDate::first_day_of_quarter(2022, 0).is_err());
+    /// assert!(NaiveDate::first_day_of_quarter(2022, 5).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn first_day_of_quarter(year: i32, quarter: u32) -> Result<NaiveDate, ChronoError> {
+        if !(1..=4).contains(&quarter) {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+        }
+        NaiveDate::from_ymd(year, quarter * 3 - 2, 1)
+    }
+
+    /// Makes a new `NaiveDate` for the last day of the given quarter of the
+    /// year, where quarters are numbered from 1 to 4.
+    ///
+    /// Returns `Err(ChronoError)` on the out-of-range date and/or invalid quarter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::last_day_of_quarter(2022, 1)?, NaiveDate::from_ymd(2022, 3, 31)?);
+    /// assert_eq!(NaiveDate::last_day_of_quarter(2022, 2)?, NaiveDate::from_ymd(2022, 6, 30)?);
+    /// assert!(NaiveDate::last_day_of_quarter(2022, 5).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn last_day_of_quarter(year: i32, quarter: u32) -> Result<NaiveDate, ChronoError> {
+        if !(1..=4).contains(&quarter) {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+        }
+        NaiveDate::last_day_of_month(year, quarter * 3)
+    }
+
+    /// Returns the number of days in the given month, taking leap years into
+    /// account for February.
+    ///
+    /// This doesn't construct a date, so the year isn't checked against the
+    /// range supported by `NaiveDate`.
+    ///
+    /// Returns `Err(ChronoError)` if `month` is not in the range `1..=12`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::num_days_in_month(2020, 2)?, 29);
+    /// assert_eq!(NaiveDate::num_days_in_month(1900, 2)?, 28);
+    /// assert_eq!(NaiveDate::num_days_in_month(2021, 4)?, 30);
+    /// assert!(NaiveDate::num_days_in_month(2021, 0).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn num_days_in_month(year: i32, month: u32) -> Result<u8, ChronoError> {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Ok(31),
+            4 | 6 | 9 | 11 => Ok(30),
+            2 if YearFlags::from_year(year).ndays() == 366 => Ok(29),
+            2 => Ok(28),
+            _ => Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+        }
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -552,7 +795,7 @@ impl NaiveDate {
             return Ok(self);
         }
 
-        let d = i32::try_from(months.0).ok().ok_or(ChronoErrorKind::InvalidDate)?;
+        let d = i32::try_from(months.0).ok().ok_or(ChronoErrorKind::Overflow)?;
         self.diff_months(d)
     }
 
@@ -583,7 +826,7 @@ impl NaiveDate {
         let d = i32::try_from(months.0)
             .ok()
             .and_then(|n| n.checked_neg())
-            .ok_or(ChronoErrorKind::InvalidDate)?;
+            .ok_or(ChronoErrorKind::Overflow)?;
 
         self.diff_months(d)
     }
@@ -596,7 +839,7 @@ impl NaiveDate {
         let year = if (years > 0 && years > (MAX_YEAR - self.year()))
             || (years < 0 && years < (MIN_YEAR - self.year()))
         {
-            return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+            return Err(ChronoError::new(ChronoErrorKind::Overflow));
         } else {
             self.year() + years
         };
@@ -606,13 +849,13 @@ impl NaiveDate {
         let month = self.month() as i32 + left;
         let (year, month) = if month <= 0 {
             if year == MIN_YEAR {
-                return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+                return Err(ChronoError::new(ChronoErrorKind::Overflow));
             }
 
             (year - 1, month + 12)
         } else if month > 12 {
             if year == MAX_YEAR {
-                return Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+                return Err(ChronoError::new(ChronoErrorKind::Overflow));
             }
 
             (year + 1, month - 12)
@@ -623,9 +866,8 @@ impl NaiveDate {
         // Clamp original day in case new month is shorter
 
         let flags = YearFlags::from_year(year);
-        let feb_days = if flags.ndays() == 366 { 29 } else { 28 };
-        let days = [31, feb_days, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-        let day = Ord::min(self.day(), days[(month - 1) as usize]);
+        let days = NaiveDate::num_days_in_month(year, month as u32)?;
+        let day = Ord::min(self.day(), u32::from(days));
 
         NaiveDate::from_mdf(year, Mdf::new(month as u32, day, flags))
     }
@@ -650,8 +892,8 @@ impl NaiveDate {
             return Ok(self);
         }
 
-        let d = i64::try_from(days.0).ok().ok_or(ChronoErrorKind::InvalidDate)?;
-        self.diff_days(d)
+        let d = i64::try_from(days.0).ok().ok_or(ChronoErrorKind::Overflow)?;
+        self.checked_add_signed_days(d)
     }
 
     /// Subtract a duration in [`Days`] from the date
@@ -673,12 +915,43 @@ impl NaiveDate {
         let d = i64::try_from(days.0)
             .ok()
             .and_then(|n| n.checked_neg())
-            .ok_or(ChronoErrorKind::InvalidDate)?;
-        self.diff_days(d)
+            .ok_or(ChronoErrorKind::Overflow)?;
+        self.checked_add_signed_days(d)
     }
 
-    fn diff_days(self, days: i64) -> Result<Self, ChronoError> {
-        self.checked_add_signed(TimeDelta::days(days))
+    /// Adds a signed number of days to the date.
+    ///
+    /// Unlike [`checked_add_signed`](#method.checked_add_signed), this needs
+    /// no `TimeDelta`, so there is no part of a day to be truncated.
+    ///
+    /// Returns `Err(ChronoError)` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2022, 2, 20)?;
+    /// assert_eq!(d.checked_add_signed_days(9)?, NaiveDate::from_ymd(2022, 3, 1)?);
+    /// assert_eq!(d.checked_add_signed_days(-51)?, NaiveDate::from_ymd(2021, 12, 31)?);
+    /// assert!(d.checked_add_signed_days(i64::max_value()).is_err());
+    /// assert!(NaiveDate::MAX.checked_add_signed_days(1).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn checked_add_signed_days(self, days: i64) -> Result<NaiveDate, ChronoError> {
+        let year = self.year();
+        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
+        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
+        let cycle = i32::try_from(days)
+            .ok()
+            .and_then(|n| (cycle as i32).checked_add(n))
+            .ok_or(ChronoErrorKind::Overflow)?;
+        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
+        year_div_400 += cycle_div_400y;
+
+        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
+        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
+        NaiveDate::from_of(year_div_400 * 400 + year_mod_400 as i32, Of::new(ordinal, flags))
     }
 
     /// Makes a new `NaiveDateTime` from the current date and given `NaiveTime`.
@@ -705,7 +978,7 @@ impl NaiveDate {
     /// No [leap second](./struct.NaiveTime.html#leap-second-handling) is allowed here;
     /// use `NaiveDate::and_hms_*` methods with a subsecond parameter instead.
     ///
-    /// Panics on invalid hour, minute and/or second.
+    /// Returns `Err(ChronoError)` on invalid hour, minute and/or second.
     ///
     /// # Example
     ///
@@ -714,7 +987,7 @@ impl NaiveDate {
     ///
     /// let d = NaiveDate::from_ymd(2015, 6, 3)?;
     ///
-    /// let dt: NaiveDateTime = d.and_hms(12, 34, 56);
+    /// let dt: NaiveDateTime = d.and_hms(12, 34, 56)?;
     /// assert_eq!(dt.year(), 2015);
     /// assert_eq!(dt.weekday(), Weekday::Wed);
     /// assert_eq!(dt.second(), 56);
@@ -792,21 +1065,22 @@ impl NaiveDate {
     /// ```
     /// use chrono::{NaiveDate, NaiveDateTime, Datelike, Timelike, Weekday};
     ///
-    /// let d = NaiveDate::from_ymd(2015, 6, 3);
+    /// let d = NaiveDate::from_ymd(2015, 6, 3)?;
     ///
-    /// let dt: NaiveDateTime = d.and_hms_micro(12, 34, 56, 789_012);
+    /// let dt: NaiveDateTime = d.and_hms_micro(12, 34, 56, 789_012)?;
     /// assert_eq!(dt.year(), 2015);
     /// assert_eq!(dt.weekday(), Weekday::Wed);
     /// assert_eq!(dt.second(), 56);
     /// assert_eq!(dt.nanosecond(), 789_012_000);
     ///
-    /// let d = NaiveDate::from_ymd(2015, 6, 3);
+    /// let d = NaiveDate::from_ymd(2015, 6, 3)?;
     /// assert!(d.and_hms_micro(12, 34, 56,   789_012).is_ok());
     /// assert!(d.and_hms_micro(12, 34, 59, 1_789_012).is_ok()); // leap second
     /// assert!(d.and_hms_micro(12, 34, 59, 2_789_012).is_err());
     /// assert!(d.and_hms_micro(12, 34, 60,   789_012).is_err());
     /// assert!(d.and_hms_micro(12, 60, 56,   789_012).is_err());
     /// assert!(d.and_hms_micro(24, 34, 56,   789_012).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn and_hms_micro(
@@ -832,21 +1106,22 @@ impl NaiveDate {
     /// ```
     /// use chrono::{NaiveDate, NaiveDateTime, Datelike, Timelike, Weekday};
     ///
-    /// let d = NaiveDate::from_ymd(2015, 6, 3);
+    /// let d = NaiveDate::from_ymd(2015, 6, 3)?;
     ///
-    /// let dt: NaiveDateTime = d.and_hms_nano(12, 34, 56, 789_012_345);
+    /// let dt: NaiveDateTime = d.and_hms_nano(12, 34, 56, 789_012_345)?;
     /// assert_eq!(dt.year(), 2015);
     /// assert_eq!(dt.weekday(), Weekday::Wed);
     /// assert_eq!(dt.second(), 56);
     /// assert_eq!(dt.nanosecond(), 789_012_345);
     ///
-    /// let d = NaiveDate::from_ymd(2015, 6, 3);
-    /// assert!(d.and_hms_nano(12, 34, 56,   789_012_345).is_some());
-    /// assert!(d.and_hms_nano(12, 34, 59, 1_789_012_345).is_some()); // leap second
-    /// assert!(d.and_hms_nano(12, 34, 59, 2_789_012_345).is_none());
-    /// assert!(d.and_hms_nano(12, 34, 60,   789_012_345).is_none());
-    /// assert!(d.and_hms_nano(12, 60, 56,   789_012_345).is_none());
-    /// assert!(d.and_hms_nano(24, 34, 56,   789_012_345).is_none());
+    /// let d = NaiveDate::from_ymd(2015, 6, 3)?;
+    /// assert!(d.and_hms_nano(12, 34, 56,   789_012_345).is_ok());
+    /// assert!(d.and_hms_nano(12, 34, 59, 1_789_012_345).is_ok()); // leap second
+    /// assert!(d.and_hms_nano(12, 34, 59, 2_789_012_345).is_err());
+    /// assert!(d.and_hms_nano(12, 34, 60,   789_012_345).is_err());
+    /// assert!(d.and_hms_nano(12, 60, 56,   789_012_345).is_err());
+    /// assert!(d.and_hms_nano(24, 34, 56,   789_012_345).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn and_hms_nano(
@@ -895,20 +1170,20 @@ impl NaiveDate {
 
     /// Makes a new `NaiveDate` for the next calendar date.
     ///
-    /// Returns `Err(ChronoError)` when `self` is the last representable date.
+    /// Returns `Err(ChronoError)` with the overflow kind when `self` is the
+    /// last representable date, [`NaiveDate::MAX`].
     ///
     /// # Example
     ///
     /// ```
     /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015,  6,  3).succ(), NaiveDate::from_ymd(2015, 6, 4));
-    /// assert_eq!(NaiveDate::from_ymd(2015,  6, 30).succ(), NaiveDate::from_ymd(2015, 7, 1));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 12, 31).succ(), NaiveDate::from_ymd(2016, 1, 1));
+    /// assert_eq!(NaiveDate::from_ymd(2015,  6,  3)?.succ()?, NaiveDate::from_ymd(2015, 6, 4)?);
+    /// assert_eq!(NaiveDate::from_ymd(2015,  6, 30)?.succ()?, NaiveDate::from_ymd(2015, 7, 1)?);
+    /// assert_eq!(NaiveDate::from_ymd(2015, 12, 31)?.succ()?, NaiveDate::from_ymd(2016, 1, 1)?);
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 3).succ_opt(),
-    ///            Some(NaiveDate::from_ymd(2015, 6, 4)));
-    /// assert_eq!(NaiveDate::MAX.succ_opt(), None);
+    /// assert!(NaiveDate::MAX.succ().is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn succ(&self) -> Result<NaiveDate, ChronoError> {
@@ -920,20 +1195,20 @@ impl NaiveDate {
 
     /// Makes a new `NaiveDate` for the previous calendar date.
     ///
-    /// Returns `Err(ChronoError)` when `self` is the first representable date.
+    /// Returns `Err(ChronoError)` with the overflow kind when `self` is the
+    /// first representable date, [`NaiveDate::MIN`].
     ///
     /// # Example
     ///
     /// ```
     /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 3).pred(), NaiveDate::from_ymd(2015,  6,  2));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 1).pred(), NaiveDate::from_ymd(2015,  5, 31));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).pred(), NaiveDate::from_ymd(2014, 12, 31));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 3)?.pred()?, NaiveDate::from_ymd(2015,  6,  2)?);
+    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 1)?.pred()?, NaiveDate::from_ymd(2015,  5, 31)?);
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1)?.pred()?, NaiveDate::from_ymd(2014, 12, 31)?);
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 6, 3).pred(),
-    ///            Some(NaiveDate::from_ymd(2015, 6, 2)));
-    /// assert_eq!(NaiveDate::MIN.pred(), None);
+    /// assert!(NaiveDate::MIN.pred().is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn pred(&self) -> Result<NaiveDate, ChronoError> {
@@ -962,19 +1237,7 @@ impl NaiveDate {
     /// assert_eq!(NaiveDate::MAX.checked_add_signed(TimeDelta::days(1)), None);
     /// ```
     pub fn checked_add_signed(self, rhs: TimeDelta) -> Result<NaiveDate, ChronoError> {
-        let year = self.year();
-        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
-        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
-        let cycle = i32::try_from(rhs.num_days())
-            .ok()
-            .and_then(|n| (cycle as i32).checked_add(n))
-            .ok_or(ChronoErrorKind::InvalidDate)?;
-        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
-        year_div_400 += cycle_div_400y;
-
-        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
-        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
-        NaiveDate::from_of(year_div_400 * 400 + year_mod_400 as i32, Of::new(ordinal, flags))
+        self.checked_add_signed_days(rhs.num_days())
     }
 
     /// Subtracts the `days` part of given `TimeDelta` from the current date.
@@ -1002,7 +1265,7 @@ impl NaiveDate {
         let cycle = i32::try_from(rhs.num_days())
             .ok()
             .and_then(|n| (cycle as i32).checked_sub(n))
-            .ok_or(ChronoErrorKind::InvalidDate)?;
+            .ok_or(ChronoErrorKind::Overflow)?;
         let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
         year_div_400 += cycle_div_400y;
 
@@ -1045,6 +1308,32 @@ impl NaiveDate {
         )
     }
 
+    /// Restricts the date to the range from `min` to `max`, inclusive.
+    ///
+    /// `min` must not be after `max`, which is only checked in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let min = NaiveDate::from_ymd(2022, 1, 1)?;
+    /// let max = NaiveDate::from_ymd(2022, 12, 31)?;
+    /// assert_eq!(NaiveDate::from_ymd(2023, 3, 1)?.clamp(min, max), max);
+    /// assert_eq!(NaiveDate::from_ymd(2022, 3, 1)?.clamp(min, max), NaiveDate::from_ymd(2022, 3, 1)?);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn clamp(self, min: NaiveDate, max: NaiveDate) -> NaiveDate {
+        debug_assert!(min <= max, "`NaiveDate::clamp` called with `min` after `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is the same as the ordinary `format` method.
     ///
@@ -1184,6 +1473,43 @@ impl NaiveDate {
         NaiveDateWeeksIterator { value: *self }
     }
 
+    /// Returns an iterator that steps by weeks across all representable dates
+    /// falling on `weekday`, starting at the first such date on or after this one.
+    ///
+    /// If there is no such date before [`NaiveDate::MAX`], the iterator yields
+    /// nothing going forwards, and starts at the last such date going backwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// // 2022 starts on a Saturday
+    /// let new_year = NaiveDate::from_ymd(2022, 1, 1)?;
+    /// let mut mondays = new_year.iter_weekday(Weekday::Mon);
+    /// assert_eq!(mondays.next(), Some(NaiveDate::from_ymd(2022, 1, 3)?));
+    /// assert_eq!(mondays.next(), Some(NaiveDate::from_ymd(2022, 1, 10)?));
+    ///
+    /// let mondays = new_year.iter_weekday(Weekday::Mon).take_while(|d| d.year() == 2022);
+    /// assert_eq!(mondays.count(), 52);
+    ///
+    /// let saturdays = new_year.iter_weekday(Weekday::Sat).rev().take(2);
+    /// let expected = [new_year, NaiveDate::from_ymd(2021, 12, 25)?];
+    /// assert!(saturdays.eq(expected.iter().copied()));
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn iter_weekday(&self, weekday: Weekday) -> NaiveDateWeeksIterator {
+        let days = i64::from(weekday.days_since(self.weekday()));
+        // there is no such date if it would be after `NaiveDate::MAX`, so
+        // start a week earlier, which `next` doesn't yield either
+        let value = match self.checked_add_signed_days(days) {
+            Ok(value) => value,
+            Err(_) => *self - TimeDelta::days(7 - days),
+        };
+        NaiveDateWeeksIterator { value }
+    }
+
     /// Returns the [`NaiveWeek`] that the date belongs to, starting with the [`Weekday`]
     /// specified.
     #[inline]
@@ -1192,6 +1518,17 @@ impl NaiveDate {
     }
 }
 
+/// Generates dates anywhere between [`NaiveDate::MIN`] and [`NaiveDate::MAX`].
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl arbitrary::Arbitrary<'_> for NaiveDate {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<NaiveDate> {
+        let year = u.int_in_range(MIN_YEAR..=MAX_YEAR)?;
+        let ordinal = u.int_in_range(1..=YearFlags::from_year(year).ndays())?;
+        NaiveDate::from_yo(year, ordinal).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Datelike for NaiveDate {
     /// Returns the year number in the [calendar date](#calendar-date).
     ///
@@ -1419,9 +1756,9 @@ impl Datelike for NaiveDate {
     /// ```
     /// use chrono::{NaiveDate, Datelike};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month(10), Ok(NaiveDate::from_ymd(2015, 10, 8)?));
-    /// assert!(NaiveDate::from_ymd(2015, 9, 8).with_month(13).is_err()); // no month 13
-    /// assert!(NaiveDate::from_ymd(2015, 9, 30).with_month(2).is_err()); // no February 30
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8)?.with_month(10)?, NaiveDate::from_ymd(2015, 10, 8)?);
+    /// assert!(NaiveDate::from_ymd(2015, 9, 8)?.with_month(13).is_err()); // no month 13
+    /// assert!(NaiveDate::from_ymd(2015, 9, 30)?.with_month(2).is_err()); // no February 30
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
@@ -1439,29 +1776,30 @@ impl Datelike for NaiveDate {
     /// ```
     /// use chrono::{NaiveDate, Datelike};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(9),
-    ///            Some(NaiveDate::from_ymd(2015, 10, 8)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(12), None); // no month 13
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 30).with_month0(1), None); // no February 30
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8)?.with_month0(9)?, NaiveDate::from_ymd(2015, 10, 8)?);
+    /// assert!(NaiveDate::from_ymd(2015, 9, 8)?.with_month0(12).is_err()); // no month 13
+    /// assert!(NaiveDate::from_ymd(2015, 9, 30)?.with_month0(1).is_err()); // no February 30
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     fn with_month0(&self, month0: u32) -> Result<NaiveDate, ChronoError> {
-        self.with_mdf(self.mdf().with_month(month0 + 1))
+        let month = month0.checked_add(1).ok_or(ChronoError::new(ChronoErrorKind::InvalidDate))?;
+        self.with_mdf(self.mdf().with_month(month))
     }
 
     /// Makes a new `NaiveDate` with the day of month (starting from 1) changed.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Returns `Err(ChronoError)` when the resulting `NaiveDate` would be
+    /// invalid.
     ///
     /// # Example
     ///
     /// ```
     /// use chrono::{NaiveDate, Datelike};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(30),
-    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(31),
-    ///            None); // no September 31
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8)?.with_day(30)?, NaiveDate::from_ymd(2015, 9, 30)?);
+    /// assert!(NaiveDate::from_ymd(2015, 9, 8)?.with_day(31).is_err()); // no September 31
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     fn with_day(&self, day: u32) -> Result<NaiveDate, ChronoError> {
@@ -1470,21 +1808,22 @@ impl Datelike for NaiveDate {
 
     /// Makes a new `NaiveDate` with the day of month (starting from 0) changed.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Returns `Err(ChronoError)` when the resulting `NaiveDate` would be
+    /// invalid.
     ///
     /// # Example
     ///
     /// ```
     /// use chrono::{NaiveDate, Datelike};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(29),
-    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(30),
-    ///            None); // no September 31
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8)?.with_day0(29)?, NaiveDate::from_ymd(2015, 9, 30)?);
+    /// assert!(NaiveDate::from_ymd(2015, 9, 8)?.with_day0(30).is_err()); // no September 31
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     fn with_day0(&self, day0: u32) -> Result<NaiveDate, ChronoError> {
-        self.with_mdf(self.mdf().with_day(day0 + 1))
+        let day = day0.checked_add(1).ok_or(ChronoError::new(ChronoErrorKind::InvalidDate))?;
+        self.with_mdf(self.mdf().with_day(day))
     }
 
     /// Makes a new `NaiveDate` with the day of year (starting from 1) changed.
@@ -1532,7 +1871,9 @@ impl Datelike for NaiveDate {
     /// ```
     #[inline]
     fn with_ordinal0(&self, ordinal0: u32) -> Result<NaiveDate, ChronoError> {
-        self.with_of(self.of().with_ordinal(ordinal0 + 1))
+        let ordinal =
+            ordinal0.checked_add(1).ok_or(ChronoError::new(ChronoErrorKind::InvalidDate))?;
+        self.with_of(self.of().with_ordinal(ordinal))
     }
 }
 
@@ -1902,7 +2243,8 @@ impl str::FromStr for NaiveDate {
 /// use chrono::NaiveDate;
 ///
 /// let default_date = NaiveDate::default();
-/// assert_eq!(default_date, NaiveDate::from_ymd(1970, 1, 1));
+/// assert_eq!(default_date, NaiveDate::from_ymd(1970, 1, 1)?);
+/// # Ok::<_, chrono::ChronoError>(())
 /// ```
 impl Default for NaiveDate {
     fn default() -> Self {
@@ -2057,15 +2399,15 @@ mod serde {
 #[cfg(test)]
 mod tests {
     use super::{
-        Days, Months, NaiveDate, MAX_BITS, MAX_DAYS_FROM_YEAR_0, MAX_YEAR, MIN_DAYS_FROM_YEAR_0,
-        MIN_YEAR,
+        Days, Month, Months, NaiveDate, MAX_BITS, MAX_DAYS_FROM_YEAR_0, MAX_YEAR,
+        MIN_DAYS_FROM_YEAR_0, MIN_YEAR,
     };
     use crate::error::ChronoErrorKind;
     use crate::time_delta::TimeDelta;
-    use crate::{ChronoError, Datelike, Weekday};
+    use crate::{ChronoError, Datelike, Timelike, Weekday};
     use std::{
         convert::{TryFrom, TryInto},
-        i32, u32,
+        i32, i64, u32,
     };
 
     macro_rules! ymd {
@@ -2225,6 +2567,35 @@ mod tests {
         assert!(from_yo(2014, 366).is_err());
     }
 
+    #[test]
+    fn test_date_from_ymd_const() {
+        const EPOCH: NaiveDate = NaiveDate::from_ymd_const(1970, 1, 1);
+        const LEAP_DAY: NaiveDate = NaiveDate::from_ymd_const(-4, 2, 29);
+        assert_eq!(EPOCH, ymd!(1970, 1, 1));
+        assert_eq!(EPOCH, NaiveDate::default());
+        assert_eq!(NaiveDate::default().weekday(), Weekday::Thu);
+        assert_eq!(LEAP_DAY, ymd!(-4, 2, 29));
+        assert_eq!(NaiveDate::from_ymd_const(MIN_YEAR, 1, 1), NaiveDate::MIN);
+        assert_eq!(NaiveDate::from_ymd_const(MAX_YEAR, 12, 31), NaiveDate::MAX);
+
+        for year in -1000..3000 {
+            for month in 1..=12 {
+                for day in 1..=31 {
+                    if let Ok(date) = NaiveDate::from_ymd(year, month, day) {
+                        assert_eq!(NaiveDate::from_ymd_const(year, month, day), date);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_date_from_ymd_const_invalid() {
+        let day = 29;
+        NaiveDate::from_ymd_const(2015, 2, day);
+    }
+
     #[test]
     fn test_date_from_isoywd() {
         let from_isoywd = NaiveDate::from_isoywd;
@@ -2326,6 +2697,32 @@ mod tests {
         assert!(from_ndays_from_ce(NaiveDate::MAX.num_days_from_ce() + 1).is_err());
     }
 
+    #[test]
+    fn test_date_jdn() {
+        assert_eq!(NaiveDate::from_jdn(2_451_545), Ok(ymd!(2000, 1, 1)));
+        assert_eq!(NaiveDate::from_jdn(2_440_588), Ok(ymd!(1970, 1, 1)));
+        assert_eq!(NaiveDate::from_jdn(2_459_580), Ok(ymd!(2021, 12, 31)));
+        // the first day of the Gregorian calendar
+        assert_eq!(NaiveDate::from_jdn(2_299_161), Ok(ymd!(1582, 10, 15)));
+        assert_eq!(NaiveDate::from_jdn(1_721_426), Ok(ymd!(1, 1, 1)));
+        assert_eq!(NaiveDate::from_jdn(0), Ok(ymd!(-4713, 11, 24)));
+        assert_eq!(NaiveDate::from_jdn(-1), Ok(ymd!(-4713, 11, 23)));
+
+        for &jdn in &[-1_000_000, 0, 1_721_426, 2_451_545, 5_000_000] {
+            assert_eq!(NaiveDate::from_jdn(jdn).map(|d| d.num_days_from_jdn()), Ok(jdn));
+        }
+        assert_eq!(ymd!(2000, 1, 2).num_days_from_jdn() - ymd!(2000, 1, 1).num_days_from_jdn(), 1);
+
+        let min = NaiveDate::MIN.num_days_from_jdn();
+        let max = NaiveDate::MAX.num_days_from_jdn();
+        assert_eq!(NaiveDate::from_jdn(min), Ok(NaiveDate::MIN));
+        assert_eq!(NaiveDate::from_jdn(max), Ok(NaiveDate::MAX));
+        assert!(NaiveDate::from_jdn(min - 1).is_err());
+        assert!(NaiveDate::from_jdn(max + 1).is_err());
+        assert!(NaiveDate::from_jdn(i64::MIN).is_err());
+        assert!(NaiveDate::from_jdn(i64::MAX).is_err());
+    }
+
     #[test]
     fn test_from_weekday_of_month() {
         let from_weekday_of_month = NaiveDate::from_weekday_of_month;
@@ -2342,6 +2739,124 @@ mod tests {
         assert_eq!(from_weekday_of_month(2018, 8, Weekday::Thu, 5), Ok(ymd!(2018, 8, 30)));
         assert_eq!(from_weekday_of_month(2018, 8, Weekday::Fri, 5), Ok(ymd!(2018, 8, 31)));
         assert!(from_weekday_of_month(2018, 8, Weekday::Sat, 5).is_err());
+
+        // the first Monday of each month of 2022
+        let mondays = [3, 7, 7, 4, 2, 6, 4, 1, 5, 3, 7, 5];
+        for (month, &day) in (1..=12).zip(mondays.iter()) {
+            assert_eq!(
+                from_weekday_of_month(2022, month, Weekday::Mon, 1),
+                Ok(ymd!(2022, month, day))
+            );
+        }
+
+        // a 5th weekday only exists in February of leap years
+        assert_eq!(from_weekday_of_month(2020, 2, Weekday::Sat, 5), Ok(ymd!(2020, 2, 29)));
+        let invalid = Err(ChronoError::new(ChronoErrorKind::InvalidDate));
+        assert_eq!(from_weekday_of_month(2021, 2, Weekday::Fri, 5), invalid);
+        assert_eq!(from_weekday_of_month(2020, 2, Weekday::Sun, 5), invalid);
+        assert_eq!(from_weekday_of_month(2018, 8, Weekday::Mon, 0), invalid);
+        assert_eq!(from_weekday_of_month(2018, 8, Weekday::Mon, u8::MAX), invalid);
+        assert!(from_weekday_of_month(2018, 13, Weekday::Mon, 1).is_err());
+    }
+
+    #[test]
+    fn test_date_invalid_vs_overflow() {
+        let invalid = ChronoError::new(ChronoErrorKind::InvalidDate);
+        let overflow = ChronoError::new(ChronoErrorKind::Overflow);
+
+        // malformed components
+        assert_eq!(NaiveDate::from_ymd(2015, 13, 1), Err(invalid));
+        assert_eq!(NaiveDate::from_ymd(2015, 2, 29), Err(invalid));
+        assert_eq!(NaiveDate::from_yo(2015, 366), Err(invalid));
+        assert_eq!(NaiveDate::from_isoywd(2015, 54, Weekday::Mon), Err(invalid));
+        // malformed components are reported even when the year is out of range
+        assert_eq!(NaiveDate::from_ymd(MAX_YEAR + 1, 13, 1), Err(invalid));
+
+        // well-formed dates outside of the supported range
+        assert_eq!(NaiveDate::from_ymd(MAX_YEAR + 1, 1, 1), Err(overflow));
+        assert_eq!(NaiveDate::from_ymd(MIN_YEAR - 1, 12, 31), Err(overflow));
+        assert_eq!(NaiveDate::from_yo(MAX_YEAR + 1, 1), Err(overflow));
+        assert_eq!(NaiveDate::from_num_days_from_ce(i32::MAX), Err(overflow));
+        assert_eq!(NaiveDate::MAX.succ(), Err(overflow));
+        assert_eq!(NaiveDate::MIN.pred(), Err(overflow));
+        assert_eq!(NaiveDate::MAX.checked_add_signed(TimeDelta::days(1)), Err(overflow));
+        assert_eq!(NaiveDate::MAX.checked_add_months(Months::new(1)), Err(overflow));
+        assert_eq!(NaiveDate::MIN.checked_sub_months(Months::new(1)), Err(overflow));
+        assert_eq!(NaiveDate::MAX.checked_add_days(Days::new(1)), Err(overflow));
+        assert_eq!(
+            NaiveDate::MAX.and_hms(0, 0, 0).unwrap().checked_add_signed(TimeDelta::days(1)),
+            Err(overflow)
+        );
+        assert_eq!(crate::NaiveDateTime::from_timestamp(i64::MAX, 0), Err(overflow));
+
+        assert_eq!(ChronoError::new(ChronoErrorKind::Overflow).to_string(), "date out of range");
+    }
+
+    #[test]
+    fn test_date_last_day_of_month() {
+        assert_eq!(NaiveDate::num_days_in_month(2020, 2), Ok(29));
+        assert_eq!(NaiveDate::num_days_in_month(2021, 2), Ok(28));
+        assert_eq!(NaiveDate::num_days_in_month(2000, 2), Ok(29));
+        assert_eq!(NaiveDate::num_days_in_month(2100, 2), Ok(28));
+        assert_eq!(NaiveDate::num_days_in_month(2021, 4), Ok(30));
+        assert_eq!(NaiveDate::num_days_in_month(2021, 12), Ok(31));
+
+        assert_eq!(NaiveDate::last_day_of_month(2020, 2), Ok(ymd!(2020, 2, 29)));
+        assert_eq!(NaiveDate::last_day_of_month(2021, 2), Ok(ymd!(2021, 2, 28)));
+        assert_eq!(NaiveDate::last_day_of_month(2021, 4), Ok(ymd!(2021, 4, 30)));
+        assert_eq!(NaiveDate::last_day_of_month(-4, 2), Ok(ymd!(-4, 2, 29)));
+
+        // agrees with the day before the first of the next month
+        for month in 1..=12 {
+            let next = ymd!(2021, month, 1).checked_add_months(Months::new(1)).unwrap();
+            let last = next.pred().unwrap();
+            assert_eq!(NaiveDate::last_day_of_month(2021, month), Ok(last));
+            assert_eq!(NaiveDate::num_days_in_month(2021, month), Ok(last.day() as u8));
+        }
+
+        let invalid = ChronoError::new(ChronoErrorKind::InvalidDate);
+        assert_eq!(NaiveDate::num_days_in_month(2021, 0), Err(invalid));
+        assert_eq!(NaiveDate::num_days_in_month(2021, 13), Err(invalid));
+        assert_eq!(NaiveDate::last_day_of_month(2021, 13), Err(invalid));
+        assert!(NaiveDate::last_day_of_month(MAX_YEAR + 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_date_quarter() {
+        assert_eq!(ymd!(2022, 1, 1).quarter(), 1);
+        assert_eq!(ymd!(2022, 3, 31).quarter(), 1);
+        assert_eq!(ymd!(2022, 4, 1).quarter(), 2);
+        assert_eq!(ymd!(2022, 9, 30).quarter(), 3);
+        assert_eq!(ymd!(2022, 12, 31).quarter(), 4);
+        assert_eq!(ymd!(2022, 4, 1).and_hms(12, 0, 0).unwrap().quarter(), 2);
+
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 1), Ok(ymd!(2022, 1, 1)));
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 2), Ok(ymd!(2022, 4, 1)));
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 3), Ok(ymd!(2022, 7, 1)));
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 4), Ok(ymd!(2022, 10, 1)));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 1), Ok(ymd!(2022, 3, 31)));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 2), Ok(ymd!(2022, 6, 30)));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 3), Ok(ymd!(2022, 9, 30)));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 4), Ok(ymd!(2022, 12, 31)));
+
+        // every day of a quarter is in that quarter
+        for quarter in 1..=4 {
+            let mut date = NaiveDate::first_day_of_quarter(2020, quarter).unwrap();
+            let last = NaiveDate::last_day_of_quarter(2020, quarter).unwrap();
+            assert_eq!(date.pred().unwrap().quarter(), (quarter + 2) % 4 + 1);
+            assert_eq!(last.succ().unwrap().quarter(), quarter % 4 + 1);
+            while date <= last {
+                assert_eq!(date.quarter(), quarter);
+                date = date.succ().unwrap();
+            }
+        }
+
+        let invalid = ChronoError::new(ChronoErrorKind::InvalidDate);
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 0), Err(invalid));
+        assert_eq!(NaiveDate::first_day_of_quarter(2022, 5), Err(invalid));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 0), Err(invalid));
+        assert_eq!(NaiveDate::last_day_of_quarter(2022, 5), Err(invalid));
+        assert!(NaiveDate::first_day_of_quarter(MAX_YEAR + 1, 1).is_err());
     }
 
     #[test]
@@ -2430,9 +2945,64 @@ mod tests {
         assert!(d.with_ordinal(u32::MAX).is_err());
     }
 
+    #[test]
+    fn test_date_with_zero_based_fields() {
+        let d = ymd!(2000, 4, 30);
+        assert_eq!(d.with_month0(0), Ok(ymd!(2000, 1, 30)));
+        assert!(d.with_month0(1).is_err());
+        assert_eq!(d.with_month0(11), Ok(ymd!(2000, 12, 30)));
+        assert!(d.with_month0(12).is_err());
+        assert!(d.with_month0(u32::MAX).is_err());
+
+        let d = ymd!(2000, 2, 8);
+        assert_eq!(d.with_day0(0), Ok(ymd!(2000, 2, 1)));
+        assert_eq!(d.with_day0(28), Ok(ymd!(2000, 2, 29)));
+        assert!(d.with_day0(29).is_err());
+        assert!(d.with_day0(u32::MAX).is_err());
+
+        let d = ymd!(2000, 5, 5);
+        assert_eq!(d.with_ordinal0(0), Ok(ymd!(2000, 1, 1)));
+        assert_eq!(d.with_ordinal0(365), Ok(ymd!(2000, 12, 31)));
+        assert!(d.with_ordinal0(366).is_err());
+        assert!(d.with_ordinal0(u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_date_num_days_in_year() {
+        assert_eq!(ymd!(2000, 1, 1).num_days_in_year(), 366);
+        assert_eq!(ymd!(2001, 1, 1).num_days_in_year(), 365);
+        assert_eq!(ymd!(2004, 12, 31).num_days_in_year(), 366);
+        assert_eq!(ymd!(1900, 6, 1).num_days_in_year(), 365);
+        assert_eq!(ymd!(0, 1, 1).num_days_in_year(), 366);
+        assert_eq!(ymd!(-1, 1, 1).num_days_in_year(), 365);
+        assert_eq!(ymd!(-4, 1, 1).num_days_in_year(), 366);
+        assert_eq!(ymd!(-100, 1, 1).num_days_in_year(), 365);
+
+        // agrees with the last ordinal of the year
+        for year in -1000..3000 {
+            let last = ymd!(year, 12, 31);
+            assert_eq!(last.num_days_in_year(), last.ordinal(), "on {:?}", last);
+        }
+    }
+
     #[test]
     fn test_date_num_days_from_ce() {
         assert_eq!(ymd!(1, 1, 1).num_days_from_ce(), 1);
+        assert_eq!(ymd!(1, 12, 31).num_days_from_ce(), 365);
+        assert_eq!(ymd!(0, 12, 31).num_days_from_ce(), 0);
+
+        // round-trips through `from_num_days_from_ce`, and counts days like subtraction does
+        let epoch = ymd!(1, 1, 1);
+        for &(y, m, d) in
+            &[(1582, 10, 15), (1899, 12, 30), (2000, 2, 29), (2022, 6, 15), (-44, 3, 15)]
+        {
+            let date = ymd!(y, m, d);
+            let days = date.num_days_from_ce();
+            assert_eq!(NaiveDate::from_num_days_from_ce(days), Ok(date));
+            assert_eq!(i64::from(days) - 1, date.signed_duration_since(epoch).num_days());
+            let dt = date.and_hms(23, 59, 59).unwrap();
+            assert_eq!(dt.num_days_from_ce(), days);
+        }
 
         for year in -9999..10001 {
             assert_eq!(
@@ -2442,6 +3012,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_and_hms_subsec() {
+        let d = ymd!(2016, 12, 31);
+        let leap = d.and_hms_milli(23, 59, 59, 1_000).unwrap();
+        assert_eq!(leap.to_string(), "2016-12-31 23:59:60");
+        assert_eq!((leap.second(), leap.nanosecond()), (59, 1_000_000_000));
+        assert_eq!(d.and_hms_micro(23, 59, 59, 1_000_000), Ok(leap));
+        assert_eq!(d.and_hms_nano(23, 59, 59, 1_000_000_000), Ok(leap));
+        assert_eq!(leap.date(), d);
+
+        assert_eq!(d.and_hms_milli(1, 2, 3, 4).unwrap().nanosecond(), 4_000_000);
+        assert_eq!(d.and_hms_micro(1, 2, 3, 4).unwrap().nanosecond(), 4_000);
+        assert_eq!(d.and_hms_nano(1, 2, 3, 4).unwrap().nanosecond(), 4);
+        assert_eq!(d.and_hms(1, 2, 3), d.and_hms_nano(1, 2, 3, 0));
+
+        let invalid = Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+        assert_eq!(d.and_hms(23, 59, 60), invalid);
+        assert_eq!(d.and_hms_milli(23, 59, 59, 2_000), invalid);
+        assert_eq!(d.and_hms_micro(23, 59, 59, 2_000_000), invalid);
+        assert_eq!(d.and_hms_nano(23, 59, 59, 2_000_000_000), invalid);
+        assert_eq!(d.and_hms_milli(24, 0, 0, 0), invalid);
+        assert_eq!(d.and_hms_micro(0, 60, 0, 0), invalid);
+        assert_eq!(d.and_hms_nano(0, 0, 60, 0), invalid);
+    }
+
+    #[test]
+    fn test_date_from_year_month_day() {
+        let mut month = Month::January;
+        for m in 1..=12 {
+            assert_eq!(
+                NaiveDate::from_year_month_day(2016, month, 1),
+                NaiveDate::from_ymd(2016, m, 1)
+            );
+            month = month.succ();
+        }
+        assert_eq!(
+            NaiveDate::from_year_month_day(2016, Month::February, 29),
+            Ok(ymd!(2016, 2, 29))
+        );
+        assert_eq!(
+            NaiveDate::from_year_month_day(2015, Month::February, 29),
+            Err(ChronoError::new(ChronoErrorKind::InvalidDate))
+        );
+        assert!(NaiveDate::from_year_month_day(400_000, Month::January, 1).is_err());
+    }
+
     #[test]
     fn test_date_succ() {
         assert_eq!(ymd!(2014, 5, 6).succ(), Ok(ymd!(2014, 5, 7)));
@@ -2449,6 +3065,10 @@ mod tests {
         assert_eq!(ymd!(2014, 12, 31).succ(), Ok(ymd!(2015, 1, 1)));
         assert_eq!(ymd!(2016, 2, 28).succ(), Ok(ymd!(2016, 2, 29)));
         assert!(ymd!(NaiveDate::MAX.year(), 12, 31).succ().is_err());
+
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(NaiveDate::MAX.succ(), overflow);
+        assert_eq!(NaiveDate::MAX.pred().and_then(|d| d.succ()), Ok(NaiveDate::MAX));
     }
 
     #[test]
@@ -2458,6 +3078,10 @@ mod tests {
         assert_eq!(ymd!(2014, 6, 1).pred(), Ok(ymd!(2014, 5, 31)));
         assert_eq!(ymd!(2014, 5, 7).pred(), Ok(ymd!(2014, 5, 6)));
         assert!(ymd!(NaiveDate::MIN.year(), 1, 1).pred().is_err());
+
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(NaiveDate::MIN.pred(), overflow);
+        assert_eq!(NaiveDate::MIN.succ().and_then(|d| d.pred()), Ok(NaiveDate::MIN));
     }
 
     #[test]
@@ -2490,24 +3114,16 @@ mod tests {
         check(
             (0, 1, 1),
             TimeDelta::days(MAX_DAYS_FROM_YEAR_0 as i64 + 1),
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
-        );
-        check(
-            (0, 1, 1),
-            TimeDelta::max_value(),
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+            Err(ChronoError::new(ChronoErrorKind::Overflow)),
         );
+        check((0, 1, 1), TimeDelta::max_value(), Err(ChronoError::new(ChronoErrorKind::Overflow)));
         check((0, 1, 1), TimeDelta::days(MIN_DAYS_FROM_YEAR_0 as i64), Ok((MIN_YEAR, 1, 1)));
         check(
             (0, 1, 1),
             TimeDelta::days(MIN_DAYS_FROM_YEAR_0 as i64 - 1),
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
-        );
-        check(
-            (0, 1, 1),
-            TimeDelta::min_value(),
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+            Err(ChronoError::new(ChronoErrorKind::Overflow)),
         );
+        check((0, 1, 1), TimeDelta::min_value(), Err(ChronoError::new(ChronoErrorKind::Overflow)));
     }
 
     #[test]
@@ -2530,6 +3146,27 @@ mod tests {
         check((MIN_YEAR, 1, 1), (0, 1, 1), TimeDelta::days(MIN_DAYS_FROM_YEAR_0 as i64));
     }
 
+    #[test]
+    fn test_date_clamp() {
+        let min = ymd!(2022, 1, 1);
+        let max = ymd!(2022, 12, 31);
+        assert_eq!(ymd!(2021, 12, 31).clamp(min, max), min);
+        assert_eq!(NaiveDate::MIN.clamp(min, max), min);
+        assert_eq!(ymd!(2022, 1, 1).clamp(min, max), min);
+        assert_eq!(ymd!(2022, 6, 15).clamp(min, max), ymd!(2022, 6, 15));
+        assert_eq!(ymd!(2022, 12, 31).clamp(min, max), max);
+        assert_eq!(ymd!(2023, 1, 1).clamp(min, max), max);
+        assert_eq!(NaiveDate::MAX.clamp(min, max), max);
+        assert_eq!(ymd!(2023, 1, 1).clamp(min, min), min);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_date_clamp_min_after_max() {
+        ymd!(2022, 6, 15).clamp(ymd!(2022, 12, 31), ymd!(2022, 1, 1));
+    }
+
     #[test]
     fn test_date_add_days() {
         fn check(
@@ -2560,10 +3197,51 @@ mod tests {
         check(
             (0, 1, 1),
             Days::new(u64::try_from(MAX_DAYS_FROM_YEAR_0).unwrap() + 1),
-            Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+            Err(ChronoError::new(ChronoErrorKind::Overflow)),
         );
     }
 
+    #[test]
+    fn test_date_checked_add_signed_days() {
+        fn check((y1, m1, d1): (i32, u32, u32), days: i64, ymd: Option<(i32, u32, u32)>) {
+            let lhs = ymd!(y1, m1, d1);
+            let sum = match ymd {
+                Some((y, m, d)) => Ok(ymd!(y, m, d)),
+                None => Err(ChronoError::new(ChronoErrorKind::Overflow)),
+            };
+            assert_eq!(lhs.checked_add_signed_days(days), sum);
+            if let Ok(sum) = sum {
+                assert_eq!(lhs.checked_add_signed(TimeDelta::days(days)), Ok(sum));
+                assert_eq!(sum.checked_add_signed_days(-days), Ok(lhs));
+            }
+        }
+
+        check((2014, 1, 1), 0, Some((2014, 1, 1)));
+        check((2014, 1, 1), 1, Some((2014, 1, 2)));
+        check((2014, 1, 1), -1, Some((2013, 12, 31)));
+        check((2014, 1, 1), 365 * 400 + 97, Some((2414, 1, 1)));
+        check((2014, 1, 1), -(365 * 400 + 97), Some((1614, 1, 1)));
+        check((2014, 1, 1), 100_000_000, None);
+        check((0, 1, 1), MAX_DAYS_FROM_YEAR_0 as i64, Some((MAX_YEAR, 12, 31)));
+        check((0, 1, 1), MIN_DAYS_FROM_YEAR_0 as i64, Some((MIN_YEAR, 1, 1)));
+        check((MIN_YEAR, 1, 1), -(MIN_DAYS_FROM_YEAR_0 as i64), Some((0, 1, 1)));
+
+        // overflow at the bounds
+        check((0, 1, 1), MAX_DAYS_FROM_YEAR_0 as i64 + 1, None);
+        check((0, 1, 1), MIN_DAYS_FROM_YEAR_0 as i64 - 1, None);
+        check((MAX_YEAR, 12, 31), 1, None);
+        check((MIN_YEAR, 1, 1), -1, None);
+        check((2014, 1, 1), i64::from(i32::MAX), None);
+        check((2014, 1, 1), i64::MAX, None);
+        check((2014, 1, 1), i64::MIN, None);
+
+        // day counts too large for a `TimeDelta` don't panic either
+        let d = ymd!(2014, 1, 1);
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(d.checked_add_days(Days::new(i64::MAX as u64)), overflow);
+        assert_eq!(d.checked_sub_days(Days::new(i64::MAX as u64)), overflow);
+    }
+
     #[test]
     fn test_date_sub_days() {
         fn check((y1, m1, d1): (i32, u32, u32), (y2, m2, d2): (i32, u32, u32), diff: Days) {
@@ -2688,6 +3366,75 @@ mod tests {
         assert!(NaiveDate::parse_from_str("2014", "%Y").is_err()); // insufficient
     }
 
+    #[test]
+    fn test_date_parse_from_str_ordinal_and_weekday() {
+        use crate::format::ParseErrorKind;
+
+        assert_eq!(NaiveDate::parse_from_str("2021 059", "%Y %j"), Ok(ymd!(2021, 2, 28)));
+        assert_eq!(NaiveDate::parse_from_str("2020 366", "%Y %j"), Ok(ymd!(2020, 12, 31)));
+        assert_eq!(
+            NaiveDate::parse_from_str("2021 367", "%Y %j").map_err(|e| e.kind()),
+            Err(ParseErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            NaiveDate::parse_from_str("2021 366", "%Y %j").map_err(|e| e.kind()),
+            Err(ParseErrorKind::OutOfRange)
+        );
+
+        // weekdays are checked against the resolved date
+        assert_eq!(NaiveDate::parse_from_str("2021 059 7", "%Y %j %u"), Ok(ymd!(2021, 2, 28)));
+        assert_eq!(NaiveDate::parse_from_str("2021 059 0", "%Y %j %w"), Ok(ymd!(2021, 2, 28)));
+        assert_eq!(
+            NaiveDate::parse_from_str("2021 059 1", "%Y %j %u").map_err(|e| e.kind()),
+            Err(ParseErrorKind::Impossible)
+        );
+        assert!(NaiveDate::parse_from_str("2021 059 8", "%Y %j %u").is_err());
+        assert!(NaiveDate::parse_from_str("2021 059 7", "%Y %j %w").is_err());
+
+        // weekdays resolve dates together with week numbers
+        assert_eq!(NaiveDate::parse_from_str("2021 08 7", "%Y %W %u"), Ok(ymd!(2021, 2, 28)));
+        assert_eq!(NaiveDate::parse_from_str("2021 09 0", "%Y %U %w"), Ok(ymd!(2021, 2, 28)));
+    }
+
+    #[test]
+    fn test_date_iso_week_format_round_trip() {
+        use crate::format::ParseErrorKind;
+
+        let d = ymd!(2020, 12, 31);
+        assert_eq!(d.format("%G-W%V-%u").to_string(), "2020-W53-4");
+        assert_eq!(NaiveDate::parse_from_str("2020-W53-4", "%G-W%V-%u"), Ok(d));
+
+        // days around the turn of the year, where the ISO year differs from the calendar year
+        for &(y, m, day, iso) in &[
+            (2019, 12, 30, "2020-W01-1"),
+            (2021, 1, 3, "2020-W53-7"),
+            (2021, 1, 4, "2021-W01-1"),
+            (2026, 12, 31, "2026-W53-4"),
+            (2027, 1, 1, "2026-W53-5"),
+        ] {
+            let d = ymd!(y, m, day);
+            assert_eq!(d.format("%G-W%V-%u").to_string(), iso);
+            assert_eq!(NaiveDate::parse_from_str(iso, "%G-W%V-%u"), Ok(d));
+        }
+
+        let mut d = ymd!(2019, 12, 1);
+        while d < ymd!(2021, 2, 1) {
+            let s = d.format("%G-W%V-%u").to_string();
+            assert_eq!(NaiveDate::parse_from_str(&s, "%G-W%V-%u"), Ok(d));
+            d = d.succ().unwrap();
+        }
+
+        // a calendar year given alongside has to agree with the resolved date
+        assert_eq!(
+            NaiveDate::parse_from_str("2020-W53-5 2021", "%G-W%V-%u %Y"),
+            Ok(ymd!(2021, 1, 1))
+        );
+        assert_eq!(
+            NaiveDate::parse_from_str("2020-W53-5 2020", "%G-W%V-%u %Y").map_err(|e| e.kind()),
+            Err(ParseErrorKind::Impossible)
+        );
+    }
+
     #[test]
     fn test_date_format() {
         let d = ymd!(2012, 3, 4);
@@ -2732,6 +3479,51 @@ mod tests {
         assert_eq!(ymd!(-262144, 1, 15).iter_weeks().rev().take(4).count(), 2);
     }
 
+    #[test]
+    fn test_weekday_iterator() {
+        let mondays: Vec<_> = ymd!(2022, 1, 1).iter_weekday(Weekday::Mon).take(5).collect();
+        assert_eq!(mondays[0], ymd!(2022, 1, 3));
+        for pair in mondays.windows(2) {
+            assert_eq!(pair[1] - pair[0], TimeDelta::days(7));
+        }
+        assert!(mondays.iter().all(|d| d.weekday() == Weekday::Mon));
+
+        // backwards from the first date on or after the start
+        let mondays: Vec<_> = ymd!(2022, 1, 31).iter_weekday(Weekday::Mon).rev().take(5).collect();
+        assert_eq!(
+            mondays,
+            [
+                ymd!(2022, 1, 31),
+                ymd!(2022, 1, 24),
+                ymd!(2022, 1, 17),
+                ymd!(2022, 1, 10),
+                ymd!(2022, 1, 3)
+            ]
+        );
+
+        // the start itself is included if it falls on the weekday
+        let mut day = ymd!(2022, 1, 1);
+        for _ in 0..7 {
+            let weekday = day.weekday();
+            assert_eq!(day.iter_weekday(weekday).next(), Some(day));
+            assert_eq!(day.iter_weekday(weekday.succ()).next(), day.succ().ok());
+            assert_eq!(day.iter_weekday(weekday.pred()).next(), Some(day + TimeDelta::days(6)));
+            day = day.succ().unwrap();
+        }
+
+        // limits
+        assert_eq!(ymd!(262143, 12, 12).iter_weekday(Weekday::Mon).take(4).count(), 2);
+        let past_max = NaiveDate::MAX.iter_weekday(NaiveDate::MAX.weekday().succ());
+        assert_eq!(past_max.len(), 0);
+        let mut forward = past_max;
+        assert_eq!(forward.next(), None);
+        let last = NaiveDate::MAX - TimeDelta::days(6);
+        assert_eq!(last.weekday(), NaiveDate::MAX.weekday().succ());
+        let back: Vec<_> = past_max.rev().take(2).collect();
+        assert_eq!(back, [last, last - TimeDelta::weeks(1)]);
+        assert_eq!(ymd!(-262144, 1, 15).iter_weekday(Weekday::Mon).rev().take(4).count(), 2);
+    }
+
     #[test]
     fn test_naiveweek() {
         let date = ymd!(2022, 5, 18);
@@ -2752,4 +3544,25 @@ mod tests {
             assert!(days.contains(&date));
         }
     }
+
+    #[test]
+    fn test_naiveweek_across_boundaries() {
+        // the week of a Wednesday spanning a month boundary
+        let week = ymd!(2022, 6, 1).week(Weekday::Mon);
+        assert_eq!(week.first_day(), ymd!(2022, 5, 30));
+        assert_eq!(week.last_day(), ymd!(2022, 6, 5));
+
+        // the week of a Saturday spanning a year boundary
+        let week = ymd!(2022, 1, 1).week(Weekday::Mon);
+        assert_eq!(week.first_day(), ymd!(2021, 12, 27));
+        assert_eq!(week.last_day(), ymd!(2022, 1, 2));
+
+        let week = ymd!(2021, 12, 31).week(Weekday::Sun);
+        assert_eq!(week.first_day(), ymd!(2021, 12, 26));
+        assert_eq!(week.last_day(), ymd!(2022, 1, 1));
+        for day in week.first_day().iter_days().take(7) {
+            assert!(week.days().contains(&day));
+            assert_eq!(day.week(Weekday::Sun).first_day(), week.first_day());
+        }
+    }
 }
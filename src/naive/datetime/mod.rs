@@ -19,7 +19,10 @@ use crate::format::DelayedFormat;
 use crate::format::{parse, ParseError, ParseResult, Parsed, StrftimeItems};
 use crate::format::{Fixed, Item, Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
-use crate::{ChronoError, DateTime, Datelike, Months, TimeDelta, TimeZone, Timelike, Weekday};
+use crate::{
+    ChronoError, DateTime, Datelike, FixedOffset, Months, TimeDelta, TimeZone, Timelike, Utc,
+    Weekday,
+};
 
 /// Tools to help serializing/deserializing `NaiveDateTime`s
 #[cfg(feature = "serde")]
@@ -144,7 +147,7 @@ impl NaiveDateTime {
         let date = i32::try_from(days)
             .ok()
             .and_then(|days| days.checked_add(719_163))
-            .ok_or(ChronoError::new(ChronoErrorKind::InvalidDateTime))
+            .ok_or(ChronoError::new(ChronoErrorKind::Overflow))
             .and_then(NaiveDate::from_num_days_from_ce)?;
 
         let time = NaiveTime::from_num_seconds_from_midnight(secs as u32, nsecs)?;
@@ -316,6 +319,31 @@ impl NaiveDateTime {
         as_ms + i64::from(self.timestamp_subsec_millis())
     }
 
+    /// Returns the number of non-leap *milliseconds* since midnight on January 1, 1970,
+    /// or `None` if it doesn't fit in an `i64`.
+    ///
+    /// This is the checked counterpart of
+    /// [`timestamp_millis`](#method.timestamp_millis). An `i64` holds about
+    /// 292 million years of milliseconds, so every value between
+    /// [`NaiveDateTime::MIN`] and [`NaiveDateTime::MAX`] currently fits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9)?.and_hms_milli(1, 46, 40, 555)?;
+    /// assert_eq!(dt.timestamp_millis_opt(), Some(1_000_000_000_555));
+    ///
+    /// assert_eq!(NaiveDateTime::MAX.timestamp_millis_opt(), Some(NaiveDateTime::MAX.timestamp_millis()));
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_millis_opt(&self) -> Option<i64> {
+        let as_ms = self.timestamp().checked_mul(1000)?;
+        as_ms.checked_add(i64::from(self.timestamp_subsec_millis()))
+    }
+
     /// Returns the number of non-leap *microseconds* since midnight on January 1, 1970.
     ///
     /// Note that this does *not* account for the timezone!
@@ -374,7 +402,7 @@ impl NaiveDateTime {
     /// assert_eq!(nanos, 1_000_000_000_000_000_555);
     /// assert_eq!(
     ///     dt,
-    ///     NaiveDateTime::from_timestamp(nanos / A_BILLION, (nanos % A_BILLION) as u32)
+    ///     NaiveDateTime::from_timestamp(nanos / A_BILLION, (nanos % A_BILLION) as u32)?
     /// );
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
@@ -384,6 +412,62 @@ impl NaiveDateTime {
         as_ns + i64::from(self.timestamp_subsec_nanos())
     }
 
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970,
+    /// or `None` if it doesn't fit in an `i64`.
+    ///
+    /// This is the non-panicking counterpart of
+    /// [`timestamp_nanos`](#method.timestamp_nanos). Only the dates between
+    /// 1677-09-21T00:12:43.145224192 and 2262-04-11T23:47:16.854775807 can be
+    /// represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9)?.and_hms_nano(1, 46, 40, 555)?;
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// let dt = NaiveDate::from_ymd(3000, 1, 1)?.and_hms(0, 0, 0)?;
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        let mut secs = self.timestamp();
+        let mut nanos = i64::from(self.timestamp_subsec_nanos());
+
+        // borrow a second for negative timestamps, so that the earliest
+        // representable nanosecond doesn't overflow in the multiplication.
+        if secs < 0 && nanos > 0 {
+            secs += 1;
+            nanos -= 1_000_000_000;
+        }
+
+        secs.checked_mul(1_000_000_000)?.checked_add(nanos)
+    }
+
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970,
+    /// as an `i128`.
+    ///
+    /// Unlike [`timestamp_nanos`](#method.timestamp_nanos), this can represent
+    /// every `NaiveDateTime` and never overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(5000, 1, 1)?.and_hms_nano(0, 0, 0, 1)?;
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// assert_eq!(dt.timestamp_nanos_i128(), 95_617_584_000_000_000_001);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn timestamp_nanos_i128(&self) -> i128 {
+        i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos())
+    }
+
     /// Returns the number of milliseconds since the last whole non-leap second.
     ///
     /// The return value ranges from 0 to 999,
@@ -450,6 +534,36 @@ impl NaiveDateTime {
         self.time.nanosecond()
     }
 
+    /// Returns the [Julian date][jd], the number of days since noon on
+    /// January 1, 4713 BCE in the proleptic *Julian* calendar, with the time
+    /// of day as its fractional part.
+    ///
+    /// Julian days start at noon, so midnight is half a day before the
+    /// [Julian day number](./struct.NaiveDate.html#method.num_days_from_jdn)
+    /// of the date. A leap second counts as an extra second at the end of the
+    /// day. An `f64` has a precision of about 40 microseconds for the current era.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2000, 1, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.julian_day(), 2_451_545.0);
+    /// let dt = NaiveDate::from_ymd(2000, 1, 1)?.and_hms(0, 0, 0)?;
+    /// assert_eq!(dt.julian_day(), 2_451_544.5);
+    /// let dt = NaiveDate::from_ymd(2000, 1, 1)?.and_hms(18, 0, 0)?;
+    /// assert_eq!(dt.julian_day(), 2_451_545.25);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    ///
+    /// [jd]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn julian_day(&self) -> f64 {
+        let secs = f64::from(self.time.num_seconds_from_midnight())
+            + f64::from(self.time.nanosecond()) / 1e9;
+        self.date.num_days_from_jdn() as f64 - 0.5 + secs / 86_400.0
+    }
+
     /// Adds given `TimeDelta` to the current date and time.
     ///
     /// As a part of Chrono's [leap second handling](./struct.NaiveTime.html#leap-second-handling),
@@ -457,7 +571,7 @@ impl NaiveDateTime {
     /// except when the `NaiveDateTime` itself represents a leap second
     /// in which case the assumption becomes that **there is exactly a single leap second ever**.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// Returns `Err(ChronoError)` when it will result in overflow.
     ///
     /// # Example
     ///
@@ -466,31 +580,28 @@ impl NaiveDateTime {
     ///
     /// let from_ymd = NaiveDate::from_ymd;
     ///
-    /// let d = from_ymd(2016, 7, 8);
+    /// let d = from_ymd(2016, 7, 8)?;
     /// let hms = |h, m, s| d.and_hms(h, m, s);
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::zero()),
-    ///            Some(hms(3, 5, 7)));
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::seconds(1)),
-    ///            Some(hms(3, 5, 8)));
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::seconds(-1)),
-    ///            Some(hms(3, 5, 6)));
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::seconds(3600 + 60)),
-    ///            Some(hms(4, 6, 7)));
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::seconds(86_400)),
-    ///            Some(from_ymd(2016, 7, 9)?.and_hms(3, 5, 7)?));
+    /// assert_eq!(hms(3, 5, 7)?.checked_add_signed(TimeDelta::zero()), hms(3, 5, 7));
+    /// assert_eq!(hms(3, 5, 7)?.checked_add_signed(TimeDelta::seconds(1)), hms(3, 5, 8));
+    /// assert_eq!(hms(3, 5, 7)?.checked_add_signed(TimeDelta::seconds(-1)), hms(3, 5, 6));
+    /// assert_eq!(hms(3, 5, 7)?.checked_add_signed(TimeDelta::seconds(3600 + 60)), hms(4, 6, 7));
+    /// assert_eq!(hms(3, 5, 7)?.checked_add_signed(TimeDelta::seconds(86_400)),
+    ///            from_ymd(2016, 7, 9)?.and_hms(3, 5, 7));
     ///
     /// let hmsm = |h, m, s, milli| d.and_hms_milli(h, m, s, milli);
-    /// assert_eq!(hmsm(3, 5, 7, 980).checked_add_signed(TimeDelta::milliseconds(450)),
-    ///            Some(hmsm(3, 5, 8, 430)));
+    /// assert_eq!(hmsm(3, 5, 7, 980)?.checked_add_signed(TimeDelta::milliseconds(450)),
+    ///            hmsm(3, 5, 8, 430));
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
-    /// Overflow returns `None`.
+    /// Overflow returns `Err(ChronoError)`.
     ///
     /// ```
     /// # use chrono::{TimeDelta, NaiveDate};
-    /// # let hms = |h, m, s| NaiveDate::from_ymd(2016, 7, 8).and_hms(h, m, s);
-    /// assert_eq!(hms(3, 5, 7).checked_add_signed(TimeDelta::days(1_000_000_000)), None);
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8)?.and_hms(3, 5, 7)?;
+    /// assert!(dt.checked_add_signed(TimeDelta::days(1_000_000_000)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
     /// Leap seconds are handled,
@@ -499,22 +610,16 @@ impl NaiveDateTime {
     /// ```
     /// # use chrono::{TimeDelta, NaiveDate};
     /// # let from_ymd = NaiveDate::from_ymd;
-    /// # let hmsm = |h, m, s, milli| Ok(from_ymd(2016, 7, 8)?.and_hms_milli(h, m, s, milli)?);
-    /// let leap = hmsm(3, 5, 59, 1_300);
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::zero()),
-    ///            Some(hmsm(3, 5, 59, 1_300)));
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(-500)),
-    ///            Some(hmsm(3, 5, 59, 800)));
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(500)),
-    ///            Some(hmsm(3, 5, 59, 1_800)));
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(800)),
-    ///            Some(hmsm(3, 6, 0, 100)));
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::seconds(10)),
-    ///            Some(hmsm(3, 6, 9, 300)));
-    /// assert_eq!(leap.checked_add_signed(TimeDelta::seconds(-10)),
-    ///            Some(hmsm(3, 5, 50, 300)));
+    /// # let hmsm = |h, m, s, milli| from_ymd(2016, 7, 8)?.and_hms_milli(h, m, s, milli);
+    /// let leap = hmsm(3, 5, 59, 1_300)?;
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::zero()), hmsm(3, 5, 59, 1_300));
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(-500)), hmsm(3, 5, 59, 800));
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(500)), hmsm(3, 5, 59, 1_800));
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::milliseconds(800)), hmsm(3, 6, 0, 100));
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::seconds(10)), hmsm(3, 6, 9, 300));
+    /// assert_eq!(leap.checked_add_signed(TimeDelta::seconds(-10)), hmsm(3, 5, 50, 300));
     /// assert_eq!(leap.checked_add_signed(TimeDelta::days(1)),
-    ///            Some(from_ymd(2016, 7, 9)?.and_hms_milli(3, 5, 59, 300)?));
+    ///            from_ymd(2016, 7, 9)?.and_hms_milli(3, 5, 59, 300));
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     pub fn checked_add_signed(self, rhs: TimeDelta) -> Result<NaiveDateTime, ChronoError> {
@@ -522,7 +627,7 @@ impl NaiveDateTime {
 
         // early checking to avoid overflow in OldTimeDelta::seconds
         if rhs <= (-1 << MAX_SECS_BITS) || rhs >= (1 << MAX_SECS_BITS) {
-            return Err(ChronoError::new(ChronoErrorKind::InvalidDateTime));
+            return Err(ChronoError::new(ChronoErrorKind::Overflow));
         }
 
         let date = self.date.checked_add_signed(TimeDelta::seconds(rhs))?;
@@ -565,7 +670,7 @@ impl NaiveDateTime {
     /// except when the `NaiveDateTime` itself represents a leap second
     /// in which case the assumption becomes that **there is exactly a single leap second ever**.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// Returns `Err(ChronoError)` when it will result in overflow.
     ///
     /// # Example
     ///
@@ -574,31 +679,28 @@ impl NaiveDateTime {
     ///
     /// let from_ymd = NaiveDate::from_ymd;
     ///
-    /// let d = from_ymd(2016, 7, 8);
+    /// let d = from_ymd(2016, 7, 8)?;
     /// let hms = |h, m, s| d.and_hms(h, m, s);
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::zero()),
-    ///            Some(hms(3, 5, 7)));
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::seconds(1)),
-    ///            Some(hms(3, 5, 6)));
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::seconds(-1)),
-    ///            Some(hms(3, 5, 8)));
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::seconds(3600 + 60)),
-    ///            Some(hms(2, 4, 7)));
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::seconds(86_400)),
-    ///            Some(from_ymd(2016, 7, 7)?.and_hms(3, 5, 7)?));
+    /// assert_eq!(hms(3, 5, 7)?.checked_sub_signed(TimeDelta::zero()), hms(3, 5, 7));
+    /// assert_eq!(hms(3, 5, 7)?.checked_sub_signed(TimeDelta::seconds(1)), hms(3, 5, 6));
+    /// assert_eq!(hms(3, 5, 7)?.checked_sub_signed(TimeDelta::seconds(-1)), hms(3, 5, 8));
+    /// assert_eq!(hms(3, 5, 7)?.checked_sub_signed(TimeDelta::seconds(3600 + 60)), hms(2, 4, 7));
+    /// assert_eq!(hms(3, 5, 7)?.checked_sub_signed(TimeDelta::seconds(86_400)),
+    ///            from_ymd(2016, 7, 7)?.and_hms(3, 5, 7));
     ///
     /// let hmsm = |h, m, s, milli| d.and_hms_milli(h, m, s, milli);
-    /// assert_eq!(hmsm(3, 5, 7, 450).checked_sub_signed(TimeDelta::milliseconds(670)),
-    ///            Some(hmsm(3, 5, 6, 780)));
+    /// assert_eq!(hmsm(3, 5, 7, 450)?.checked_sub_signed(TimeDelta::milliseconds(670)),
+    ///            hmsm(3, 5, 6, 780));
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
-    /// Overflow returns `None`.
+    /// Overflow returns `Err(ChronoError)`.
     ///
     /// ```
     /// # use chrono::{TimeDelta, NaiveDate};
-    /// # let hms = |h, m, s| NaiveDate::from_ymd(2016, 7, 8).and_hms(h, m, s);
-    /// assert_eq!(hms(3, 5, 7).checked_sub_signed(TimeDelta::days(1_000_000_000)), None);
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8)?.and_hms(3, 5, 7)?;
+    /// assert!(dt.checked_sub_signed(TimeDelta::days(1_000_000_000)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
     /// Leap seconds are handled,
@@ -607,25 +709,22 @@ impl NaiveDateTime {
     /// ```
     /// # use chrono::{TimeDelta, NaiveDate};
     /// # let from_ymd = NaiveDate::from_ymd;
-    /// # let hmsm = |h, m, s, milli| from_ymd(2016, 7, 8).and_hms_milli(h, m, s, milli);
-    /// let leap = hmsm(3, 5, 59, 1_300);
-    /// assert_eq!(leap.checked_sub_signed(TimeDelta::zero()),
-    ///            Some(hmsm(3, 5, 59, 1_300)));
-    /// assert_eq!(leap.checked_sub_signed(TimeDelta::milliseconds(200)),
-    ///            Some(hmsm(3, 5, 59, 1_100)));
-    /// assert_eq!(leap.checked_sub_signed(TimeDelta::milliseconds(500)),
-    ///            Some(hmsm(3, 5, 59, 800)));
-    /// assert_eq!(leap.checked_sub_signed(TimeDelta::seconds(60)),
-    ///            Some(hmsm(3, 5, 0, 300)));
+    /// # let hmsm = |h, m, s, milli| from_ymd(2016, 7, 8)?.and_hms_milli(h, m, s, milli);
+    /// let leap = hmsm(3, 5, 59, 1_300)?;
+    /// assert_eq!(leap.checked_sub_signed(TimeDelta::zero()), hmsm(3, 5, 59, 1_300));
+    /// assert_eq!(leap.checked_sub_signed(TimeDelta::milliseconds(200)), hmsm(3, 5, 59, 1_100));
+    /// assert_eq!(leap.checked_sub_signed(TimeDelta::milliseconds(500)), hmsm(3, 5, 59, 800));
+    /// assert_eq!(leap.checked_sub_signed(TimeDelta::seconds(60)), hmsm(3, 5, 0, 300));
     /// assert_eq!(leap.checked_sub_signed(TimeDelta::days(1)),
-    ///            Some(from_ymd(2016, 7, 7)?.and_hms_milli(3, 6, 0, 300)?));
+    ///            from_ymd(2016, 7, 7)?.and_hms_milli(3, 6, 0, 300));
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     pub fn checked_sub_signed(self, rhs: TimeDelta) -> Result<NaiveDateTime, ChronoError> {
         let (time, rhs) = self.time.overflowing_sub_signed(rhs);
 
         // early checking to avoid overflow in OldTimeDelta::seconds
         if rhs <= (-1 << MAX_SECS_BITS) || rhs >= (1 << MAX_SECS_BITS) {
-            return Err(ChronoError::new(ChronoErrorKind::InvalidDateTime));
+            return Err(ChronoError::new(ChronoErrorKind::Overflow));
         }
 
         let date = self.date.checked_sub_signed(TimeDelta::seconds(rhs))?;
@@ -675,6 +774,62 @@ impl NaiveDateTime {
         Ok(Self { date: self.date.checked_sub_days(days)?, ..self })
     }
 
+    /// Adds the given [`FixedOffset`] to the current date and time, e.g. to
+    /// convert a date and time in UTC into the local date and time.
+    ///
+    /// A [leap second](./struct.NaiveTime.html#leap-second-handling) stays a
+    /// leap second, as the offset is a whole number of seconds.
+    ///
+    /// Returns `Err(ChronoError)` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+    ///
+    /// let utc = NaiveDate::from_ymd(2022, 12, 31)?.and_hms(20, 0, 0)?;
+    /// let local = utc.checked_add_offset(FixedOffset::east(5 * 3600))?;
+    /// assert_eq!(local, NaiveDate::from_ymd(2023, 1, 1)?.and_hms(1, 0, 0)?);
+    ///
+    /// assert!(NaiveDateTime::MAX.checked_add_offset(FixedOffset::east(1)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn checked_add_offset(self, rhs: FixedOffset) -> Result<NaiveDateTime, ChronoError> {
+        self.add_offset_secs(rhs.local_minus_utc())
+    }
+
+    /// Subtracts the given [`FixedOffset`] from the current date and time,
+    /// e.g. to convert a local date and time into UTC.
+    ///
+    /// A [leap second](./struct.NaiveTime.html#leap-second-handling) stays a
+    /// leap second, as the offset is a whole number of seconds.
+    ///
+    /// Returns `Err(ChronoError)` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+    ///
+    /// let local = NaiveDate::from_ymd(2023, 1, 1)?.and_hms(1, 0, 0)?;
+    /// let utc = local.checked_sub_offset(FixedOffset::east(5 * 3600))?;
+    /// assert_eq!(utc, NaiveDate::from_ymd(2022, 12, 31)?.and_hms(20, 0, 0)?);
+    ///
+    /// assert!(NaiveDateTime::MIN.checked_sub_offset(FixedOffset::east(1)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn checked_sub_offset(self, rhs: FixedOffset) -> Result<NaiveDateTime, ChronoError> {
+        self.add_offset_secs(-rhs.local_minus_utc())
+    }
+
+    fn add_offset_secs(self, secs: i32) -> Result<NaiveDateTime, ChronoError> {
+        // the fraction is kept aside, so a leap second isn't normalized away
+        let frac = self.time.nanosecond();
+        let datetime =
+            self.with_nanosecond(0)?.checked_add_signed(TimeDelta::seconds(i64::from(secs)))?;
+        datetime.with_nanosecond(frac)
+    }
+
     /// Subtracts another `NaiveDateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     ///
@@ -718,6 +873,32 @@ impl NaiveDateTime {
         self.date.signed_duration_since(rhs.date) + self.time.signed_duration_since(rhs.time)
     }
 
+    /// Restricts the date and time to the range from `min` to `max`, inclusive.
+    ///
+    /// `min` must not be after `max`, which is only checked in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let min = NaiveDate::from_ymd(2022, 1, 1)?.and_hms(9, 0, 0)?;
+    /// let max = NaiveDate::from_ymd(2022, 1, 1)?.and_hms(17, 0, 0)?;
+    /// let dt = NaiveDate::from_ymd(2022, 1, 1)?.and_hms(8, 30, 0)?;
+    /// assert_eq!(dt.clamp(min, max), min);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn clamp(self, min: NaiveDateTime, max: NaiveDateTime) -> NaiveDateTime {
+        debug_assert!(min <= max, "`NaiveDateTime::clamp` called with `min` after `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
     /// Formats the combined date and time with the specified formatting items.
     /// Otherwise it is the same as the ordinary [`format`](#method.format) method.
     ///
@@ -822,12 +1003,40 @@ impl NaiveDateTime {
         tz.from_local_datetime(self)
     }
 
+    /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Utc>`,
+    /// treating it as a UTC date and time.
+    ///
+    /// Unlike [`and_local_timezone`](#method.and_local_timezone) this can't
+    /// fail, since every UTC date and time is unambiguous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Utc};
+    /// let dt = NaiveDate::from_ymd(2015, 9, 5)?.and_hms(23, 56, 4)?.and_utc();
+    /// assert_eq!(dt.timezone(), Utc);
+    /// assert_eq!(dt.to_string(), "2015-09-05 23:56:04 UTC");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn and_utc(&self) -> DateTime<Utc> {
+        DateTime::from_utc(*self, Utc)
+    }
+
     /// The minimum possible `NaiveDateTime`.
     pub const MIN: Self = Self { date: NaiveDate::MIN, time: NaiveTime::MIN };
     /// The maximum possible `NaiveDateTime`.
     pub const MAX: Self = Self { date: NaiveDate::MAX, time: NaiveTime::MAX };
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl arbitrary::Arbitrary<'_> for NaiveDateTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<NaiveDateTime> {
+        Ok(NaiveDateTime::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
 impl Datelike for NaiveDateTime {
     /// Returns the year number in the [calendar date](./index.html#calendar-date).
     ///
@@ -2,7 +2,7 @@ use super::NaiveDateTime;
 use crate::error::ChronoErrorKind;
 use crate::naive::NaiveDate;
 use crate::time_delta::TimeDelta;
-use crate::{ChronoError, Datelike, FixedOffset, Utc};
+use crate::{ChronoError, Datelike, FixedOffset, TimeZone, Utc};
 use std::i64;
 
 #[test]
@@ -53,12 +53,12 @@ fn test_datetime_add() {
     check!(
         (0, 1, 1, 0, 0, 0),
         max_days_from_year_0 + TimeDelta::seconds(86_400),
-        Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+        Err(ChronoError::new(ChronoErrorKind::Overflow)),
     );
     check!(
         (0, 1, 1, 0, 0, 0),
         TimeDelta::max_value(),
-        Err(ChronoError::new(ChronoErrorKind::InvalidDateTime)),
+        Err(ChronoError::new(ChronoErrorKind::Overflow)),
     );
 
     let min_days_from_year_0 =
@@ -67,12 +67,12 @@ fn test_datetime_add() {
     check!(
         (0, 1, 1, 0, 0, 0),
         min_days_from_year_0 - TimeDelta::seconds(1),
-        Err(ChronoError::new(ChronoErrorKind::InvalidDate)),
+        Err(ChronoError::new(ChronoErrorKind::Overflow)),
     );
     check!(
         (0, 1, 1, 0, 0, 0),
         TimeDelta::min_value(),
-        Err(ChronoError::new(ChronoErrorKind::InvalidDateTime)),
+        Err(ChronoError::new(ChronoErrorKind::Overflow)),
     );
 }
 
@@ -103,6 +103,20 @@ fn test_datetime_sub() {
     );
 }
 
+#[test]
+fn test_datetime_clamp() {
+    let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd(y, m, d).unwrap().and_hms(h, n, s).unwrap();
+    let min = ymdhms(2022, 1, 1, 9, 0, 0);
+    let max = ymdhms(2022, 1, 1, 17, 0, 0);
+    assert_eq!(ymdhms(2021, 12, 31, 12, 0, 0).clamp(min, max), min);
+    assert_eq!(ymdhms(2022, 1, 1, 8, 59, 59).clamp(min, max), min);
+    assert_eq!(ymdhms(2022, 1, 1, 12, 0, 0).clamp(min, max), ymdhms(2022, 1, 1, 12, 0, 0));
+    assert_eq!(max.clamp(min, max), max);
+    assert_eq!(ymdhms(2022, 1, 1, 17, 0, 1).clamp(min, max), max);
+    assert_eq!(NaiveDateTime::MAX.clamp(min, max), max);
+    assert_eq!(NaiveDateTime::MIN.clamp(min, max), min);
+}
+
 #[test]
 fn test_datetime_addassignment() {
     let ymdhms =
@@ -272,6 +286,113 @@ fn test_nanosecond_range() {
     );
 }
 
+#[test]
+fn test_datetime_checked_add_offset() {
+    let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+    let east = FixedOffset::east(86_399);
+    let west = FixedOffset::west(86_399);
+
+    let dt = NaiveDate::from_ymd(2022, 12, 31).unwrap().and_hms(20, 0, 0).unwrap();
+    let local = dt.checked_add_offset(FixedOffset::east(5 * 3600)).unwrap();
+    assert_eq!(local, NaiveDate::from_ymd(2023, 1, 1).unwrap().and_hms(1, 0, 0).unwrap());
+    assert_eq!(local.checked_sub_offset(FixedOffset::east(5 * 3600)), Ok(dt));
+    assert_eq!(dt.checked_add_offset(west), dt.checked_sub_offset(east));
+    assert_eq!(dt.checked_add_offset(FixedOffset::east(3600)), Ok(dt + FixedOffset::east(3600)));
+
+    // near the end of the supported range
+    assert_eq!(NaiveDateTime::MAX.checked_add_offset(east), overflow);
+    assert_eq!(NaiveDateTime::MAX.checked_sub_offset(west), overflow);
+    assert_eq!(NaiveDateTime::MIN.checked_sub_offset(east), overflow);
+    assert_eq!(NaiveDateTime::MIN.checked_add_offset(west), overflow);
+    assert_eq!(NaiveDateTime::MAX.checked_add_offset(FixedOffset::east(0)), Ok(NaiveDateTime::MAX));
+    let last = NaiveDateTime::MAX.checked_sub_offset(east).unwrap();
+    assert_eq!(last.checked_add_offset(east), Ok(NaiveDateTime::MAX));
+
+    // a leap second is kept
+    let leap = NaiveDate::from_ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 1_500).unwrap();
+    let local = leap.checked_add_offset(FixedOffset::east(5 * 3600 + 1800)).unwrap();
+    assert_eq!(local.to_string(), "2017-01-01 05:29:60.500");
+    assert_eq!(local.checked_sub_offset(FixedOffset::east(5 * 3600 + 1800)), Ok(leap));
+
+    // the local time can't be converted into UTC at the end of the range
+    assert_eq!(
+        FixedOffset::east(3600).from_local_datetime(&NaiveDateTime::MIN).map(|dt| dt.naive_utc()),
+        overflow
+    );
+    assert!(FixedOffset::east(3600).from_local_datetime(&NaiveDateTime::MAX).is_ok());
+}
+
+#[test]
+fn test_datetime_leap_second_ordering() {
+    let d = NaiveDate::from_ymd(2016, 12, 31).unwrap();
+    let before = d.and_hms_milli(23, 59, 59, 500).unwrap();
+    let leap = d.and_hms_milli(23, 59, 59, 1_000).unwrap();
+    let leap_end = d.and_hms_nano(23, 59, 59, 1_999_999_999).unwrap();
+    let after = NaiveDate::from_ymd(2017, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(leap.to_string(), "2016-12-31 23:59:60");
+
+    assert!(before < leap);
+    assert!(leap < leap_end);
+    assert!(leap_end < after);
+    assert!(before < after);
+    assert_eq!(leap.time().cmp(&after.time()), core::cmp::Ordering::Greater);
+
+    let mut sorted = vec![after, leap_end, leap, before];
+    sorted.sort();
+    assert_eq!(sorted, [before, leap, leap_end, after]);
+
+    // the leap second shares its timestamp with the preceding second,
+    // but still sorts between it and the next one
+    assert_eq!(leap.timestamp(), before.timestamp());
+    assert_eq!(leap.timestamp() + 1, after.timestamp());
+    assert_eq!(leap - before, TimeDelta::milliseconds(500));
+}
+
+#[test]
+fn test_timestamp_millis_opt() {
+    let dt = NaiveDate::from_ymd(2000, 1, 1).unwrap().and_hms_milli(0, 0, 0, 1).unwrap();
+    assert_eq!(dt.timestamp_millis_opt(), Some(946_684_800_001));
+
+    let dt = NaiveDate::from_ymd(1969, 12, 31).unwrap().and_hms_milli(23, 59, 59, 100).unwrap();
+    assert_eq!(dt.timestamp_millis_opt(), Some(-900));
+
+    // far dates overflow nanoseconds, but not milliseconds
+    let dt = NaiveDate::from_ymd(3000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(dt.timestamp_nanos_opt(), None);
+    assert_eq!(dt.timestamp_millis_opt(), Some(32_503_680_000_000));
+
+    // the whole supported range fits in milliseconds
+    for &dt in &[NaiveDateTime::MIN, NaiveDateTime::MAX] {
+        assert_eq!(dt.timestamp_millis_opt(), Some(dt.timestamp_millis()));
+    }
+    assert!(NaiveDate::from_ymd(300_000, 1, 1).is_err());
+}
+
+#[test]
+fn test_timestamp_nanos_opt() {
+    let dt = NaiveDate::from_ymd(2000, 1, 1).unwrap().and_hms_nano(0, 0, 0, 1).unwrap();
+    assert_eq!(dt.timestamp_nanos_opt(), Some(946_684_800_000_000_001));
+    assert_eq!(dt.timestamp_nanos_opt(), Some(dt.timestamp_nanos()));
+
+    let dt = NaiveDate::from_ymd(1969, 12, 31).unwrap().and_hms_nano(23, 59, 59, 1).unwrap();
+    assert_eq!(dt.timestamp_nanos_opt(), Some(-999_999_999));
+
+    let dt = NaiveDate::from_ymd(3000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(dt.timestamp_nanos_opt(), None);
+    let dt = NaiveDate::from_ymd(1000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+    assert_eq!(dt.timestamp_nanos_opt(), None);
+
+    let maximum: NaiveDateTime = "2262-04-11T23:47:16.854775807".parse().unwrap();
+    assert_eq!(maximum.timestamp_nanos_opt(), Some(i64::MAX));
+    let after = maximum + TimeDelta::nanoseconds(1);
+    assert_eq!(after.timestamp_nanos_opt(), None);
+
+    let minimum: NaiveDateTime = "1677-09-21T00:12:43.145224192".parse().unwrap();
+    assert_eq!(minimum.timestamp_nanos_opt(), Some(i64::MIN));
+    let before = minimum - TimeDelta::nanoseconds(1);
+    assert_eq!(before.timestamp_nanos_opt(), None);
+}
+
 #[test]
 fn test_and_timezone() {
     let ndt = NaiveDate::from_ymd(2022, 6, 15).unwrap().and_hms(18, 59, 36).unwrap();
@@ -284,3 +405,40 @@ fn test_and_timezone() {
     assert_eq!(dt_offset.naive_local(), ndt);
     assert_eq!(dt_offset.timezone(), offset_tz);
 }
+
+#[test]
+fn test_and_utc() {
+    let ndt = NaiveDate::from_ymd(2022, 6, 1).unwrap().and_hms_nano(12, 34, 56, 789).unwrap();
+    let dt = ndt.and_utc();
+    assert_eq!(dt, Utc.from_utc_datetime(&ndt).unwrap());
+    assert_eq!(dt.naive_utc(), ndt);
+    assert_eq!(dt.timestamp(), ndt.timestamp());
+
+    assert_eq!(NaiveDateTime::MIN.and_utc().naive_utc(), NaiveDateTime::MIN);
+    assert_eq!(NaiveDateTime::MAX.and_utc().naive_utc(), NaiveDateTime::MAX);
+}
+
+#[test]
+fn test_julian_day() {
+    let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd(y, m, d).unwrap().and_hms(h, n, s).unwrap();
+    assert_eq!(ymdhms(2000, 1, 1, 12, 0, 0).julian_day(), 2_451_545.0);
+    assert_eq!(ymdhms(2000, 1, 1, 0, 0, 0).julian_day(), 2_451_544.5);
+    assert_eq!(ymdhms(1999, 12, 31, 23, 59, 59).julian_day(), 2_451_545.0 - 0.5 - 1.0 / 86_400.0);
+    assert_eq!(ymdhms(1970, 1, 1, 0, 0, 0).julian_day(), 2_440_587.5);
+    assert_eq!(ymdhms(-4713, 11, 24, 12, 0, 0).julian_day(), 0.0);
+    assert_eq!(ymdhms(-4713, 11, 24, 0, 0, 0).julian_day(), -0.5);
+
+    // agrees with the Unix timestamp, which has the Julian date 2440587.5 as its epoch
+    for &secs in &[0, 1_000_000_000, 1_656_633_600, -1_000_000_000] {
+        let dt = NaiveDateTime::from_timestamp(secs, 0).unwrap();
+        let expected = 2_440_587.5 + secs as f64 / 86_400.0;
+        assert!((dt.julian_day() - expected).abs() < 1e-6, "{}", dt);
+    }
+
+    // sub-second precision and leap seconds
+    let dt = NaiveDate::from_ymd(2000, 1, 1).unwrap().and_hms_milli(12, 0, 0, 500).unwrap();
+    assert!((dt.julian_day() - (2_451_545.0 + 0.5 / 86_400.0)).abs() < 1e-9);
+    let leap = NaiveDate::from_ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 1_500).unwrap();
+    let before = NaiveDate::from_ymd(2016, 12, 31).unwrap().and_hms_milli(23, 59, 59, 999).unwrap();
+    assert!((leap.julian_day() - before.julian_day() - 0.501 / 86_400.0).abs() < 1e-9);
+}
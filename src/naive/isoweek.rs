@@ -57,8 +57,9 @@ impl IsoWeek {
     /// ```
     /// use chrono::{NaiveDate, Datelike, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd(2015, 1, Weekday::Mon);
+    /// let d = NaiveDate::from_isoywd(2015, 1, Weekday::Mon)?;
     /// assert_eq!(d.iso_week().year(), 2015);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     ///
     /// This year number might not match the calendar year number.
@@ -66,9 +67,10 @@ impl IsoWeek {
     ///
     /// ```
     /// # use chrono::{NaiveDate, Datelike, Weekday};
-    /// # let d = NaiveDate::from_isoywd(2015, 1, Weekday::Mon);
+    /// # let d = NaiveDate::from_isoywd(2015, 1, Weekday::Mon)?;
     /// assert_eq!(d.year(), 2014);
-    /// assert_eq!(d, NaiveDate::from_ymd(2014, 12, 29));
+    /// assert_eq!(d, NaiveDate::from_ymd(2014, 12, 29)?);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn year(&self) -> i32 {
@@ -84,8 +86,9 @@ impl IsoWeek {
     /// ```
     /// use chrono::{NaiveDate, Datelike, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd(2015, 15, Weekday::Mon);
+    /// let d = NaiveDate::from_isoywd(2015, 15, Weekday::Mon)?;
     /// assert_eq!(d.iso_week().week(), 15);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn week(&self) -> u32 {
@@ -101,8 +104,9 @@ impl IsoWeek {
     /// ```
     /// use chrono::{NaiveDate, Datelike, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd(2015, 15, Weekday::Mon);
+    /// let d = NaiveDate::from_isoywd(2015, 15, Weekday::Mon)?;
     /// assert_eq!(d.iso_week().week0(), 14);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn week0(&self) -> u32 {
@@ -119,17 +123,19 @@ impl IsoWeek {
 /// ```
 /// use chrono::{NaiveDate, Datelike};
 ///
-/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(2015,  9,  5).iso_week()), "2015-W36");
-/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(   0,  1,  3).iso_week()), "0000-W01");
-/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(9999, 12, 31).iso_week()), "9999-W52");
+/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(2015,  9,  5)?.iso_week()), "2015-W36");
+/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(   0,  1,  3)?.iso_week()), "0000-W01");
+/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(9999, 12, 31)?.iso_week()), "9999-W52");
+/// # Ok::<_, chrono::ChronoError>(())
 /// ```
 ///
 /// ISO 8601 requires an explicit sign for years before 1 BCE or after 9999 CE.
 ///
 /// ```
 /// # use chrono::{NaiveDate, Datelike};
-/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(    0,  1,  2).iso_week()),  "-0001-W52");
-/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(10000, 12, 31).iso_week()), "+10000-W52");
+/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(    0,  1,  2)?.iso_week()),  "-0001-W52");
+/// assert_eq!(format!("{:?}", NaiveDate::from_ymd(10000, 12, 31)?.iso_week()), "+10000-W52");
+/// # Ok::<_, chrono::ChronoError>(())
 /// ```
 impl fmt::Debug for IsoWeek {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -164,4 +170,33 @@ mod tests {
         assert_eq!(maxweek.week0(), 0);
         assert_eq!(format!("{:?}", maxweek), NaiveDate::MAX.format("%G-W%V").to_string());
     }
+
+    #[test]
+    fn test_iso_week_year_boundaries() {
+        let week = NaiveDate::from_ymd(2021, 1, 1).unwrap().iso_week();
+        assert_eq!((week.year(), week.week(), week.week0()), (2020, 53, 52));
+
+        let week = NaiveDate::from_ymd(2021, 1, 4).unwrap().iso_week();
+        assert_eq!((week.year(), week.week(), week.week0()), (2021, 1, 0));
+
+        let week = NaiveDate::from_ymd(2019, 12, 30).unwrap().iso_week();
+        assert_eq!((week.year(), week.week(), week.week0()), (2020, 1, 0));
+
+        let week = NaiveDate::from_ymd(2018, 12, 31).unwrap().iso_week();
+        assert_eq!((week.year(), week.week()), (2019, 1));
+    }
+
+    #[test]
+    fn test_iso_week_datetime() {
+        use crate::{FixedOffset, TimeZone, Utc};
+
+        let dt = Utc.ymd(2021, 1, 1).unwrap().and_hms(12, 0, 0).unwrap();
+        assert_eq!((dt.iso_week().year(), dt.iso_week().week()), (2020, 53));
+
+        // the ISO week follows the local date
+        let dt = Utc.ymd(2021, 1, 3).unwrap().and_hms(23, 0, 0).unwrap();
+        assert_eq!(dt.iso_week().week(), 53);
+        let dt = dt.with_fixed_timezone(&FixedOffset::east(2 * 3600));
+        assert_eq!((dt.iso_week().year(), dt.iso_week().week()), (2021, 1));
+    }
 }
@@ -247,7 +247,7 @@ impl NaiveTime {
     /// ```
     /// use chrono::{NaiveTime, Timelike};
     ///
-    /// let t = NaiveTime::from_hms_milli(23, 56, 4, 12);
+    /// let t = NaiveTime::from_hms_milli(23, 56, 4, 12)?;
     /// assert_eq!(t.hour(), 23);
     /// assert_eq!(t.minute(), 56);
     /// assert_eq!(t.second(), 4);
@@ -262,6 +262,7 @@ impl NaiveTime {
     /// assert!(from_hms_milli(23, 60, 0, 0).is_err());
     /// assert!(from_hms_milli(23, 59, 60, 0).is_err());
     /// assert!(from_hms_milli(23, 59, 59, 2_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn from_hms_milli(
@@ -287,7 +288,7 @@ impl NaiveTime {
     /// ```
     /// use chrono::{NaiveTime, Timelike};
     ///
-    /// let t = NaiveTime::from_hms_micro(23, 56, 4, 12_345);
+    /// let t = NaiveTime::from_hms_micro(23, 56, 4, 12_345)?;
     /// assert_eq!(t.hour(), 23);
     /// assert_eq!(t.minute(), 56);
     /// assert_eq!(t.second(), 4);
@@ -302,6 +303,7 @@ impl NaiveTime {
     /// assert!(from_hms_micro(23, 60, 0, 0).is_err());
     /// assert!(from_hms_micro(23, 59, 60, 0).is_err());
     /// assert!(from_hms_micro(23, 59, 59, 2_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn from_hms_micro(
@@ -327,7 +329,7 @@ impl NaiveTime {
     /// ```
     /// use chrono::{NaiveTime, Timelike};
     ///
-    /// let t = NaiveTime::from_hms_nano(23, 56, 4, 12_345_678);
+    /// let t = NaiveTime::from_hms_nano(23, 56, 4, 12_345_678)?;
     /// assert_eq!(t.hour(), 23);
     /// assert_eq!(t.minute(), 56);
     /// assert_eq!(t.second(), 4);
@@ -342,6 +344,7 @@ impl NaiveTime {
     /// assert!(from_hms_nano(23, 60, 0, 0).is_err());
     /// assert!(from_hms_nano(23, 59, 60, 0).is_err());
     /// assert!(from_hms_nano(23, 59, 59, 2_000_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn from_hms_nano(
@@ -371,7 +374,7 @@ impl NaiveTime {
     /// ```
     /// use chrono::{NaiveTime, Timelike};
     ///
-    /// let t = NaiveTime::from_num_seconds_from_midnight(86164, 12_345_678);
+    /// let t = NaiveTime::from_num_seconds_from_midnight(86164, 12_345_678)?;
     /// assert_eq!(t.hour(), 23);
     /// assert_eq!(t.minute(), 56);
     /// assert_eq!(t.second(), 4);
@@ -384,6 +387,7 @@ impl NaiveTime {
     /// assert!(from_num_seconds_from_midnight(86399, 1_999_999_999).is_ok()); // a leap second after 23:59:59
     /// assert!(from_num_seconds_from_midnight(86_400, 0).is_err());
     /// assert!(from_num_seconds_from_midnight(86399, 2_000_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     #[inline]
     pub fn from_num_seconds_from_midnight(secs: u32, nano: u32) -> Result<NaiveTime, ChronoError> {
@@ -393,6 +397,59 @@ impl NaiveTime {
         Ok(NaiveTime { secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from the number of nanoseconds since midnight.
+    ///
+    /// Values from 86,400 up to 86,401 seconds are the
+    /// [leap second](#leap-second-handling) at the end of the day, i.e. `23:59:60`.
+    ///
+    /// Returns `Err(ChronoError)` on an invalid number of nanoseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, Timelike};
+    ///
+    /// let t = NaiveTime::from_num_nanoseconds_from_midnight(86_164_012_345_678)?;
+    /// assert_eq!(t, NaiveTime::from_hms_nano(23, 56, 4, 12_345_678)?);
+    ///
+    /// let leap = NaiveTime::from_num_nanoseconds_from_midnight(86_400_500_000_000)?;
+    /// assert_eq!(leap.to_string(), "23:59:60.500");
+    /// assert_eq!(leap.num_nanoseconds_from_midnight(), 86_400_500_000_000);
+    ///
+    /// assert!(NaiveTime::from_num_nanoseconds_from_midnight(86_401_000_000_000).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn from_num_nanoseconds_from_midnight(nanos: u64) -> Result<NaiveTime, ChronoError> {
+        if nanos >= 86_401 * 1_000_000_000 {
+            return Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+        }
+        let secs = ((nanos / 1_000_000_000) as u32).min(86_399);
+        let frac = (nanos - u64::from(secs) * 1_000_000_000) as u32;
+        Ok(NaiveTime { secs, frac })
+    }
+
+    /// Returns the number of nanoseconds since midnight, which is the inverse
+    /// of [`NaiveTime::from_num_nanoseconds_from_midnight`].
+    ///
+    /// The leap second at the end of the day counts from 86,400 seconds. Leap
+    /// seconds at other times have the same number as the following second,
+    /// as they can't be told apart in this representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_milli(1, 2, 3, 456)?;
+    /// assert_eq!(t.num_nanoseconds_from_midnight(), 3_723_456_000_000);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn num_nanoseconds_from_midnight(&self) -> u64 {
+        u64::from(self.secs) * 1_000_000_000 + u64::from(self.frac)
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -749,6 +806,21 @@ impl NaiveTime {
     pub(super) const MAX: Self = Self { secs: 23 * 3600 + 59 * 60 + 59, frac: 999_999_999 };
 }
 
+/// Generates any valid time, including [leap seconds](#leap-second-handling)
+/// after the last second of a minute.
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl arbitrary::Arbitrary<'_> for NaiveTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<NaiveTime> {
+        let secs = u.int_in_range(0..=86_399)?;
+        // only `hh:mm:59` can be followed by a leap second
+        let max_nano = if secs % 60 == 59 { 1_999_999_999 } else { 999_999_999 };
+        let nano = u.int_in_range(0..=max_nano)?;
+        NaiveTime::from_num_seconds_from_midnight(secs, nano)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Timelike for NaiveTime {
     /// Returns the hour number from 0 to 23.
     ///
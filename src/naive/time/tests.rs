@@ -1,6 +1,7 @@
 use std::u32;
 
 use super::NaiveTime;
+use crate::error::{ChronoError, ChronoErrorKind};
 use crate::{TimeDelta, Timelike};
 
 #[test]
@@ -45,6 +46,72 @@ fn test_time_from_hms_micro() {
     assert!(NaiveTime::from_hms_micro(3, 5, 7, u32::MAX).is_err());
 }
 
+#[test]
+fn test_time_from_hms_boundaries() {
+    let invalid = Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+
+    // there is no hour 24, not even for the end of a day
+    assert!(NaiveTime::from_hms(23, 59, 59).is_ok());
+    assert_eq!(NaiveTime::from_hms(24, 0, 0), invalid);
+    assert_eq!(NaiveTime::from_hms_milli(24, 0, 0, 0), invalid);
+    assert_eq!(NaiveTime::from_hms_micro(24, 0, 0, 0), invalid);
+    assert_eq!(NaiveTime::from_hms_nano(24, 0, 0, 0), invalid);
+    assert_eq!(NaiveTime::from_num_seconds_from_midnight(86_400, 0), invalid);
+    assert_eq!(NaiveTime::from_hms(23, 60, 0), invalid);
+
+    // a leap second is second 59 with a fraction of at least one second, never second 60
+    assert_eq!(NaiveTime::from_hms(23, 59, 60), invalid);
+    assert_eq!(NaiveTime::from_hms_milli(23, 59, 60, 0), invalid);
+    assert_eq!(NaiveTime::from_hms_nano(23, 59, 60, 0), invalid);
+    let leap = NaiveTime::from_hms_nano(23, 59, 59, 1_000_000_000).unwrap();
+    assert_eq!(leap.second(), 59);
+    assert_eq!(leap.nanosecond(), 1_000_000_000);
+    assert_eq!(leap.to_string(), "23:59:60");
+    assert_eq!(NaiveTime::from_hms_milli(23, 59, 59, 1_000), Ok(leap));
+    assert_eq!(NaiveTime::from_hms_micro(23, 59, 59, 1_000_000), Ok(leap));
+    assert_eq!(NaiveTime::from_num_seconds_from_midnight(86_399, 1_000_000_000), Ok(leap));
+    assert_eq!(NaiveTime::from_hms_nano(23, 59, 59, 2_000_000_000), invalid);
+
+    // overflowing subsecond units are rejected rather than wrapping
+    assert_eq!(NaiveTime::from_hms_milli(0, 0, 0, u32::MAX), invalid);
+    assert_eq!(NaiveTime::from_hms_micro(0, 0, 0, u32::MAX), invalid);
+}
+
+#[test]
+fn test_time_num_nanoseconds_from_midnight() {
+    let invalid = Err(ChronoError::new(ChronoErrorKind::InvalidTime));
+    let from_nanos = NaiveTime::from_num_nanoseconds_from_midnight;
+
+    assert_eq!(from_nanos(0), NaiveTime::from_hms(0, 0, 0));
+    assert_eq!(from_nanos(1), NaiveTime::from_hms_nano(0, 0, 0, 1));
+    assert_eq!(from_nanos(86_399_999_999_999), NaiveTime::from_hms_nano(23, 59, 59, 999_999_999));
+
+    let t = NaiveTime::from_hms_micro(12, 34, 56, 789_012).unwrap();
+    assert_eq!(t.num_nanoseconds_from_midnight(), 45_296_789_012_000);
+    assert_eq!(from_nanos(t.num_nanoseconds_from_midnight()), Ok(t));
+    assert_eq!(
+        t.num_nanoseconds_from_midnight(),
+        u64::from(t.num_seconds_from_midnight()) * 1_000_000_000 + u64::from(t.nanosecond())
+    );
+
+    // the leap second at the end of the day
+    let leap = NaiveTime::from_hms_milli(23, 59, 59, 1_500).unwrap();
+    assert_eq!(leap.to_string(), "23:59:60.500");
+    assert_eq!(leap.num_nanoseconds_from_midnight(), 86_400_500_000_000);
+    assert_eq!(from_nanos(86_400_500_000_000), Ok(leap));
+    assert_eq!(from_nanos(86_400_000_000_000), NaiveTime::from_hms_milli(23, 59, 59, 1_000));
+    assert_eq!(from_nanos(86_400_999_999_999), NaiveTime::from_hms_nano(23, 59, 59, 1_999_999_999));
+    assert_eq!(from_nanos(86_401_000_000_000), invalid);
+    assert_eq!(from_nanos(u64::MAX), invalid);
+
+    // other leap seconds share their number with the next second
+    let leap = NaiveTime::from_hms_milli(11, 59, 59, 1_500).unwrap();
+    assert_eq!(
+        leap.num_nanoseconds_from_midnight(),
+        NaiveTime::from_hms_milli(12, 0, 0, 500).unwrap().num_nanoseconds_from_midnight()
+    );
+}
+
 #[test]
 fn test_time_hms() {
     assert_eq!(NaiveTime::from_hms(3, 5, 7).unwrap().hour(), 3);
@@ -325,6 +392,37 @@ fn test_time_parse_from_str() {
     assert!(NaiveTime::parse_from_str("12:3456", "%H:%M:%S").is_err());
 }
 
+#[test]
+fn test_time_parse_from_str_12_hour() {
+    use crate::format::ParseErrorKind;
+
+    let hms = NaiveTime::from_hms;
+    let parse = |s| NaiveTime::parse_from_str(s, "%I:%M %p").map_err(|e| e.kind());
+
+    assert_eq!(parse("07:30 PM"), Ok(hms(19, 30, 0).unwrap()));
+    assert_eq!(parse("07:30 am"), Ok(hms(7, 30, 0).unwrap()));
+    assert_eq!(parse("12:00 AM"), Ok(hms(0, 0, 0).unwrap()));
+    assert_eq!(parse("12:00 PM"), Ok(hms(12, 0, 0).unwrap()));
+    assert_eq!(parse("13:00 PM"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("00:00 AM"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(
+        NaiveTime::parse_from_str("07:30", "%I:%M").map_err(|e| e.kind()),
+        Err(ParseErrorKind::NotEnough)
+    );
+
+    // a 24-hour clock hour has to agree with the meridiem
+    let parse = |s| NaiveTime::parse_from_str(s, "%H:%M %p").map_err(|e| e.kind());
+    assert_eq!(parse("19:30 PM"), Ok(hms(19, 30, 0).unwrap()));
+    assert_eq!(parse("00:30 AM"), Ok(hms(0, 30, 0).unwrap()));
+    assert_eq!(parse("19:30 AM"), Err(ParseErrorKind::Impossible));
+    assert_eq!(parse("12:00 AM"), Err(ParseErrorKind::Impossible));
+
+    // and so does a 12-hour clock hour
+    let parse = |s| NaiveTime::parse_from_str(s, "%H %I:%M").map_err(|e| e.kind());
+    assert_eq!(parse("19 07:30"), Ok(hms(19, 30, 0).unwrap()));
+    assert_eq!(parse("19 08:30"), Err(ParseErrorKind::Impossible));
+}
+
 #[test]
 fn test_time_format() {
     let t = NaiveTime::from_hms_nano(3, 5, 7, 98765432).unwrap();
@@ -356,3 +454,30 @@ fn test_time_format() {
         "23:59:60"
     );
 }
+
+#[test]
+fn test_time_format_parse_fraction_widths() {
+    let t = NaiveTime::from_hms_nano(3, 5, 7, 123_456_789).unwrap();
+    for &(fmt, formatted, nano) in &[
+        ("%S%.3f", "07.123", 123_000_000),
+        ("%S%.6f", "07.123456", 123_456_000),
+        ("%S%.9f", "07.123456789", 123_456_789),
+        ("%S%3f", "07123", 123_000_000),
+        ("%S%6f", "07123456", 123_456_000),
+        ("%S%9f", "07123456789", 123_456_789),
+    ] {
+        let fmt = format!("%H:%M:{}", fmt);
+        let s = t.format(&fmt).to_string();
+        assert_eq!(s, format!("03:05:{}", formatted));
+        assert_eq!(
+            NaiveTime::parse_from_str(&s, &fmt),
+            Ok(NaiveTime::from_hms_nano(3, 5, 7, nano).unwrap())
+        );
+    }
+
+    // the fixed-width variants don't accept other widths
+    assert!(NaiveTime::parse_from_str("03:05:07.12", "%H:%M:%S%.3f").is_err());
+    assert!(NaiveTime::parse_from_str("03:05:07.1234", "%H:%M:%S%.3f").is_err());
+    assert!(NaiveTime::parse_from_str("03:05:0712", "%H:%M:%S%3f").is_err());
+    assert!(NaiveTime::parse_from_str("03:05:071234", "%H:%M:%S%3f").is_err());
+}
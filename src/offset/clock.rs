@@ -0,0 +1,106 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A source of the current date and time, which can be swapped out in tests.
+
+use super::Utc;
+use crate::{ChronoError, DateTime};
+
+/// A source of the current date and time.
+///
+/// Code which needs the current time can take a `Clock` instead of calling
+/// [`Utc::now`](./struct.Utc.html#method.now) directly, so that tests can
+/// substitute a clock returning a fixed instant.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{ChronoError, Clock, DateTime, TimeDelta, TimeZone, Utc};
+///
+/// fn is_expired<C: Clock>(clock: &C, deadline: DateTime<Utc>) -> Result<bool, ChronoError> {
+///     Ok(clock.now_utc()? > deadline)
+/// }
+///
+/// struct FixedClock(DateTime<Utc>);
+///
+/// impl Clock for FixedClock {
+///     fn now_utc(&self) -> Result<DateTime<Utc>, ChronoError> {
+///         Ok(self.0)
+///     }
+/// }
+///
+/// let deadline = Utc.ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+/// assert!(!is_expired(&FixedClock(deadline), deadline)?);
+/// assert!(is_expired(&FixedClock(deadline + TimeDelta::seconds(1)), deadline)?);
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+pub trait Clock {
+    /// Returns a `DateTime` which corresponds to the current date and time.
+    fn now_utc(&self) -> Result<DateTime<Utc>, ChronoError>;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now_utc(&self) -> Result<DateTime<Utc>, ChronoError> {
+        (**self).now_utc()
+    }
+}
+
+/// A [`Clock`] which reads the system clock, the same as
+/// [`Utc::now`](./struct.Utc.html#method.now).
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+impl Clock for SystemClock {
+    fn now_utc(&self) -> Result<DateTime<Utc>, ChronoError> {
+        Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clock;
+    use crate::{ChronoError, DateTime, FixedOffset, TimeDelta, TimeZone, Utc};
+
+    struct MockClock(DateTime<Utc>);
+
+    impl Clock for MockClock {
+        fn now_utc(&self) -> Result<DateTime<Utc>, ChronoError> {
+            Ok(self.0)
+        }
+    }
+
+    fn local_hour<C: Clock>(clock: C, offset: FixedOffset) -> Result<String, ChronoError> {
+        Ok(clock.now_utc()?.with_fixed_timezone(&offset).format("%H:%M").to_string())
+    }
+
+    #[test]
+    fn test_mock_clock() {
+        let instant = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 30, 0).unwrap();
+        let clock = MockClock(instant);
+        assert_eq!(clock.now_utc(), Ok(instant));
+        assert_eq!(clock.now_utc(), Ok(instant));
+
+        // composes through references and trait objects
+        assert_eq!(local_hour(&clock, FixedOffset::east(9 * 3600)), Ok("21:30".to_string()));
+        let dyn_clock: &dyn Clock = &clock;
+        assert_eq!(local_hour(dyn_clock, FixedOffset::west(3600)), Ok("11:30".to_string()));
+
+        let later = MockClock(instant + TimeDelta::hours(1));
+        assert_eq!(later.now_utc().unwrap() - clock.now_utc().unwrap(), TimeDelta::hours(1));
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_system_clock() {
+        use super::SystemClock;
+
+        let before = Utc::now().unwrap();
+        let now = SystemClock.now_utc().unwrap();
+        let after = Utc::now().unwrap();
+        assert!(before <= now && now <= after);
+    }
+}
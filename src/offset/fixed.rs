@@ -3,20 +3,31 @@
 
 //! The time zone which has a fixed offset from UTC.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::String;
 use core::fmt;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Neg, Sub};
 
 use num_integer::div_mod_floor;
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
 use super::{FixedTimeZone, Offset, TimeZone};
+use crate::error::ChronoErrorKind;
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::time_delta::TimeDelta;
 use crate::{ChronoError, DateTime, Timelike};
 
 /// The time zone with fixed offset, from UTC-23:59:59 to UTC+23:59:59.
 ///
+/// Its `Display` and `Debug` output is the ISO 8601 form `+HH:MM`, like
+/// `+05:30` or `-08:00`, with `:SS` appended if the offset has seconds. UTC
+/// is `+00:00` rather than `Z`. See [`to_iso_string_opts`](#method.to_iso_string_opts)
+/// for the other renderings.
+///
 /// Using the [`TimeZone`](./trait.TimeZone.html) methods
 /// on a `FixedOffset` struct is the preferred way to construct
 /// `DateTime<FixedOffset>` instances. See the [`east`](#method.east) and
@@ -99,6 +110,112 @@ impl FixedOffset {
     pub fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// Adds two offsets, e.g. a base offset and a daylight saving time adjustment.
+    ///
+    /// Returns `Err(ChronoError)` if the sum is outside of the range of a
+    /// `FixedOffset`, which is exclusive of ±24 hours.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let standard = FixedOffset::west(5 * 3600);
+    /// let dst = standard.add_offset(FixedOffset::east(3600))?;
+    /// assert_eq!(dst, FixedOffset::west(4 * 3600));
+    ///
+    /// assert!(FixedOffset::east(23 * 3600).add_offset(FixedOffset::east(3600)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn add_offset(self, other: FixedOffset) -> Result<FixedOffset, ChronoError> {
+        FixedOffset::east_opt(self.local_minus_utc + other.local_minus_utc)
+            .ok_or_else(|| ChronoError::new(ChronoErrorKind::Overflow))
+    }
+
+    /// Returns the offset as an ISO 8601 string, like `+05:30` or `+0530`
+    /// depending on `colon`.
+    ///
+    /// UTC is written as `+00:00`, use
+    /// [`to_iso_string_opts`](#method.to_iso_string_opts) to get `Z` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let offset = FixedOffset::east(5 * 3600 + 30 * 60);
+    /// assert_eq!(offset.to_iso_string(true), "+05:30");
+    /// assert_eq!(offset.to_iso_string(false), "+0530");
+    /// assert_eq!(offset.to_iso_string(true), offset.to_string());
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_iso_string(&self, colon: bool) -> String {
+        self.to_iso_string_opts(colon, false)
+    }
+
+    /// Returns the offset as an ISO 8601 string, with `Z` for UTC if `use_z`
+    /// is true.
+    ///
+    /// Seconds are only written if the offset has them, e.g. `+05:30:15` or
+    /// `+053015`, which is outside of what ISO 8601 allows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let utc = FixedOffset::east(0);
+    /// assert_eq!(utc.to_iso_string_opts(true, false), "+00:00");
+    /// assert_eq!(utc.to_iso_string_opts(false, true), "Z");
+    ///
+    /// let offset = FixedOffset::west(8 * 3600);
+    /// assert_eq!(offset.to_iso_string_opts(false, true), "-0800");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_iso_string_opts(&self, colon: bool, use_z: bool) -> String {
+        let mut result = String::with_capacity(9);
+        self.write_iso(&mut result, colon, use_z).expect("writing to a String can't fail");
+        result
+    }
+
+    fn write_iso<W: fmt::Write>(&self, w: &mut W, colon: bool, use_z: bool) -> fmt::Result {
+        let offset = self.local_minus_utc;
+        if use_z && offset == 0 {
+            return w.write_char('Z');
+        }
+        let (sign, offset) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
+        let (mins, sec) = div_mod_floor(offset, 60);
+        let (hour, min) = div_mod_floor(mins, 60);
+        let sep = if colon { ":" } else { "" };
+        write!(w, "{}{:02}{}{:02}", sign, hour, sep, min)?;
+        if sec != 0 {
+            write!(w, "{}{:02}", sep, sec)?;
+        }
+        Ok(())
+    }
+}
+
+/// Negates the offset, turning an offset east of UTC into the same offset west of UTC.
+///
+/// This can't overflow, as the range of a `FixedOffset` is symmetric.
+///
+/// # Example
+///
+/// ```
+/// use chrono::FixedOffset;
+///
+/// assert_eq!(-FixedOffset::east(3600), FixedOffset::west(3600));
+/// ```
+impl Neg for FixedOffset {
+    type Output = FixedOffset;
+
+    #[inline]
+    fn neg(self) -> FixedOffset {
+        FixedOffset { local_minus_utc: -self.local_minus_utc }
+    }
 }
 
 impl TimeZone for FixedOffset {
@@ -134,7 +251,18 @@ impl FixedTimeZone for FixedOffset {
     }
 }
 
+/// Generates offsets of any number of seconds within a day from UTC.
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl arbitrary::Arbitrary<'_> for FixedOffset {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<FixedOffset> {
+        let secs = u.int_in_range(-86_399..=86_399)?;
+        FixedOffset::east_opt(secs).ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Offset for FixedOffset {
+    #[inline]
     fn fix(&self) -> FixedOffset {
         *self
     }
@@ -142,15 +270,7 @@ impl Offset for FixedOffset {
 
 impl fmt::Debug for FixedOffset {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let offset = self.local_minus_utc;
-        let (sign, offset) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
-        let (mins, sec) = div_mod_floor(offset, 60);
-        let (hour, min) = div_mod_floor(mins, 60);
-        if sec == 0 {
-            write!(f, "{}{:02}:{:02}", sign, hour, min)
-        } else {
-            write!(f, "{}{:02}:{:02}:{:02}", sign, hour, min, sec)
-        }
+        self.write_iso(f, true, false)
     }
 }
 
@@ -198,7 +318,7 @@ impl Add<FixedOffset> for NaiveDateTime {
 
     #[inline]
     fn add(self, rhs: FixedOffset) -> NaiveDateTime {
-        add_with_leapsecond(&self, rhs.local_minus_utc)
+        self.checked_add_offset(rhs).expect("`NaiveDateTime + FixedOffset` overflowed")
     }
 }
 
@@ -207,7 +327,7 @@ impl Sub<FixedOffset> for NaiveDateTime {
 
     #[inline]
     fn sub(self, rhs: FixedOffset) -> NaiveDateTime {
-        add_with_leapsecond(&self, -rhs.local_minus_utc)
+        self.checked_sub_offset(rhs).expect("`NaiveDateTime - FixedOffset` overflowed")
     }
 }
 
@@ -232,7 +352,9 @@ impl<Tz: TimeZone> Sub<FixedOffset> for DateTime<Tz> {
 #[cfg(test)]
 mod tests {
     use super::FixedOffset;
-    use crate::offset::TimeZone;
+    use crate::error::{ChronoError, ChronoErrorKind};
+    use crate::offset::{Offset, TimeZone};
+    use core::ops::Neg;
 
     #[test]
     fn test_date_extreme_offset() {
@@ -261,4 +383,52 @@ mod tests {
             "2012-03-04T05:06:07-23:59:59".to_string()
         );
     }
+
+    #[test]
+    fn test_fixed_offset_arithmetic() {
+        let hour = 3600;
+        assert_eq!(FixedOffset::east(hour).neg(), FixedOffset::west(hour));
+        assert_eq!(-FixedOffset::west(5 * hour), FixedOffset::east(5 * hour));
+        assert_eq!(-FixedOffset::east(86_399), FixedOffset::west(86_399));
+        assert_eq!(-FixedOffset::east(0), FixedOffset::east(0));
+        assert_eq!(FixedOffset::east(hour).fix(), FixedOffset::east(hour));
+
+        let cet = FixedOffset::east(hour);
+        assert_eq!(cet.add_offset(FixedOffset::east(hour)), Ok(FixedOffset::east(2 * hour)));
+        assert_eq!(cet.add_offset(-cet), Ok(FixedOffset::east(0)));
+        assert_eq!(
+            FixedOffset::east(86_398).add_offset(FixedOffset::east(1)),
+            Ok(FixedOffset::east(86_399))
+        );
+
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(FixedOffset::east(86_399).add_offset(FixedOffset::east(1)), overflow);
+        assert_eq!(FixedOffset::west(86_399).add_offset(FixedOffset::west(1)), overflow);
+        assert_eq!(FixedOffset::east(20 * hour).add_offset(FixedOffset::east(20 * hour)), overflow);
+    }
+
+    #[test]
+    fn test_fixed_offset_to_iso_string() {
+        let east = FixedOffset::east(5 * 3600 + 30 * 60);
+        assert_eq!(east.to_string(), "+05:30");
+        assert_eq!(east.to_iso_string(true), "+05:30");
+        assert_eq!(east.to_iso_string(false), "+0530");
+        assert_eq!(east.to_iso_string_opts(true, true), "+05:30");
+
+        let west = FixedOffset::west(8 * 3600);
+        assert_eq!(west.to_string(), "-08:00");
+        assert_eq!(west.to_iso_string(true), "-08:00");
+        assert_eq!(west.to_iso_string(false), "-0800");
+
+        let zero = FixedOffset::east(0);
+        assert_eq!(zero.to_string(), "+00:00");
+        assert_eq!(zero.to_iso_string(true), "+00:00");
+        assert_eq!(zero.to_iso_string(false), "+0000");
+        assert_eq!(zero.to_iso_string_opts(true, true), "Z");
+        assert_eq!(zero.to_iso_string_opts(false, true), "Z");
+
+        let seconds = FixedOffset::west(3600 + 2 * 60 + 3);
+        assert_eq!(seconds.to_string(), "-01:02:03");
+        assert_eq!(seconds.to_iso_string(false), "-010203");
+    }
 }
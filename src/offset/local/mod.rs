@@ -41,6 +41,20 @@ mod tz_info;
 /// on the Local struct is the preferred way to construct `DateTime<Local>`
 /// instances.
 ///
+/// On Unix the `TZ` environment variable is honored like libc does, so it
+/// can either refer to a file in the time zone database (`:Area/Location` or
+/// `Area/Location`) or be a POSIX time zone string (`EST5EDT,M3.2.0,M11.1.0`).
+/// If it is unset or can't be parsed, the system time zone from
+/// `/etc/localtime` is used instead.
+///
+/// On Unix the system time zone is loaded once per thread and kept in a
+/// `thread_local` cache, so no locking is involved when converting to local
+/// time. The cache is reloaded when `/etc/localtime` is modified (checked at
+/// most once per second). The offset for the current UTC day is remembered as
+/// well, so that repeated calls to [`Local::now`] or conversions of times from
+/// the same day don't look it up again, unless the offset changes during that
+/// day.
+///
 /// # Example
 ///
 /// ```
@@ -84,6 +98,33 @@ impl Local {
         let offset = FixedOffset::west((js_sys::Date::new_0().get_timezone_offset() as i32) * 60);
         DateTime::from_utc(now.naive_utc(), offset)
     }
+
+    /// Returns the IANA name of the local time zone, like `America/New_York`.
+    ///
+    /// On Unix a time zone name or file in the `TZ` environment variable is
+    /// used if it is set. Otherwise the system time zone is read from
+    /// `/etc/localtime` or `/etc/timezone`, or from the Windows API on Windows.
+    ///
+    /// Returns `None` if the name can't be determined, including when `TZ` is
+    /// a POSIX time zone string like `EST5EDT,M3.2.0,M11.1.0`, or when it
+    /// can't be loaded and `Local` falls back to the system time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Local;
+    ///
+    /// if let Some(name) = Local::timezone_name() {
+    ///     println!("your time zone is {}", name);
+    /// }
+    /// ```
+    pub fn timezone_name() -> Option<String> {
+        #[cfg(unix)]
+        return inner::timezone_name();
+
+        #[cfg(not(unix))]
+        iana_time_zone::get_timezone().ok()
+    }
 }
 
 impl TimeZone for Local {
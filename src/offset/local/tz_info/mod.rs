@@ -8,7 +8,7 @@ use std::time::SystemTimeError;
 use std::{error, fmt, io};
 
 mod timezone;
-pub(crate) use timezone::TimeZone;
+pub(crate) use timezone::{find_tz_file, TimeZone};
 
 mod parser;
 mod rule;
@@ -586,7 +586,7 @@ impl LocalTimeType {
 }
 
 /// Open the TZif file corresponding to a TZ string
-fn find_tz_file(path: impl AsRef<Path>) -> Result<File, Error> {
+pub(crate) fn find_tz_file(path: impl AsRef<Path>) -> Result<File, Error> {
     // Don't check system timezone directories on non-UNIX platforms
     #[cfg(not(unix))]
     return Ok(File::open(path)?);
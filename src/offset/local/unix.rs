@@ -8,9 +8,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(test)]
+use std::cell::Cell;
+use std::path::Path;
 use std::{cell::RefCell, env, fs, time::SystemTime};
 
-use super::tz_info::TimeZone;
+use super::tz_info::{find_tz_file, TimeZone};
 use super::{DateTime, FixedOffset, Local, NaiveDateTime};
 use crate::{Datelike, LocalResult, Utc};
 
@@ -19,6 +22,30 @@ pub(super) fn now() -> Result<DateTime<Local>, ChronoError> {
     Ok(naive_to_local(&now, false))
 }
 
+/// Returns the time zone name from `TZ` if it is set, or else the name of the
+/// system time zone.
+pub(super) fn timezone_name() -> Option<String> {
+    match env::var("TZ") {
+        // `Local` falls back to another time zone if `TZ` can't be loaded
+        Ok(ref tz) if !tz.is_empty() => TimeZone::local().ok().and_then(|_| tz_name(tz)),
+        _ => iana_time_zone::get_timezone().ok(),
+    }
+}
+
+/// Returns the name of the time zone in a `TZ` value, like `Europe/Berlin`
+/// for `:/usr/share/zoneinfo/Europe/Berlin`.
+fn tz_name(tz: &str) -> Option<String> {
+    let tz = if tz.starts_with(':') { &tz[1..] } else { tz };
+    if Path::new(tz).is_absolute() {
+        let start = tz.rfind("zoneinfo/")? + "zoneinfo/".len();
+        return Some(tz[start..].to_owned()).filter(|name| !name.is_empty());
+    }
+
+    // a POSIX TZ string like `EST5EDT,M3.2.0,M11.1.0` isn't a name, unless
+    // it is also the name of a file in the time zone database.
+    find_tz_file(tz).ok().map(|_| tz.to_owned())
+}
+
 pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> DateTime<Local> {
     TZ_INFO.with(|maybe_cache| {
         maybe_cache.borrow_mut().get_or_insert_with(Cache::default).offset(*d, local)
@@ -31,6 +58,14 @@ thread_local! {
     static TZ_INFO: RefCell<Option<Cache>> = Default::default();
 }
 
+// counts how often the offset of a UTC day was found in or missing from the cache
+// on this thread, to test caching.
+#[cfg(test)]
+thread_local! {
+    static DAY_OFFSET_HITS: Cell<usize> = Cell::new(0);
+    static DAY_OFFSET_MISSES: Cell<usize> = Cell::new(0);
+}
+
 enum Source {
     LocalTime { mtime: SystemTime, last_checked: SystemTime },
     // we don't bother storing the contents of the environment variable in this case.
@@ -94,6 +129,10 @@ impl Source {
 struct Cache {
     zone: TimeZone,
     source: Source,
+    /// The offset for the UTC day starting at the given Unix timestamp, if the
+    /// offset doesn't change during that day. This saves looking up the local
+    /// time type when converting many times from the same day, such as `now`.
+    day_offset: Option<(i64, FixedOffset)>,
 }
 
 #[cfg(target_os = "android")]
@@ -118,24 +157,45 @@ impl Default for Cache {
         Cache {
             zone: TimeZone::local().ok().or_else(fallback_timezone).unwrap_or_else(TimeZone::utc),
             source: Source::default(),
+            day_offset: None,
         }
     }
 }
 
 impl Cache {
+    fn utc_offset(&self, timestamp: i64) -> FixedOffset {
+        FixedOffset::east(
+            self.zone
+                .find_local_time_type(timestamp)
+                .expect("unable to select local time type")
+                .offset(),
+        )
+    }
+
     fn offset(&mut self, d: NaiveDateTime, local: bool) -> LocalResult<DateTime<Local>> {
         if self.source.out_of_date() {
             *self = Cache::default();
         }
 
         if !local {
-            let offset = FixedOffset::east(
-                self.zone
-                    .find_local_time_type(d.timestamp())
-                    .expect("unable to select local time type")
-                    .offset(),
-            );
+            let timestamp = d.timestamp();
+            let day_start = timestamp.div_euclid(86_400) * 86_400;
+            if let Some((start, offset)) = self.day_offset {
+                if start == day_start {
+                    #[cfg(test)]
+                    DAY_OFFSET_HITS.with(|hits| hits.set(hits.get() + 1));
+                    return LocalResult::Single(DateTime::from_utc(d, offset));
+                }
+            }
 
+            #[cfg(test)]
+            DAY_OFFSET_MISSES.with(|misses| misses.set(misses.get() + 1));
+            let offset = self.utc_offset(timestamp);
+            // only remember the offset if there's no transition during the day
+            if self.utc_offset(day_start) == offset && self.utc_offset(day_start + 86_399) == offset
+            {
+                self.day_offset = Some((day_start, offset));
+            }
             return LocalResult::Single(DateTime::from_utc(d, offset));
         }
 
@@ -163,3 +223,56 @@ impl Cache {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        find_tz_file, naive_to_local, tz_name, DAY_OFFSET_HITS, DAY_OFFSET_MISSES, TZ_INFO,
+    };
+    use crate::NaiveDate;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_day_offset_cache() {
+        let noon = NaiveDate::from_ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+        let evening = NaiveDate::from_ymd(2022, 6, 1).unwrap().and_hms(18, 0, 0).unwrap();
+
+        let counts = || (DAY_OFFSET_HITS.with(Cell::get), DAY_OFFSET_MISSES.with(Cell::get));
+
+        let first = naive_to_local(&noon, false);
+        let (hits, misses) = counts();
+        let second = naive_to_local(&evening, false);
+        assert_eq!(first.offset(), second.offset());
+
+        // the offset is remembered unless there's a transition during the day,
+        // so the same UTC day is then served from the cache
+        let day_start = noon.timestamp().div_euclid(86_400) * 86_400;
+        TZ_INFO.with(|cache| {
+            let cache = cache.borrow();
+            let cache = cache.as_ref().unwrap();
+            if cache.utc_offset(day_start) == cache.utc_offset(day_start + 86_399) {
+                assert_eq!(cache.day_offset, Some((day_start, *first.offset())));
+                assert_eq!(counts(), (hits + 1, misses));
+            } else {
+                assert_eq!(counts(), (hits, misses + 1));
+            }
+        });
+    }
+
+    #[test]
+    fn test_tz_name() {
+        let name = |s: &str| Some(s.to_owned());
+        assert_eq!(tz_name("/usr/share/zoneinfo/America/New_York"), name("America/New_York"));
+        assert_eq!(tz_name(":/etc/zoneinfo/Europe/Berlin"), name("Europe/Berlin"));
+        assert_eq!(tz_name("/etc/localtime"), None);
+        assert_eq!(tz_name("/usr/share/zoneinfo/"), None);
+        assert_eq!(tz_name("EST5EDT,M3.2.0,M11.1.0"), None);
+        assert_eq!(tz_name("<+0330>-3:30"), None);
+
+        // only if the machine has a time zone database
+        if find_tz_file("UTC").is_ok() {
+            assert_eq!(tz_name("UTC"), name("UTC"));
+            assert_eq!(tz_name(":UTC"), name("UTC"));
+        }
+    }
+}
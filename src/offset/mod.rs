@@ -18,16 +18,30 @@
 //! and provides implementations for 1 and 3.
 //! An `TimeZone` instance can be reconstructed from the corresponding `Offset` instance.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 use core::fmt;
 
 use crate::error::ChronoErrorKind;
 use crate::format::{parse, ParseResult, Parsed, StrftimeItems};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
-use crate::{ChronoError, Date, DateTime, Weekday};
+use crate::{ChronoError, Date, DateTime, TimeDelta, Weekday};
+
+mod clock;
+pub use self::clock::Clock;
+#[cfg(feature = "clock")]
+pub use self::clock::SystemClock;
 
 mod fixed;
 pub use self::fixed::FixedOffset;
 
+mod posix;
+pub use self::posix::{PosixTz, PosixTzOffset};
+
 #[cfg(feature = "clock")]
 mod local;
 #[cfg(feature = "clock")]
@@ -78,6 +92,56 @@ impl<T> LocalResult<T> {
     }
 }
 
+impl<T> LocalResult<LocalResult<T>> {
+    /// Flattens a nested `LocalResult`, e.g. from [`map`](#method.map) with a
+    /// function which returns a `LocalResult` itself.
+    ///
+    /// The result is only `Single` if both the outer and the inner results
+    /// are. Otherwise it is `Ambiguous`, from the earliest result of the
+    /// outer minimum to the latest result of the outer maximum:
+    ///
+    /// `self`                                        | `self.flatten()`
+    /// --------------------------------------------- | -----------------
+    /// `Single(Single(a))`                           | `Single(a)`
+    /// `Single(Ambiguous(a, b))`                     | `Ambiguous(a, b)`
+    /// `Ambiguous(Single(a), Single(b))`             | `Ambiguous(a, b)`
+    /// `Ambiguous(Single(a), Ambiguous(_, b))`       | `Ambiguous(a, b)`
+    /// `Ambiguous(Ambiguous(a, _), Single(b))`       | `Ambiguous(a, b)`
+    /// `Ambiguous(Ambiguous(a, _), Ambiguous(_, b))` | `Ambiguous(a, b)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::LocalResult;
+    ///
+    /// let nested = LocalResult::Ambiguous(1, 2).map(|n| LocalResult::Ambiguous(n * 10, n * 10 + 1));
+    /// assert_eq!(nested.flatten(), LocalResult::Ambiguous(10, 21));
+    /// assert_eq!(LocalResult::Single(LocalResult::Single(1)).flatten(), LocalResult::Single(1));
+    /// ```
+    pub fn flatten(self) -> LocalResult<T> {
+        match self {
+            LocalResult::Single(inner) => inner,
+            LocalResult::Ambiguous(min, max) => {
+                LocalResult::Ambiguous(min.into_earliest(), max.into_latest())
+            }
+        }
+    }
+}
+
+impl<T> LocalResult<T> {
+    fn into_earliest(self) -> T {
+        match self {
+            LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => t,
+        }
+    }
+
+    fn into_latest(self) -> T {
+        match self {
+            LocalResult::Single(t) | LocalResult::Ambiguous(_, t) => t,
+        }
+    }
+}
+
 impl<Tz: TimeZone> LocalResult<Date<Tz>> {
     /// Makes a new `DateTime` from the current date and given `NaiveTime`.
     /// The offset in the current date is preserved.
@@ -188,6 +252,28 @@ impl<T: fmt::Debug> LocalResult<T> {
 pub trait Offset: Sized + Clone + fmt::Debug {
     /// Returns the fixed offset from UTC to the local time stored.
     fn fix(&self) -> FixedOffset;
+
+    /// Returns the abbreviated name of the offset, like `UTC` or `CEST`, if
+    /// it has one.
+    ///
+    /// This is what the `%Z` format specifier prints. Offsets without an
+    /// abbreviation are printed using their `Display` implementation instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, Offset, TimeZone, Utc};
+    ///
+    /// assert_eq!(Utc.abbreviation(), Some("UTC"));
+    /// assert_eq!(FixedOffset::east(3600).abbreviation(), None);
+    ///
+    /// let dt = FixedOffset::east(3600).ymd(2022, 6, 1)?.and_hms(12, 0, 0)?;
+    /// assert_eq!(dt.format("%H:%M %Z").to_string(), "12:00 +01:00");
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    fn abbreviation(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// The time zone.
@@ -333,6 +419,61 @@ pub trait TimeZone: Sized + Clone {
         self.timestamp(secs, nanos as u32)
     }
 
+    /// Makes a new `DateTime` from the number of non-leap nanoseconds
+    /// since January 1, 1970 0:00:00 UTC, as an `i128`.
+    ///
+    /// Unlike [`timestamp_nanos`](#method.timestamp_nanos), this covers the
+    /// whole range of `DateTime`, and is the reverse of
+    /// [`DateTime::timestamp_nanos_i128`].
+    ///
+    /// Returns `Err(ChronoError)` if the number of nanoseconds is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.timestamp_nanos_i128(95_617_584_000_000_000_001)?;
+    /// assert_eq!(dt.to_string(), "5000-01-01 00:00:00.000000001 UTC");
+    ///
+    /// assert!(Utc.timestamp_nanos_i128(i128::max_value()).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    fn timestamp_nanos_i128(&self, nanos: i128) -> Result<DateTime<Self>, ChronoError> {
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000))
+            .map_err(|_| ChronoError::new(ChronoErrorKind::Overflow))?;
+        self.timestamp(secs, nanos.rem_euclid(1_000_000_000) as u32)
+    }
+
+    /// Makes a new `DateTime` from a local `(year, month, day, hour, minute,
+    /// second, nanosecond)` tuple, as returned by database drivers.
+    ///
+    /// This is the reverse of [`DateTime::to_sql_parts`].
+    ///
+    /// Returns `Err(ChronoError)` if the parts are out of range, or if the
+    /// local time doesn't exist or is ambiguous in this time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.from_sql_parts((2022, 6, 15, 18, 59, 36, 0))?;
+    /// assert_eq!(dt, Utc.ymd(2022, 6, 15)?.and_hms(18, 59, 36)?);
+    ///
+    /// assert!(Utc.from_sql_parts((2022, 2, 30, 0, 0, 0, 0)).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_sql_parts(
+        &self,
+        parts: (i32, u32, u32, u32, u32, u32, u32),
+    ) -> Result<DateTime<Self>, ChronoError> {
+        let (year, month, day, hour, min, sec, nano) = parts;
+        let local = NaiveDate::from_ymd(year, month, day)?.and_hms_nano(hour, min, sec, nano)?;
+        self.from_local_datetime(&local)
+    }
+
     /// Parses a string with the specified format string and returns a
     /// `DateTime` with the current offset.
     ///
@@ -340,7 +481,22 @@ pub trait TimeZone: Sized + Clone {
     /// supported escape sequences.
     ///
     /// If the to-be-parsed string includes an offset, it *must* match the
-    /// offset of the TimeZone, otherwise an error will be returned.
+    /// offset of the TimeZone, otherwise an error of the
+    /// [`OffsetMismatch`](../format/enum.ParseErrorKind.html#variant.OffsetMismatch)
+    /// kind will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::ParseErrorKind;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let err = Utc.datetime_from_str("2020-01-01 00:00:00 +0100", "%Y-%m-%d %H:%M:%S %z").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::OffsetMismatch {
+    ///     parsed: FixedOffset::east(3600),
+    ///     expected: FixedOffset::east(0),
+    /// });
+    /// ```
     ///
     /// See also [`DateTime::parse_from_str`] which gives a [`DateTime`] with
     /// parsed [`FixedOffset`].
@@ -362,6 +518,58 @@ pub trait TimeZone: Sized + Clone {
         local: &NaiveDateTime,
     ) -> Result<Self::Offset, ChronoError>;
 
+    /// Creates all offsets for given local `NaiveDateTime`.
+    ///
+    /// Unlike [`offset_from_local_datetime`](#tymethod.offset_from_local_datetime),
+    /// this returns both offsets when the local time occurs twice, ordered by
+    /// the instant they denote. It still returns `Err` if the local time
+    /// doesn't exist.
+    ///
+    /// The default implementation calls `offset_from_local_datetime`. If that
+    /// reports the local time as ambiguous, the offsets in effect a day before
+    /// and after it are tried instead, so time zones with transitions less than
+    /// a day apart should override it.
+    fn offset_from_local_datetime_all(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<LocalResult<Self::Offset>, ChronoError> {
+        let error = match self.offset_from_local_datetime(local) {
+            Err(e) if e == ChronoError::new(ChronoErrorKind::AmbiguousDate) => e,
+            result => return result.map(LocalResult::Single),
+        };
+
+        // both sides of the transition, if their offsets map back to `local`
+        let mut first: Option<(NaiveDateTime, Self::Offset)> = None;
+        let day = TimeDelta::days(1);
+        for around in [local.checked_sub_signed(day), local.checked_add_signed(day)].iter() {
+            let guess = match *around {
+                Ok(ref around) => self.offset_from_utc_datetime(around)?.fix(),
+                Err(_) => continue,
+            };
+            let utc = match local.checked_sub_offset(guess) {
+                Ok(utc) => utc,
+                Err(_) => continue,
+            };
+            let offset = self.offset_from_utc_datetime(&utc)?;
+            if offset.fix() != guess {
+                continue;
+            }
+
+            match first {
+                Some((first_utc, ref first)) if first_utc < utc => {
+                    return Ok(LocalResult::Ambiguous(first.clone(), offset));
+                }
+                Some((first_utc, ref first)) if first_utc > utc => {
+                    return Ok(LocalResult::Ambiguous(offset, first.clone()));
+                }
+                Some(_) => {}
+                None => first = Some((utc, offset)),
+            }
+        }
+
+        Err(error)
+    }
+
     /// Converts the local `NaiveDate` to the timezone-aware `Date` if possible.
     #[allow(clippy::wrong_self_convention)]
     fn from_local_date(&self, local: &NaiveDate) -> Result<Date<Self>, ChronoError> {
@@ -373,7 +581,7 @@ pub trait TimeZone: Sized + Clone {
     #[allow(clippy::wrong_self_convention)]
     fn from_local_datetime(&self, local: &NaiveDateTime) -> Result<DateTime<Self>, ChronoError> {
         let offset = self.offset_from_local_datetime(local)?;
-        Ok(DateTime::from_utc(*local - offset.fix(), offset))
+        Ok(DateTime::from_utc(local.checked_sub_offset(offset.fix())?, offset))
     }
 
     /// Creates the offset for given UTC `NaiveDate`. This cannot fail.
@@ -395,6 +603,98 @@ pub trait TimeZone: Sized + Clone {
     fn from_utc_datetime(&self, utc: &NaiveDateTime) -> Result<DateTime<Self>, ChronoError> {
         Ok(DateTime::from_utc(*utc, self.offset_from_utc_datetime(utc)?))
     }
+
+    /// Returns the first instant after `after` at which the offset from UTC
+    /// changes, e.g. because daylight saving time starts or ends.
+    ///
+    /// Returns `None` if the offset never changes again, or if the time zone
+    /// doesn't know its transitions. The default implementation always
+    /// returns `None`, which is correct for fixed time zones like [`Utc`] and
+    /// [`FixedOffset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{PosixTz, TimeZone, Utc};
+    ///
+    /// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+    /// let now = Utc.ymd(2021, 1, 1)?.and_hms(0, 0, 0)?;
+    ///
+    /// // 02:00 EST on March 14 is 07:00 UTC
+    /// let spring_forward = Utc.ymd(2021, 3, 14)?.and_hms(7, 0, 0)?;
+    /// assert_eq!(tz.next_transition(&now), Some(spring_forward));
+    /// assert_eq!(Utc.next_transition(&now), None);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    fn next_transition(&self, _after: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        None
+    }
+
+    /// Lists the distinct offsets in effect from `start` (inclusive) up to
+    /// `end` (exclusive).
+    ///
+    /// The first entry is `start` with the offset in effect at that instant,
+    /// followed by each transition in the range which changes the offset,
+    /// along with the new offset. The list is empty if `start` isn't before
+    /// `end`.
+    ///
+    /// The default implementation follows [`TimeZone::next_transition`], so
+    /// time zones which don't know their transitions only get the first
+    /// entry.
+    ///
+    /// Returns `Err(ChronoError)` if the time zone fails to resolve an
+    /// offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, PosixTz, TimeZone, Utc};
+    ///
+    /// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+    /// let start = Utc.ymd(2021, 1, 1)?.and_hms(0, 0, 0)?;
+    /// let end = Utc.ymd(2022, 1, 1)?.and_hms(0, 0, 0)?;
+    ///
+    /// let offsets = tz.offsets_in_range(&start, &end)?;
+    /// assert_eq!(offsets, [
+    ///     (start, FixedOffset::west(5 * 3600)),
+    ///     (Utc.ymd(2021, 3, 14)?.and_hms(7, 0, 0)?, FixedOffset::west(4 * 3600)),
+    ///     (Utc.ymd(2021, 11, 7)?.and_hms(6, 0, 0)?, FixedOffset::west(5 * 3600)),
+    /// ]);
+    /// assert_eq!(Utc.offsets_in_range(&start, &end)?, [(start, FixedOffset::east(0))]);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    fn offsets_in_range(
+        &self,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, FixedOffset)>, ChronoError> {
+        let mut offsets = Vec::new();
+        if start >= end {
+            return Ok(offsets);
+        }
+
+        let mut last = self.offset_from_utc_datetime(&start.naive_utc())?.fix();
+        offsets.push((*start, last));
+
+        let mut current = *start;
+        while let Some(transition) = self.next_transition(&current) {
+            // also guards against a time zone which doesn't move forward
+            if transition >= *end || transition <= current {
+                break;
+            }
+
+            let offset = self.offset_from_utc_datetime(&transition.naive_utc())?.fix();
+            if offset != last {
+                offsets.push((transition, offset));
+                last = offset;
+            }
+            current = transition;
+        }
+
+        Ok(offsets)
+    }
 }
 
 /// A time zone that is fixed. It is distinguished from [TimeZone] by allowing
@@ -425,6 +725,7 @@ pub trait FixedTimeZone: TimeZone {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::TimeDelta;
 
     #[test]
     fn test_negative_millis() {
@@ -475,4 +776,145 @@ mod tests {
         Utc.timestamp_nanos(i64::default()).unwrap();
         Utc.timestamp_nanos(i64::min_value()).unwrap();
     }
+
+    #[test]
+    fn test_timestamp_nanos_i128() {
+        let dt = Utc.ymd(5000, 1, 1).unwrap().and_hms_nano(12, 0, 0, 123_456_789).unwrap();
+        assert_eq!(dt.timestamp_nanos_opt(), None);
+        assert_eq!(dt.timestamp_nanos_i128(), 95_617_627_200_123_456_789);
+        assert_eq!(Utc.timestamp_nanos_i128(95_617_627_200_123_456_789), Ok(dt));
+
+        let dt = Utc.ymd(-5000, 1, 1).unwrap().and_hms_nano(0, 0, 0, 1).unwrap();
+        assert_eq!(dt.timestamp_nanos_i128(), -219_951_935_999_999_999_999);
+        assert_eq!(Utc.timestamp_nanos_i128(dt.timestamp_nanos_i128()), Ok(dt));
+
+        assert_eq!(
+            Utc.timestamp_nanos_i128(-1).unwrap().to_string(),
+            "1969-12-31 23:59:59.999999999 UTC"
+        );
+        for &nanos in &[0, i128::from(i64::MIN), i128::from(i64::MAX)] {
+            assert_eq!(Utc.timestamp_nanos_i128(nanos), Utc.timestamp_nanos(nanos as i64));
+        }
+
+        let max = DateTime::<Utc>::MAX_UTC;
+        assert_eq!(Utc.timestamp_nanos_i128(max.timestamp_nanos_i128()), Ok(max));
+        let min = DateTime::<Utc>::MIN_UTC;
+        assert_eq!(Utc.timestamp_nanos_i128(min.timestamp_nanos_i128()), Ok(min));
+
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(Utc.timestamp_nanos_i128(i128::max_value()), overflow);
+        assert_eq!(Utc.timestamp_nanos_i128(i128::min_value()), overflow);
+        assert!(Utc.timestamp_nanos_i128(max.timestamp_nanos_i128() + 1).is_err());
+    }
+
+    #[test]
+    fn test_local_result_flatten() {
+        use super::LocalResult::{Ambiguous, Single};
+
+        assert_eq!(Single(Single(1)).flatten(), Single(1));
+        assert_eq!(Single(Ambiguous(1, 2)).flatten(), Ambiguous(1, 2));
+        assert_eq!(Ambiguous(Single(1), Single(2)).flatten(), Ambiguous(1, 2));
+        assert_eq!(Ambiguous(Single(1), Ambiguous(2, 3)).flatten(), Ambiguous(1, 3));
+        assert_eq!(Ambiguous(Ambiguous(1, 2), Single(3)).flatten(), Ambiguous(1, 3));
+        assert_eq!(Ambiguous(Ambiguous(1, 2), Ambiguous(3, 4)).flatten(), Ambiguous(1, 4));
+
+        // the result stays ambiguous, even if both sides are equal
+        assert_eq!(Ambiguous(Single(1), Single(1)).flatten(), Ambiguous(1, 1));
+        assert_eq!(Single(1).map(|n| Single(n + 1)).flatten(), Single(2));
+        assert_eq!(Single(Ambiguous(1, 2)).flatten().single(), None);
+    }
+
+    /// A time zone which moves from `+01:00` to `+02:00` at 2022-03-27 01:00
+    /// UTC, and never changes again.
+    #[derive(Clone)]
+    struct OneTransition;
+
+    impl OneTransition {
+        fn transition() -> DateTime<Utc> {
+            Utc.ymd(2022, 3, 27).unwrap().and_hms(1, 0, 0).unwrap()
+        }
+    }
+
+    impl TimeZone for OneTransition {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            OneTransition
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> Result<FixedOffset, ChronoError> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0)?)
+        }
+
+        fn offset_from_local_datetime(
+            &self,
+            local: &NaiveDateTime,
+        ) -> Result<FixedOffset, ChronoError> {
+            self.offset_from_utc_datetime(&local.checked_sub_offset(FixedOffset::east(3600))?)
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> Result<FixedOffset, ChronoError> {
+            self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0)?)
+        }
+
+        fn offset_from_utc_datetime(
+            &self,
+            utc: &NaiveDateTime,
+        ) -> Result<FixedOffset, ChronoError> {
+            if *utc < OneTransition::transition().naive_utc() {
+                Ok(FixedOffset::east(3600))
+            } else {
+                Ok(FixedOffset::east(2 * 3600))
+            }
+        }
+
+        fn next_transition(&self, after: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+            let transition = OneTransition::transition();
+            if *after < transition {
+                Some(transition)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn test_offsets_in_range() {
+        let ymdhms = |y, m, d, h, n, s| Utc.ymd(y, m, d).unwrap().and_hms(h, n, s).unwrap();
+        let winter = FixedOffset::east(3600);
+        let summer = FixedOffset::east(2 * 3600);
+        let transition = OneTransition::transition();
+
+        let start = ymdhms(2022, 1, 1, 0, 0, 0);
+        let end = ymdhms(2023, 1, 1, 0, 0, 0);
+        assert_eq!(
+            OneTransition.offsets_in_range(&start, &end),
+            Ok(vec![(start, winter), (transition, summer)])
+        );
+
+        // the range excludes its end, but not its start
+        assert_eq!(OneTransition.offsets_in_range(&start, &transition), Ok(vec![(start, winter)]));
+        assert_eq!(
+            OneTransition.offsets_in_range(&transition, &end),
+            Ok(vec![(transition, summer)])
+        );
+        let before = transition - TimeDelta::nanoseconds(1);
+        assert_eq!(
+            OneTransition.offsets_in_range(&before, &end),
+            Ok(vec![(before, winter), (transition, summer)])
+        );
+
+        // no transitions in the range
+        let start = ymdhms(2022, 6, 1, 0, 0, 0);
+        assert_eq!(OneTransition.offsets_in_range(&start, &end), Ok(vec![(start, summer)]));
+
+        // empty ranges
+        assert_eq!(OneTransition.offsets_in_range(&end, &end), Ok(vec![]));
+        assert_eq!(OneTransition.offsets_in_range(&end, &start), Ok(vec![]));
+
+        // fixed time zones only have a single entry
+        assert_eq!(Utc.offsets_in_range(&start, &end), Ok(vec![(start, FixedOffset::east(0))]));
+        let offset = FixedOffset::west(5 * 3600);
+        assert_eq!(offset.offsets_in_range(&start, &end), Ok(vec![(start, offset)]));
+    }
 }
@@ -0,0 +1,776 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A time zone described by a POSIX `TZ` string, such as `EST5EDT,M3.2.0,M11.1.0`.
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use super::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+use crate::error::ChronoErrorKind;
+use crate::naive::{NaiveDate, NaiveDateTime};
+use crate::{ChronoError, DateTime, Datelike, Weekday};
+
+/// The maximum length of a time zone abbreviation.
+const MAX_NAME_LEN: usize = 16;
+
+/// The transition time used when a rule doesn't specify one, 02:00:00.
+const DEFAULT_RULE_TIME: i32 = 2 * 3600;
+
+/// The number of days from 0001-01-01 to 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// A time zone described by a [POSIX `TZ` string][posix], like
+/// `EST5EDT,M3.2.0,M11.1.0`.
+///
+/// This computes the daylight saving time transitions from the rules in the
+/// string itself, so it doesn't need a time zone database. This makes it
+/// useful on embedded systems, but it can't know about historical changes to
+/// the rules: the same rules apply to every year.
+///
+/// The supported syntax is `std offset [dst [offset] [,start[/time],end[/time]]]`:
+///
+/// - `std` and `dst` are the abbreviations of standard and daylight saving
+///   time, made up of at least three letters (`EST`), or any alphanumeric
+///   characters, `+` and `-` between `<` and `>` (`<+0530>`).
+/// - `offset` is `[+-]hh[:mm[:ss]]`, the time to *add* to the local time to
+///   get UTC. This means it is positive west of Greenwich, the opposite of
+///   [`FixedOffset::east`]. The daylight saving time offset defaults to one
+///   hour ahead of standard time.
+/// - `start` and `end` are the days daylight saving time starts and ends:
+///   `Jn` is the day `n` (1--365) of the year not counting February 29, `n`
+///   is the zero-based day `n` (0--365) of the year counting February 29, and
+///   `Mm.w.d` is the weekday `d` (0--6, Sunday is 0) in the week `w` (1--5,
+///   where 5 is the last) of month `m`.
+/// - `time` is the local time of the transition, `[+-]hh[:mm[:ss]]` where
+///   the hours may be from -167 to 167 (an extension from [RFC 8536]). It
+///   defaults to `02:00:00`.
+///
+/// A daylight saving time abbreviation without any rules is rejected, since
+/// the default rules are implementation-defined.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Offset, PosixTz, TimeZone};
+///
+/// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+///
+/// let winter = tz.ymd(2021, 1, 15)?.and_hms(12, 0, 0)?;
+/// assert_eq!(winter.to_string(), "2021-01-15 12:00:00 EST");
+/// assert_eq!(winter.offset().fix().local_minus_utc(), -5 * 3600);
+///
+/// let summer = tz.ymd(2021, 7, 15)?.and_hms(12, 0, 0)?;
+/// assert_eq!(summer.format("%H:%M %z").to_string(), "12:00 -0400");
+/// # Ok::<_, chrono::ChronoError>(())
+/// ```
+///
+/// [posix]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+/// [RFC 8536]: https://datatracker.ietf.org/doc/html/rfc8536#section-3.3.1
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    std: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+/// The offset from UTC in a [`PosixTz`], either standard or daylight saving
+/// time.
+///
+/// It is displayed as the time zone abbreviation, like `EST`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTzOffset {
+    tz: PosixTz,
+    is_dst: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct LocalTimeType {
+    name: Name,
+    offset: FixedOffset,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct DstRule {
+    dst: LocalTimeType,
+    start: RuleDay,
+    /// The local time of the start, in seconds since midnight of `start`.
+    start_time: i32,
+    end: RuleDay,
+    /// The local time of the end, in seconds since midnight of `end`.
+    end_time: i32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDay {
+    /// `Jn`: 1-based day of the year, not counting February 29.
+    Julian1(u16),
+    /// `n`: 0-based day of the year, counting February 29.
+    Julian0(u16),
+    /// `Mm.w.d`: the weekday in the given week of the month, where week 5 is the last.
+    MonthWeekday { month: u8, week: u8, weekday: Weekday },
+}
+
+/// A time zone abbreviation, stored inline so `PosixTz` doesn't need to allocate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Name {
+    bytes: [u8; MAX_NAME_LEN],
+    len: u8,
+}
+
+impl PosixTz {
+    /// Returns the offsets for the given local date and time.
+    ///
+    /// Unlike [`TimeZone::offset_from_local_datetime`], this returns both
+    /// offsets when the local time occurs twice because daylight saving time
+    /// ends, ordered by the instant they denote. Returns `Err` if the local
+    /// time doesn't exist because daylight saving time starts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{LocalResult, NaiveDate, PosixTz};
+    ///
+    /// let tz: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse()?;
+    ///
+    /// let fall_back = NaiveDate::from_ymd(2021, 11, 7)?.and_hms(1, 30, 0)?;
+    /// match tz.offsets_from_local_datetime(&fall_back)? {
+    ///     LocalResult::Ambiguous(earlier, later) => {
+    ///         assert_eq!(earlier.to_string(), "EDT");
+    ///         assert_eq!(later.to_string(), "EST");
+    ///     }
+    ///     LocalResult::Single(_) => unreachable!(),
+    /// }
+    ///
+    /// let spring_forward = NaiveDate::from_ymd(2021, 3, 14)?.and_hms(2, 30, 0)?;
+    /// assert!(tz.offsets_from_local_datetime(&spring_forward).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn offsets_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<LocalResult<PosixTzOffset>, ChronoError> {
+        let rule = match self.dst {
+            Some(ref rule) => rule,
+            None => return Ok(LocalResult::Single(self.offset(false))),
+        };
+
+        let local_timestamp = local.timestamp();
+        let year = local.year();
+        let types = [(rule.dst.offset, true), (self.std.offset, false)];
+        let mut candidates = types
+            .iter()
+            .filter(|&&(offset, is_dst)| {
+                let utc = local_timestamp - i64::from(offset.local_minus_utc());
+                rule.is_dst_at(self.std.offset, utc, year) == is_dst
+            })
+            .map(|&(_, is_dst)| self.offset(is_dst));
+
+        // the daylight saving time offset is usually ahead of standard time,
+        // so it denotes the earlier instant when both are possible
+        match (candidates.next(), candidates.next()) {
+            (Some(a), Some(b)) if a.fix() == b.fix() => Ok(LocalResult::Single(b)),
+            (Some(a), Some(b)) if a.fix().local_minus_utc() > b.fix().local_minus_utc() => {
+                Ok(LocalResult::Ambiguous(a, b))
+            }
+            (Some(a), Some(b)) => Ok(LocalResult::Ambiguous(b, a)),
+            (Some(a), None) => Ok(LocalResult::Single(a)),
+            _ => Err(ChronoError::new(ChronoErrorKind::InvalidDateTime)),
+        }
+    }
+
+    /// Returns the offset in effect at the given UTC date and time.
+    fn offset_at_utc(&self, utc: &NaiveDateTime) -> PosixTzOffset {
+        let is_dst = match self.dst {
+            Some(ref rule) => rule.is_dst_at(self.std.offset, utc.timestamp(), utc.year()),
+            None => false,
+        };
+        self.offset(is_dst)
+    }
+
+    fn offset(&self, is_dst: bool) -> PosixTzOffset {
+        PosixTzOffset { tz: *self, is_dst }
+    }
+}
+
+impl PosixTzOffset {
+    /// Returns `true` if this is the daylight saving time offset.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    fn local_time_type(&self) -> &LocalTimeType {
+        match self.tz.dst {
+            Some(ref rule) if self.is_dst => &rule.dst,
+            _ => &self.tz.std,
+        }
+    }
+}
+
+impl Offset for PosixTzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.local_time_type().offset
+    }
+
+    fn abbreviation(&self) -> Option<&str> {
+        Some(self.local_time_type().name.as_str())
+    }
+}
+
+impl fmt::Debug for PosixTzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{:?}", self.local_time_type().name.as_str(), self.fix())
+    }
+}
+
+impl fmt::Display for PosixTzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.local_time_type().name.as_str())
+    }
+}
+
+impl fmt::Debug for PosixTz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PosixTz({}", self.std.name.as_str())?;
+        if let Some(ref rule) = self.dst {
+            write!(f, "/{}", rule.dst.name.as_str())?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl TimeZone for PosixTz {
+    type Offset = PosixTzOffset;
+
+    fn from_offset(offset: &PosixTzOffset) -> PosixTz {
+        offset.tz
+    }
+
+    // uses the offset at the local midnight, much like `Local`.
+    fn offset_from_local_date(&self, local: &NaiveDate) -> Result<PosixTzOffset, ChronoError> {
+        self.offset_from_local_datetime(&local.and_midnight())
+    }
+
+    fn offset_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<PosixTzOffset, ChronoError> {
+        match self.offsets_from_local_datetime(local)? {
+            LocalResult::Single(offset) => Ok(offset),
+            LocalResult::Ambiguous(..) => Err(ChronoError::new(ChronoErrorKind::AmbiguousDate)),
+        }
+    }
+
+    fn offset_from_local_datetime_all(
+        &self,
+        local: &NaiveDateTime,
+    ) -> Result<LocalResult<PosixTzOffset>, ChronoError> {
+        self.offsets_from_local_datetime(local)
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Result<PosixTzOffset, ChronoError> {
+        Ok(self.offset_at_utc(&utc.and_midnight()))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Result<PosixTzOffset, ChronoError> {
+        Ok(self.offset_at_utc(utc))
+    }
+
+    fn next_transition(&self, after: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let rule = self.dst.as_ref()?;
+        if rule.dst.offset == self.std.offset {
+            return None;
+        }
+
+        let after = after.timestamp();
+        let year = utc_year(after)?;
+        let mut transitions = [None; 8];
+        for (i, year) in (year - 1..=year + 2).enumerate() {
+            let [start, end] = rule.transitions(self.std.offset, year);
+            transitions[i * 2] = start;
+            transitions[i * 2 + 1] = end;
+        }
+        transitions.sort_unstable();
+
+        // skip transitions which don't change anything, like those of a
+        // rule which keeps daylight saving time all year
+        let (at, _) = transitions.iter().filter_map(|t| *t).find(|&(at, _)| {
+            let year = match utc_year(at) {
+                Some(year) => year,
+                None => return false,
+            };
+            at > after
+                && rule.is_dst_at(self.std.offset, at - 1, year)
+                    != rule.is_dst_at(self.std.offset, at, year)
+        })?;
+        let utc = NaiveDateTime::from_timestamp(at, 0).ok()?;
+        Some(DateTime::from_utc(utc, Utc))
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = ChronoError;
+
+    fn from_str(s: &str) -> Result<PosixTz, ChronoError> {
+        let invalid = || ChronoError::new(ChronoErrorKind::InvalidTimeZone);
+        let mut cursor = Cursor { bytes: s.as_bytes() };
+
+        let std_name = cursor.name().ok_or_else(invalid)?;
+        let std_offset = cursor.offset().ok_or_else(invalid)?;
+        let std = LocalTimeType { name: std_name, offset: std_offset };
+        if cursor.is_empty() {
+            return Ok(PosixTz { std, dst: None });
+        }
+
+        let dst_name = cursor.name().ok_or_else(invalid)?;
+        let dst_offset = match cursor.peek() {
+            Some(b',') | None => FixedOffset::east_opt(std_offset.local_minus_utc() + 3600),
+            Some(_) => cursor.offset(),
+        };
+        let dst = LocalTimeType { name: dst_name, offset: dst_offset.ok_or_else(invalid)? };
+
+        cursor.tag(b',').ok_or_else(invalid)?;
+        let (start, start_time) = cursor.rule().ok_or_else(invalid)?;
+        cursor.tag(b',').ok_or_else(invalid)?;
+        let (end, end_time) = cursor.rule().ok_or_else(invalid)?;
+        if !cursor.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(PosixTz { std, dst: Some(DstRule { dst, start, start_time, end, end_time }) })
+    }
+}
+
+impl DstRule {
+    /// Returns `true` if daylight saving time is in effect at the given Unix
+    /// timestamp, which is close to the given year.
+    fn is_dst_at(&self, std: FixedOffset, timestamp: i64, year: i32) -> bool {
+        // the transitions of the surrounding years, since the local times of
+        // transitions may extend into the neighbouring years
+        let mut transitions = [None; 6];
+        for (i, &year) in [year - 1, year, year + 1].iter().enumerate() {
+            let [start, end] = self.transitions(std, year);
+            transitions[i * 2] = start;
+            transitions[i * 2 + 1] = end;
+        }
+
+        let transitions = transitions.iter().filter_map(|t| *t);
+        match transitions.clone().filter(|&(at, _)| at <= timestamp).max_by_key(|&(at, _)| at) {
+            Some((_, is_dst)) => is_dst,
+            // before all of the transitions, so the opposite of the first one is in effect
+            None => transitions.min_by_key(|&(at, _)| at).map_or(false, |(_, is_dst)| !is_dst),
+        }
+    }
+
+    /// Returns the Unix timestamps of the start and end of daylight saving
+    /// time in the given year, with whether daylight saving time starts.
+    fn transitions(&self, std: FixedOffset, year: i32) -> [Option<(i64, bool)>; 2] {
+        let start = self.start.to_date(year).map(|date| {
+            let local = days_since_unix_epoch(date) * 86_400 + i64::from(self.start_time);
+            (local - i64::from(std.local_minus_utc()), true)
+        });
+        let end = self.end.to_date(year).map(|date| {
+            let local = days_since_unix_epoch(date) * 86_400 + i64::from(self.end_time);
+            (local - i64::from(self.dst.offset.local_minus_utc()), false)
+        });
+        [start, end]
+    }
+}
+
+impl RuleDay {
+    /// Returns the date of this rule in the given year, if it exists.
+    fn to_date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            RuleDay::Julian1(day) => {
+                // February 29 is never counted, so days after February are shifted in leap years
+                let leap_day = NaiveDate::from_ymd(year, 2, 29).is_ok() && day >= 60;
+                NaiveDate::from_yo(year, u32::from(day) + if leap_day { 1 } else { 0 }).ok()
+            }
+            RuleDay::Julian0(day) => {
+                let ordinal = u32::from(day) + 1;
+                match NaiveDate::from_yo(year, ordinal) {
+                    Ok(date) => Some(date),
+                    // day 365 in a common year is the first day of the next year
+                    Err(_) => NaiveDate::from_yo(year + 1, 1).ok(),
+                }
+            }
+            RuleDay::MonthWeekday { month, week, weekday } => {
+                let month = u32::from(month);
+                NaiveDate::from_weekday_of_month(year, month, weekday, week)
+                    .or_else(|_| NaiveDate::from_weekday_of_month(year, month, weekday, 4))
+                    .ok()
+            }
+        }
+    }
+}
+
+/// Returns the UTC year of the given Unix timestamp, if it is in range.
+fn utc_year(timestamp: i64) -> Option<i32> {
+    Some(NaiveDateTime::from_timestamp(timestamp, 0).ok()?.year())
+}
+
+fn days_since_unix_epoch(date: NaiveDate) -> i64 {
+    i64::from(date.num_days_from_ce()) - UNIX_EPOCH_DAYS_FROM_CE
+}
+
+impl Name {
+    fn as_str(&self) -> &str {
+        // only ASCII is ever stored
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or("")
+    }
+}
+
+/// A minimal cursor over the bytes of a TZ string.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.first().cloned()
+    }
+
+    fn tag(&mut self, tag: u8) -> Option<()> {
+        if self.peek()? != tag {
+            return None;
+        }
+        self.bytes = &self.bytes[1..];
+        Some(())
+    }
+
+    /// Takes bytes while they match the predicate.
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> &'a [u8] {
+        let len = self.bytes.iter().take_while(|&&b| f(b)).count();
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        taken
+    }
+
+    /// Parses a time zone abbreviation, either `EST` or `<+0530>`.
+    fn name(&mut self) -> Option<Name> {
+        let name = if self.tag(b'<').is_some() {
+            let name = self.take_while(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-');
+            self.tag(b'>')?;
+            name
+        } else {
+            self.take_while(|b| b.is_ascii_alphabetic())
+        };
+        if name.len() < 3 || name.len() > MAX_NAME_LEN {
+            return None;
+        }
+        let mut bytes = [0; MAX_NAME_LEN];
+        bytes[..name.len()].copy_from_slice(name);
+        Some(Name { bytes, len: name.len() as u8 })
+    }
+
+    /// Parses a number of at most `max_digits` digits.
+    fn number(&mut self, max_digits: usize) -> Option<i32> {
+        let digits = self.take_while(|b| b.is_ascii_digit());
+        if digits.is_empty() || digits.len() > max_digits {
+            return None;
+        }
+        Some(digits.iter().fold(0, |n, &d| n * 10 + i32::from(d - b'0')))
+    }
+
+    /// Parses `[+-]hh[:mm[:ss]]` into seconds, with hours up to `max_hours`.
+    fn signed_hhmmss(&mut self, max_hours: i32) -> Option<i32> {
+        let sign = match self.peek() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            self.bytes = &self.bytes[1..];
+        }
+        let hours = self.number(3)?;
+        let mut minutes = 0;
+        let mut seconds = 0;
+        if self.tag(b':').is_some() {
+            minutes = self.number(2)?;
+            if self.tag(b':').is_some() {
+                seconds = self.number(2)?;
+            }
+        }
+        if hours > max_hours || minutes > 59 || seconds > 59 {
+            return None;
+        }
+        let secs = hours * 3600 + minutes * 60 + seconds;
+        Some(if sign < 0 { -secs } else { secs })
+    }
+
+    /// Parses an offset, which is the time to add to the local time to get UTC.
+    fn offset(&mut self) -> Option<FixedOffset> {
+        FixedOffset::west_opt(self.signed_hhmmss(24)?)
+    }
+
+    /// Parses `Jn`, `n` or `Mm.w.d` followed by an optional `/time`.
+    fn rule(&mut self) -> Option<(RuleDay, i32)> {
+        let day = match self.peek()? {
+            b'J' => {
+                self.bytes = &self.bytes[1..];
+                let day = self.number(3)?;
+                if !(1..=365).contains(&day) {
+                    return None;
+                }
+                RuleDay::Julian1(day as u16)
+            }
+            b'M' => {
+                self.bytes = &self.bytes[1..];
+                let month = self.number(2)?;
+                self.tag(b'.')?;
+                let week = self.number(1)?;
+                self.tag(b'.')?;
+                let weekday = self.number(1)?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                    return None;
+                }
+                // POSIX counts weekdays from Sunday
+                let weekday = Weekday::try_from(((weekday + 6) % 7) as u8).ok()?;
+                RuleDay::MonthWeekday { month: month as u8, week: week as u8, weekday }
+            }
+            _ => {
+                let day = self.number(3)?;
+                if day > 365 {
+                    return None;
+                }
+                RuleDay::Julian0(day as u16)
+            }
+        };
+        let time = match self.tag(b'/') {
+            Some(()) => self.signed_hhmmss(167)?,
+            None => DEFAULT_RULE_TIME,
+        };
+        Some((day, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PosixTz, PosixTzOffset};
+    use crate::error::{ChronoError, ChronoErrorKind};
+    use crate::naive::{NaiveDate, NaiveDateTime};
+    use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+    use crate::{DateTime, TimeDelta};
+
+    const US_RULES: &str = "EST5EDT,M3.2.0,M11.1.0";
+
+    fn ymdhms(y: i32, m: u32, d: u32, h: u32, n: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(y, m, d).unwrap().and_hms(h, n, s).unwrap()
+    }
+
+    fn utc_offset(tz: &PosixTz, utc: NaiveDateTime) -> (String, i32) {
+        let offset = tz.offset_from_utc_datetime(&utc).unwrap();
+        (offset.to_string(), offset.fix().local_minus_utc())
+    }
+
+    fn name(offset: LocalResult<PosixTzOffset>) -> LocalResult<String> {
+        offset.map(|o| o.to_string())
+    }
+
+    #[test]
+    fn test_posix_tz_us_spring_forward() {
+        let tz: PosixTz = US_RULES.parse().unwrap();
+
+        // 2021-03-14 02:00 EST is 07:00 UTC
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 3, 14, 6, 59, 59)), ("EST".into(), -5 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 3, 14, 7, 0, 0)), ("EDT".into(), -4 * 3600));
+
+        let local = |h, n, s| tz.offsets_from_local_datetime(&ymdhms(2021, 3, 14, h, n, s));
+        assert_eq!(local(1, 59, 59).map(name), Ok(LocalResult::Single("EST".into())));
+        let gap = Err(ChronoError::new(ChronoErrorKind::InvalidDateTime));
+        assert_eq!(local(2, 0, 0).map(name), gap);
+        assert_eq!(local(2, 30, 0).map(name), gap);
+        assert_eq!(local(2, 59, 59).map(name), gap);
+        assert_eq!(local(3, 0, 0).map(name), Ok(LocalResult::Single("EDT".into())));
+
+        assert_eq!(
+            tz.from_local_datetime(&ymdhms(2021, 3, 14, 2, 30, 0)),
+            Err(ChronoError::new(ChronoErrorKind::InvalidDateTime))
+        );
+        let dt = tz.from_local_datetime(&ymdhms(2021, 3, 14, 3, 0, 0)).unwrap();
+        assert_eq!(dt.naive_utc(), ymdhms(2021, 3, 14, 7, 0, 0));
+        assert_eq!(dt.to_string(), "2021-03-14 03:00:00 EDT");
+    }
+
+    #[test]
+    fn test_posix_tz_us_fall_back() {
+        let tz: PosixTz = US_RULES.parse().unwrap();
+
+        // 2021-11-07 02:00 EDT is 06:00 UTC
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 11, 7, 5, 59, 59)), ("EDT".into(), -4 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 11, 7, 6, 0, 0)), ("EST".into(), -5 * 3600));
+
+        let local = |h, n, s| tz.offsets_from_local_datetime(&ymdhms(2021, 11, 7, h, n, s));
+        assert_eq!(local(0, 59, 59).map(name), Ok(LocalResult::Single("EDT".into())));
+        let ambiguous = LocalResult::Ambiguous("EDT".to_string(), "EST".to_string());
+        assert_eq!(local(1, 0, 0).map(name), Ok(ambiguous.clone()));
+        assert_eq!(local(1, 30, 0).map(name), Ok(ambiguous.clone()));
+        assert_eq!(local(1, 59, 59).map(name), Ok(ambiguous));
+        assert_eq!(local(2, 0, 0).map(name), Ok(LocalResult::Single("EST".into())));
+
+        assert_eq!(
+            tz.from_local_datetime(&ymdhms(2021, 11, 7, 1, 30, 0)),
+            Err(ChronoError::new(ChronoErrorKind::AmbiguousDate))
+        );
+        assert_eq!(
+            tz.offset_from_local_datetime_all(&ymdhms(2021, 11, 7, 1, 30, 0)),
+            tz.offsets_from_local_datetime(&ymdhms(2021, 11, 7, 1, 30, 0))
+        );
+
+        // both readings of 01:30 are an hour apart
+        match tz.offsets_from_local_datetime(&ymdhms(2021, 11, 7, 1, 30, 0)).unwrap() {
+            LocalResult::Ambiguous(earlier, later) => {
+                let local = ymdhms(2021, 11, 7, 1, 30, 0);
+                let earlier = local - earlier.fix();
+                let later = local - later.fix();
+                assert_eq!(later.signed_duration_since(earlier).num_hours(), 1);
+            }
+            LocalResult::Single(_) => panic!("expected an ambiguous local time"),
+        }
+
+        // converting back and forth from UTC over the whole transition day
+        let mut utc = ymdhms(2021, 11, 6, 12, 0, 0);
+        while utc < ymdhms(2021, 11, 8, 12, 0, 0) {
+            let dt = Utc.from_utc_datetime(&utc).unwrap().with_timezone(&tz).unwrap();
+            match tz.offsets_from_local_datetime(&dt.naive_local()).unwrap() {
+                LocalResult::Single(offset) => assert_eq!(offset, *dt.offset()),
+                LocalResult::Ambiguous(a, b) => assert!(a == *dt.offset() || b == *dt.offset()),
+            }
+            utc += crate::TimeDelta::minutes(15);
+        }
+    }
+
+    #[test]
+    fn test_posix_tz_southern_hemisphere() {
+        let tz: PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 1, 15, 0, 0, 0)), ("AEDT".into(), 11 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 7, 15, 0, 0, 0)), ("AEST".into(), 10 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 12, 31, 23, 0, 0)), ("AEDT".into(), 11 * 3600));
+
+        // 2021-04-04 03:00 AEDT is 2021-04-03 16:00 UTC
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 4, 3, 15, 59, 59)), ("AEDT".into(), 11 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 4, 3, 16, 0, 0)), ("AEST".into(), 10 * 3600));
+        // 2021-10-03 02:00 AEST is 2021-10-02 16:00 UTC
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 10, 2, 15, 59, 59)), ("AEST".into(), 10 * 3600));
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 10, 2, 16, 0, 0)), ("AEDT".into(), 11 * 3600));
+    }
+
+    #[test]
+    fn test_posix_tz_fixed() {
+        let tz: PosixTz = "IST-5:30".parse().unwrap();
+        let dt = tz.ymd(2021, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+        assert_eq!(dt.offset().fix().local_minus_utc(), 5 * 3600 + 1800);
+        assert_eq!(dt.to_string(), "2021-06-01 12:00:00 IST");
+        assert!(!dt.offset().is_dst());
+
+        let tz: PosixTz = "<+0530>-05:30".parse().unwrap();
+        let dt = tz.ymd(2021, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+        assert_eq!(dt.offset().abbreviation(), Some("+0530"));
+        assert_eq!(dt.offset().fix().local_minus_utc(), 5 * 3600 + 1800);
+
+        let tz: PosixTz = "<-03>3".parse().unwrap();
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 6, 1, 0, 0, 0)), ("-03".into(), -3 * 3600));
+    }
+
+    #[test]
+    fn test_posix_tz_next_transition() {
+        let utc = |y, m, d, h| DateTime::from_utc(ymdhms(y, m, d, h, 0, 0), Utc);
+        let next = |tz: &PosixTz, after| tz.next_transition(&after);
+
+        // 02:00 EST is 07:00 UTC, 02:00 EDT is 06:00 UTC
+        let tz: PosixTz = US_RULES.parse().unwrap();
+        assert_eq!(next(&tz, utc(2021, 1, 1, 0)), Some(utc(2021, 3, 14, 7)));
+        assert_eq!(next(&tz, utc(2021, 3, 14, 6)), Some(utc(2021, 3, 14, 7)));
+        assert_eq!(next(&tz, utc(2021, 3, 14, 7)), Some(utc(2021, 11, 7, 6)));
+        assert_eq!(next(&tz, utc(2021, 11, 7, 6)), Some(utc(2022, 3, 13, 7)));
+        assert_eq!(next(&tz, utc(2021, 12, 31, 23)), Some(utc(2022, 3, 13, 7)));
+
+        // the offset changes at the returned instant
+        let at = next(&tz, utc(2021, 1, 1, 0)).unwrap();
+        let before = tz.offset_from_utc_datetime(&(at.naive_utc() - TimeDelta::seconds(1)));
+        assert_eq!(before.unwrap().to_string(), "EST");
+        assert_eq!(tz.offset_from_utc_datetime(&at.naive_utc()).unwrap().to_string(), "EDT");
+
+        // daylight saving time ends at 03:00 AEDT, which is 16:00 UTC the day before
+        let tz: PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        assert_eq!(next(&tz, utc(2021, 1, 1, 0)), Some(utc(2021, 4, 3, 16)));
+        assert_eq!(next(&tz, utc(2021, 4, 3, 16)), Some(utc(2021, 10, 2, 16)));
+
+        // daylight saving time all year, so nothing ever changes
+        let tz: PosixTz = "EST5EDT,0/0,J365/25".parse().unwrap();
+        assert_eq!(next(&tz, utc(2021, 1, 1, 0)), None);
+
+        let tz: PosixTz = "IST-5:30".parse().unwrap();
+        assert_eq!(next(&tz, utc(2021, 1, 1, 0)), None);
+        assert_eq!(Utc.next_transition(&utc(2021, 1, 1, 0)), None);
+        assert_eq!(FixedOffset::east(3600).next_transition(&utc(2021, 1, 1, 0)), None);
+    }
+
+    #[test]
+    fn test_posix_tz_rule_days() {
+        let dst_start = |s: &str, year| {
+            let tz: PosixTz = s.parse().unwrap();
+            let mut date = NaiveDate::from_ymd(year, 1, 1).unwrap();
+            // the first day with daylight saving time before its end
+            while !tz.offset_from_utc_datetime(&date.and_hms(23, 59, 59).unwrap()).unwrap().is_dst()
+            {
+                date = date.succ().unwrap();
+            }
+            date
+        };
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d).unwrap();
+
+        // `Jn` never counts February 29
+        assert_eq!(dst_start("AAA0BBB,J60/0,J300/0", 2021), ymd(2021, 3, 1));
+        assert_eq!(dst_start("AAA0BBB,J60/0,J300/0", 2020), ymd(2020, 3, 1));
+        // `n` does
+        assert_eq!(dst_start("AAA0BBB,59/0,300/0", 2021), ymd(2021, 3, 1));
+        assert_eq!(dst_start("AAA0BBB,59/0,300/0", 2020), ymd(2020, 2, 29));
+        // the fifth week is the last one
+        assert_eq!(dst_start("AAA0BBB,M3.5.0/0,M10.5.0/0", 2021), ymd(2021, 3, 28));
+        assert_eq!(dst_start("AAA0BBB,M5.5.1/0,M10.5.0/0", 2021), ymd(2021, 5, 31));
+        // the time of the transition can be negative or beyond 24 hours
+        assert_eq!(dst_start("AAA0BBB,M3.2.0/-1,M10.5.0/0", 2021), ymd(2021, 3, 13));
+        assert_eq!(dst_start("AAA0BBB,M3.2.0/26,M10.5.0/0", 2021), ymd(2021, 3, 15));
+        // the daylight saving time offset defaults to an hour ahead
+        let tz: PosixTz = "AAA0BBB,M3.2.0/0,M10.5.0/0".parse().unwrap();
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 6, 1, 0, 0, 0)), ("BBB".into(), 3600));
+        let tz: PosixTz = "AAA0BBB-2,M3.2.0/0,M10.5.0/0".parse().unwrap();
+        assert_eq!(utc_offset(&tz, ymdhms(2021, 6, 1, 0, 0, 0)), ("BBB".into(), 7200));
+    }
+
+    #[test]
+    fn test_posix_tz_invalid() {
+        for &s in &[
+            "",
+            "E5",
+            "EST",
+            "EST+",
+            "EST5EDT",
+            "EST5EDT,M3.2.0",
+            "EST5EDT,M3.2.0,M11.1.0,",
+            "EST5EDT,M13.2.0,M11.1.0",
+            "EST5EDT,M3.6.0,M11.1.0",
+            "EST5EDT,M3.2.7,M11.1.0",
+            "EST5EDT,J0,J365",
+            "EST5EDT,366,300",
+            "EST5EDT,M3.2.0/168,M11.1.0",
+            "EST25",
+            "EST5:60",
+            "<EST5",
+            "<E>5",
+            "ABCDEFGHIJKLMNOPQ5",
+        ] {
+            assert_eq!(
+                s.parse::<PosixTz>(),
+                Err(ChronoError::new(ChronoErrorKind::InvalidTimeZone)),
+                "{:?}",
+                s
+            );
+        }
+    }
+}
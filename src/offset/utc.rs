@@ -18,6 +18,15 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use rkyv::{Archive, Deserialize, Serialize};
 
 use super::{FixedOffset, FixedTimeZone, Offset, TimeZone};
+#[cfg(all(
+    feature = "clock",
+    not(all(
+        target_arch = "wasm32",
+        feature = "wasmbind",
+        not(any(target_os = "emscripten", target_os = "wasi"))
+    ))
+))]
+use crate::error::ChronoErrorKind;
 use crate::naive::{NaiveDate, NaiveDateTime};
 #[cfg(feature = "clock")]
 use crate::{ChronoError, Date, DateTime};
@@ -53,14 +62,18 @@ impl Utc {
     }
 
     /// Returns a `DateTime` which corresponds to the current date and time.
+    ///
+    /// Returns `Err(ChronoError)` if the system clock is set before the Unix
+    /// epoch.
     #[cfg(not(all(
         target_arch = "wasm32",
         feature = "wasmbind",
         not(any(target_os = "emscripten", target_os = "wasi"))
     )))]
     pub fn now() -> Result<DateTime<Utc>, ChronoError> {
-        let now =
-            SystemTime::now().duration_since(UNIX_EPOCH).expect("system time before Unix epoch");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ChronoError::new(ChronoErrorKind::SystemTimeBeforeEpoch))?;
         let naive = NaiveDateTime::from_timestamp(now.as_secs() as i64, now.subsec_nanos() as u32)?;
         Ok(DateTime::from_utc(naive, Utc))
     }
@@ -114,6 +127,10 @@ impl Offset for Utc {
     fn fix(&self) -> FixedOffset {
         FixedOffset::east(0)
     }
+
+    fn abbreviation(&self) -> Option<&str> {
+        Some("UTC")
+    }
 }
 
 impl fmt::Debug for Utc {
@@ -0,0 +1,135 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Strategies for property testing with [`proptest`](https://docs.rs/proptest).
+//!
+//! The strategies only generate valid values, and shrink towards the Unix
+//! epoch rather than towards the oldest representable date. Generated times
+//! are never [leap seconds](../naive/struct.NaiveTime.html#leap-second-handling).
+//!
+//! *Available on crate feature 'proptest' only.*
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::proptest::datetime_in_range;
+//! use chrono::{TimeZone, Utc};
+//! use proptest::prelude::*;
+//!
+//! let start = Utc.ymd(2000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+//! let end = Utc.ymd(2100, 1, 1).unwrap().and_hms(0, 0, 0).unwrap();
+//!
+//! proptest!(|(dt in datetime_in_range(start, end))| {
+//!     prop_assert!(dt >= start && dt < end);
+//! });
+//! ```
+
+use ::proptest::strategy::Strategy;
+
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{FixedOffset, TimeZone, Utc};
+use crate::{DateTime, Datelike, TimeDelta};
+
+// The number of days from the common era to 1970-01-01.
+const UNIX_EPOCH_DAY: i32 = 719_163;
+
+/// Returns a strategy generating any [`NaiveDate`] between [`NaiveDate::MIN`]
+/// and [`NaiveDate::MAX`].
+pub fn any_naive_date() -> impl Strategy<Value = NaiveDate> {
+    let min = NaiveDate::MIN.num_days_from_ce() - UNIX_EPOCH_DAY;
+    let max = NaiveDate::MAX.num_days_from_ce() - UNIX_EPOCH_DAY;
+    (min..=max).prop_map(|days| {
+        NaiveDate::from_num_days_from_ce(days + UNIX_EPOCH_DAY).expect("date in range")
+    })
+}
+
+/// Returns a strategy generating any [`NaiveTime`] with nanosecond precision.
+pub fn any_naive_time() -> impl Strategy<Value = NaiveTime> {
+    (0..86_400u32, 0..1_000_000_000u32).prop_map(|(secs, nano)| {
+        NaiveTime::from_num_seconds_from_midnight(secs, nano).expect("time in range")
+    })
+}
+
+/// Returns a strategy generating any [`NaiveDateTime`] between
+/// [`NaiveDateTime::MIN`] and [`NaiveDateTime::MAX`].
+pub fn any_naive_datetime() -> impl Strategy<Value = NaiveDateTime> {
+    (any_naive_date(), any_naive_time()).prop_map(|(date, time)| NaiveDateTime::new(date, time))
+}
+
+/// Returns a strategy generating any [`FixedOffset`], in seconds.
+pub fn any_fixed_offset() -> impl Strategy<Value = FixedOffset> {
+    (-86_399..86_400i32).prop_map(FixedOffset::east)
+}
+
+/// Returns a strategy generating any `DateTime<Utc>`.
+pub fn any_datetime_utc() -> impl Strategy<Value = DateTime<Utc>> {
+    any_naive_datetime().prop_map(|datetime| DateTime::from_utc(datetime, Utc))
+}
+
+/// Returns a strategy generating a `DateTime<Utc>` from `start` (inclusive)
+/// up to `end` (exclusive), with nanosecond precision.
+///
+/// # Panics
+///
+/// Panics if `start` isn't before `end`.
+pub fn datetime_in_range(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> impl Strategy<Value = DateTime<Utc>> {
+    assert!(start < end, "`datetime_in_range` called with an empty range");
+    let span = end.signed_duration_since(start);
+    let secs = span.num_seconds();
+    let nanos = (span - TimeDelta::seconds(secs)).num_nanoseconds().unwrap_or(0);
+    let span = i128::from(secs) * 1_000_000_000 + i128::from(nanos);
+
+    (0..span).prop_map(move |n| {
+        let delta = TimeDelta::seconds((n / 1_000_000_000) as i64)
+            + TimeDelta::nanoseconds((n % 1_000_000_000) as i64);
+        Utc.from_utc_datetime(&(start.naive_utc() + delta)).expect("datetime in range")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use super::{any_datetime_utc, any_fixed_offset, any_naive_datetime, datetime_in_range};
+    use crate::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone, Utc};
+
+    proptest! {
+        #[test]
+        fn test_naive_datetime_format_parse(dt in any_naive_datetime()) {
+            let fmt = "%Y-%m-%d %H:%M:%S%.f";
+            let s = dt.format(fmt).to_string();
+            prop_assert_eq!(NaiveDateTime::parse_from_str(&s, fmt), Ok(dt), "{}", s);
+        }
+
+        #[test]
+        fn test_datetime_format_parse(dt in any_datetime_utc(), minutes in -1439..1440i32) {
+            // `%:z` can't represent seconds in the offset
+            let offset = FixedOffset::east(minutes * 60);
+            prop_assume!(dt.naive_utc().checked_add_offset(offset).is_ok());
+            let dt = dt.with_timezone(&offset).unwrap();
+            let fmt = "%Y-%m-%d %H:%M:%S%.f %:z";
+            let s = dt.format(fmt).to_string();
+            let parsed = DateTime::parse_from_str(&s, fmt);
+            prop_assert_eq!(parsed, Ok(dt), "{}", s);
+            prop_assert_eq!(*parsed.unwrap().offset(), offset);
+        }
+
+        #[test]
+        fn test_fixed_offset(offset in any_fixed_offset()) {
+            prop_assert!(offset.local_minus_utc().abs() < 86_400);
+            prop_assert_eq!(offset.fix(), offset);
+        }
+
+        #[test]
+        fn test_datetime_in_range(dt in datetime_in_range(
+            Utc.ymd(1999, 12, 31).unwrap().and_hms(23, 59, 59).unwrap(),
+            Utc.ymd(2000, 1, 1).unwrap().and_hms_nano(0, 0, 0, 1).unwrap(),
+        )) {
+            prop_assert!(dt >= Utc.ymd(1999, 12, 31).unwrap().and_hms(23, 59, 59).unwrap());
+            prop_assert!(dt <= Utc.ymd(2000, 1, 1).unwrap().and_hms(0, 0, 0).unwrap());
+        }
+    }
+}
@@ -22,12 +22,18 @@ pub trait SubsecRound {
     /// 9 or more digits, self is returned unmodified. Halfway values are
     /// rounded up (away from zero).
     ///
+    /// Rounding up can carry into the seconds, and from there into the
+    /// minutes and so on.
+    ///
     /// # Example
     /// ``` rust
     /// # use chrono::{DateTime, SubsecRound, Timelike, TimeZone, Utc};
     /// let dt = Utc.ymd(2018, 1, 11)?.and_hms_milli(12, 0, 0, 154)?;
     /// assert_eq!(dt.round_subsecs(2).nanosecond(), 150_000_000);
     /// assert_eq!(dt.round_subsecs(1).nanosecond(), 200_000_000);
+    ///
+    /// let dt = Utc.ymd(2018, 1, 11)?.and_hms_nano(12, 59, 59, 999_999_999)?;
+    /// assert_eq!(dt.round_subsecs(3), Utc.ymd(2018, 1, 11)?.and_hms(13, 0, 0)?);
     /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     fn round_subsecs(self, digits: u16) -> Self;
@@ -401,6 +407,28 @@ mod tests {
         assert_eq!(dt.trunc_subsecs(0).second(), 59);
     }
 
+    #[test]
+    fn test_subsecs_to_millis() {
+        let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms_nano(12, 0, 0, 123_456_789).unwrap();
+        assert_eq!(dt.trunc_subsecs(3).nanosecond(), 123_000_000);
+        assert_eq!(dt.round_subsecs(3).nanosecond(), 123_000_000);
+        assert_eq!(dt.trunc_subsecs(3).to_rfc3339(), "2022-06-01T12:00:00.123+00:00");
+
+        // rounding carries into the seconds and beyond, truncating doesn't
+        let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms_nano(12, 0, 0, 999_999_999).unwrap();
+        assert_eq!(dt.round_subsecs(3), Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 1).unwrap());
+        assert_eq!(dt.trunc_subsecs(3).nanosecond(), 999_000_000);
+        let dt = Utc.ymd(2022, 12, 31).unwrap().and_hms_nano(23, 59, 59, 999_500_000).unwrap();
+        assert_eq!(dt.round_subsecs(3), Utc.ymd(2023, 1, 1).unwrap().and_hms(0, 0, 0).unwrap());
+        assert_eq!(dt.trunc_subsecs(3).nanosecond(), 999_000_000);
+
+        // a leap second stays one when its fraction is rounded down or truncated
+        let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms_nano(23, 59, 59, 1_123_456_789).unwrap();
+        assert_eq!(dt.round_subsecs(3).nanosecond(), 1_123_000_000);
+        assert_eq!(dt.trunc_subsecs(3).nanosecond(), 1_123_000_000);
+        assert_eq!(dt.trunc_subsecs(3).to_rfc3339(), "2016-12-31T23:59:60.123+00:00");
+    }
+
     #[test]
     fn test_duration_round() {
         let dt = Utc.ymd(2016, 12, 31).unwrap().and_hms_nano(23, 59, 59, 175_500_000).unwrap();
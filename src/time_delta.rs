@@ -10,12 +10,23 @@
 
 //! Temporal quantification
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::iter::Sum;
 use core::ops::{Add, Div, Mul, Neg, Sub};
 use core::time::Duration as StdDuration;
 use core::{fmt, i64};
 #[cfg(any(feature = "std", test))]
 use std::error::Error;
 
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
+
+use crate::error::{ChronoError, ChronoErrorKind};
+
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
@@ -75,8 +86,31 @@ impl TimeDelta {
     /// Panics when the duration is out of bounds.
     #[inline]
     pub fn weeks(weeks: i64) -> TimeDelta {
-        let secs = weeks.checked_mul(SECS_PER_WEEK).expect("Duration::weeks out of bounds");
-        TimeDelta::seconds(secs)
+        TimeDelta::try_weeks(weeks).expect("Duration::weeks out of bounds")
+    }
+
+    /// Makes a new `Duration` with given number of weeks.
+    /// Returns `Err(ChronoError)` when the duration is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::try_weeks(2)?.num_days(), 14);
+    /// assert!(TimeDelta::try_weeks(i64::MAX).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    pub fn try_weeks(weeks: i64) -> Result<TimeDelta, ChronoError> {
+        let secs = weeks
+            .checked_mul(SECS_PER_WEEK)
+            .ok_or_else(|| ChronoError::new(ChronoErrorKind::Overflow))?;
+        let d = TimeDelta { secs, nanos: 0 };
+        if d < MIN || d > MAX {
+            return Err(ChronoError::new(ChronoErrorKind::Overflow));
+        }
+        Ok(d)
     }
 
     /// Makes a new `Duration` with given number of days.
@@ -142,6 +176,8 @@ impl TimeDelta {
     }
 
     /// Returns the total number of whole weeks in the duration.
+    ///
+    /// This rounds towards zero, so `-13` days are `-1` week.
     #[inline]
     pub fn num_weeks(&self) -> i64 {
         self.num_days() / 7
@@ -246,6 +282,166 @@ impl TimeDelta {
         }
     }
 
+    /// Multiply a duration by an integer, returning `None` if overflow occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(1500).checked_mul(-3), Some(TimeDelta::milliseconds(-4500)));
+    /// assert_eq!(TimeDelta::max_value().checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: i32) -> Option<TimeDelta> {
+        TimeDelta::from_total_nanos(self.total_nanos() * i128::from(rhs))
+    }
+
+    /// Divide a duration by an integer, returning `None` if `rhs` is zero or
+    /// if overflow occurred.
+    ///
+    /// The result is otherwise the same as with the `/` operator. It can only
+    /// overflow for [`TimeDelta::min_value`] divided by `-1`, as the bounds
+    /// aren't symmetric.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(10).checked_div(4), Some(TimeDelta::milliseconds(2500)));
+    /// assert_eq!(TimeDelta::seconds(10).checked_div(0), None);
+    /// ```
+    pub fn checked_div(&self, rhs: i32) -> Option<TimeDelta> {
+        if rhs == 0 {
+            return None;
+        }
+        let d = *self / rhs;
+        if d < MIN || d > MAX {
+            None
+        } else {
+            Some(d)
+        }
+    }
+
+    /// Add two durations, saturating at the bounds instead of overflowing.
+    fn saturating_add(&self, rhs: &TimeDelta) -> TimeDelta {
+        match self.checked_add(rhs) {
+            Some(d) => d,
+            None if *rhs < TimeDelta::zero() => MIN,
+            None => MAX,
+        }
+    }
+
+    /// Sums the durations, returning `Err(ChronoError)` if overflow occurred.
+    ///
+    /// The [`Sum`] implementations saturate instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let laps = [TimeDelta::seconds(62), TimeDelta::seconds(58)];
+    /// assert_eq!(TimeDelta::try_sum(&laps)?, TimeDelta::minutes(2));
+    /// assert_eq!(TimeDelta::try_sum(laps.iter().copied())?, TimeDelta::minutes(2));
+    ///
+    /// assert!(TimeDelta::try_sum(&[TimeDelta::max_value(), TimeDelta::nanoseconds(1)]).is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn try_sum<I>(iter: I) -> Result<TimeDelta, ChronoError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<TimeDelta>,
+    {
+        iter.into_iter().try_fold(TimeDelta::zero(), |acc, x| {
+            acc.checked_add(x.borrow()).ok_or_else(|| ChronoError::new(ChronoErrorKind::Overflow))
+        })
+    }
+
+    /// Divides this duration by another one, returning the ratio as an `f64`.
+    ///
+    /// This follows float semantics when `rhs` is zero: the result is
+    /// infinite, or NaN if this duration is zero as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::hours(1).div_duration_f64(TimeDelta::minutes(30)), 2.0);
+    /// assert_eq!(TimeDelta::seconds(-1).div_duration_f64(TimeDelta::seconds(4)), -0.25);
+    /// assert_eq!(TimeDelta::seconds(1).div_duration_f64(TimeDelta::zero()), std::f64::INFINITY);
+    /// assert!(TimeDelta::zero().div_duration_f64(TimeDelta::zero()).is_nan());
+    /// ```
+    pub fn div_duration_f64(self, rhs: TimeDelta) -> f64 {
+        self.total_nanos() as f64 / rhs.total_nanos() as f64
+    }
+
+    /// Multiplies the duration by an `f64`, rounding towards zero to whole
+    /// nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` isn't finite or if the result overflows, see
+    /// [`checked_mul_f64`](#method.checked_mul_f64) for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let duration = TimeDelta::seconds(10);
+    /// assert_eq!(duration.mul_f64(0.25), TimeDelta::milliseconds(2500));
+    /// assert_eq!(duration.mul_f64(-1.5), TimeDelta::seconds(-15));
+    /// ```
+    pub fn mul_f64(self, rhs: f64) -> TimeDelta {
+        self.checked_mul_f64(rhs).expect("`TimeDelta::mul_f64` overflowed")
+    }
+
+    /// Multiplies the duration by an `f64`, rounding towards zero to whole
+    /// nanoseconds.
+    ///
+    /// Returns `None` if `rhs` isn't finite or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let start = TimeDelta::seconds(2);
+    /// let end = TimeDelta::seconds(6);
+    /// let halfway = start.checked_mul_f64(0.5).unwrap() + end.checked_mul_f64(0.5).unwrap();
+    /// assert_eq!(halfway, TimeDelta::seconds(4));
+    ///
+    /// assert_eq!(TimeDelta::max_value().checked_mul_f64(2.0), None);
+    /// assert_eq!(start.checked_mul_f64(std::f64::NAN), None);
+    /// ```
+    pub fn checked_mul_f64(self, rhs: f64) -> Option<TimeDelta> {
+        let nanos = self.total_nanos() as f64 * rhs;
+        if nanos.is_nan() || nanos < MIN.total_nanos() as f64 || nanos > MAX.total_nanos() as f64 {
+            return None;
+        }
+        // the bounds can be rounded up when converted to `f64`, so they are
+        // checked again
+        TimeDelta::from_total_nanos(nanos as i128)
+    }
+
+    /// Makes a duration from nanoseconds, or `None` if it is out of bounds.
+    fn from_total_nanos(nanos: i128) -> Option<TimeDelta> {
+        if nanos < MIN.total_nanos() || nanos > MAX.total_nanos() {
+            return None;
+        }
+        let secs = nanos.div_euclid(i128::from(NANOS_PER_SEC));
+        let nanos = nanos.rem_euclid(i128::from(NANOS_PER_SEC));
+        Some(TimeDelta { secs: secs as i64, nanos: nanos as i32 })
+    }
+
+    /// Returns the duration in nanoseconds, which can't overflow an `i128`.
+    fn total_nanos(&self) -> i128 {
+        i128::from(self.secs) * i128::from(NANOS_PER_SEC) + i128::from(self.nanos)
+    }
+
     /// Returns the duration as an absolute (non-negative) value.
     #[inline]
     pub fn abs(&self) -> TimeDelta {
@@ -280,6 +476,186 @@ impl TimeDelta {
         self.secs == 0 && self.nanos == 0
     }
 
+    /// Returns a wrapper which displays the duration in a human readable form,
+    /// like `1h 2m 3s`.
+    ///
+    /// Each non-zero component is printed from days down to nanoseconds,
+    /// separated by spaces. Sub-second components use `ms`, `µs` and `ns`.
+    /// Negative durations are prefixed with `-` and a zero duration is
+    /// printed as `0s`. The alternate flag (`{:#}`) spells the units out,
+    /// like `3 days`.
+    ///
+    /// Nothing is allocated until the wrapper is formatted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(3723).humanize().to_string(), "1h 2m 3s");
+    /// assert_eq!(TimeDelta::milliseconds(-1500).humanize().to_string(), "-1s 500ms");
+    /// assert_eq!(format!("{:#}", TimeDelta::days(3).humanize()), "3 days");
+    /// ```
+    #[inline]
+    pub fn humanize(&self) -> HumanizedTimeDelta {
+        HumanizedTimeDelta(*self)
+    }
+
+    /// Parses an ISO 8601 duration like `PT1H30M` or `P2DT3H`.
+    ///
+    /// Only the components which have an exact length are supported: weeks
+    /// (`W`), days (`D`), hours (`H`), minutes (`M`) and seconds (`S`).
+    /// Seconds may have a fractional part using either `.` or `,` as a
+    /// separator. The duration may be prefixed with a `-` sign.
+    ///
+    /// Returns `Err(ChronoError)` if the duration has years or months in it,
+    /// since a `TimeDelta` can't represent calendar units exactly, if it is
+    /// malformed, or if it is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_iso8601("PT1H30M")?, TimeDelta::minutes(90));
+    /// assert_eq!(TimeDelta::from_iso8601("-P1DT0.5S")?, -TimeDelta::milliseconds(86_400_500));
+    /// assert!(TimeDelta::from_iso8601("P1Y").is_err());
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<TimeDelta, ChronoError> {
+        let invalid = ChronoError::new(ChronoErrorKind::InvalidDuration);
+
+        let mut s = s.as_bytes();
+
+        let negative = s.first() == Some(&b'-');
+
+        if negative || s.first() == Some(&b'+') {
+            s = &s[1..];
+        }
+
+        if s.first() != Some(&b'P') {
+            return Err(invalid);
+        }
+
+        s = &s[1..];
+
+        let mut total = TimeDelta::zero();
+        let mut in_time = false;
+        // the order of the last designator seen, which must be increasing.
+        let mut last = 0;
+
+        while let Some(&c) = s.first() {
+            if c == b'T' {
+                if in_time {
+                    return Err(invalid);
+                }
+
+                in_time = true;
+                s = &s[1..];
+                continue;
+            }
+
+            let len = s.iter().take_while(|c| c.is_ascii_digit()).count();
+
+            if len == 0 {
+                return Err(invalid);
+            }
+
+            let mut value = 0i64;
+
+            for &c in &s[..len] {
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(i64::from(c - b'0')))
+                    .ok_or(invalid)?;
+            }
+
+            s = &s[len..];
+
+            let mut nanos = None;
+
+            if s.first() == Some(&b'.') || s.first() == Some(&b',') {
+                let len = s[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+
+                if len == 0 {
+                    return Err(invalid);
+                }
+
+                // digits beyond nanosecond precision are truncated.
+                let mut n = 0;
+
+                for i in 0..9 {
+                    let digit = s.get(1 + i).filter(|_| i < len).map_or(0, |c| c - b'0');
+                    n = n * 10 + i32::from(digit);
+                }
+
+                nanos = Some(n);
+                s = &s[1 + len..];
+            }
+
+            let unit = match s.first() {
+                Some(&unit) => unit,
+                None => return Err(invalid),
+            };
+
+            s = &s[1..];
+
+            let (order, unit_secs) = match (in_time, unit) {
+                (false, b'Y') | (false, b'M') => {
+                    return Err(ChronoError::new(ChronoErrorKind::UnsupportedDurationUnit))
+                }
+                (false, b'W') => (1, SECS_PER_WEEK),
+                (false, b'D') => (2, SECS_PER_DAY),
+                (true, b'H') => (3, SECS_PER_HOUR),
+                (true, b'M') => (4, SECS_PER_MINUTE),
+                (true, b'S') => (5, 1),
+                _ => return Err(invalid),
+            };
+
+            if order <= last || (nanos.is_some() && unit != b'S') {
+                return Err(invalid);
+            }
+
+            last = order;
+
+            let secs = value.checked_mul(unit_secs).ok_or(invalid)?;
+            let part = TimeDelta { secs, nanos: nanos.unwrap_or(0) };
+            // accumulate with the sign applied, since `MIN` has a larger
+            // magnitude than `MAX`.
+            let part = if negative { -part } else { part };
+            total = total.checked_add(&part).ok_or(invalid)?;
+        }
+
+        // there must be at least one component, and one after `T` if present.
+        if last == 0 || (in_time && last < 3) {
+            return Err(invalid);
+        }
+
+        Ok(total)
+    }
+
+    /// Formats the duration as an ISO 8601 duration like `P2DT3H`.
+    ///
+    /// Unlike the [`Display`](fmt::Display) implementation, which only uses
+    /// days and seconds, this breaks the time down into hours, minutes and
+    /// seconds. The output can be parsed back with
+    /// [`TimeDelta::from_iso8601`]. Negative durations are prefixed with `-`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(90).to_iso8601(), "PT1H30M");
+    /// assert_eq!(TimeDelta::milliseconds(-1500).to_iso8601(), "-PT1.500S");
+    /// assert_eq!(TimeDelta::zero().to_iso8601(), "PT0S");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_iso8601(&self) -> String {
+        Iso8601(*self).to_string()
+    }
+
     /// Creates a `time::Duration` object from `std::time::Duration`
     ///
     /// This function errors when original duration is larger than the maximum
@@ -382,19 +758,23 @@ impl Div<i32> for TimeDelta {
     }
 }
 
-#[cfg(any(feature = "std", test))]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl<'a> std::iter::Sum<&'a TimeDelta> for TimeDelta {
+/// Sums the durations, saturating at [`TimeDelta::max_value`] or
+/// [`TimeDelta::min_value`] instead of overflowing.
+///
+/// Use [`TimeDelta::try_sum`] to detect overflow instead.
+impl<'a> Sum<&'a TimeDelta> for TimeDelta {
     fn sum<I: Iterator<Item = &'a TimeDelta>>(iter: I) -> TimeDelta {
-        iter.fold(TimeDelta::zero(), |acc, x| acc + *x)
+        iter.fold(TimeDelta::zero(), |acc, x| acc.saturating_add(x))
     }
 }
 
-#[cfg(any(feature = "std", test))]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl std::iter::Sum<TimeDelta> for TimeDelta {
+/// Sums the durations, saturating at [`TimeDelta::max_value`] or
+/// [`TimeDelta::min_value`] instead of overflowing.
+///
+/// Use [`TimeDelta::try_sum`] to detect overflow instead.
+impl Sum<TimeDelta> for TimeDelta {
     fn sum<I: Iterator<Item = TimeDelta>>(iter: I) -> TimeDelta {
-        iter.fold(TimeDelta::zero(), |acc, x| acc + x)
+        iter.fold(TimeDelta::zero(), |acc, x| acc.saturating_add(&x))
     }
 }
 
@@ -432,6 +812,135 @@ impl fmt::Display for TimeDelta {
     }
 }
 
+/// Converts a [`std::time::Duration`](StdDuration) into a `TimeDelta`.
+///
+/// This is the same as [`TimeDelta::from_std`]. The conversion fails with an
+/// [`OutOfRangeError`] if the duration exceeds `i64::MAX` milliseconds.
+impl TryFrom<StdDuration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    #[inline]
+    fn try_from(duration: StdDuration) -> Result<TimeDelta, OutOfRangeError> {
+        TimeDelta::from_std(duration)
+    }
+}
+
+/// Converts a `TimeDelta` into a [`std::time::Duration`](StdDuration).
+///
+/// This is the same as [`TimeDelta::to_std`]. Since the standard library
+/// duration can't be negative, the conversion fails with an
+/// [`OutOfRangeError`] if the `TimeDelta` is less than zero.
+impl TryFrom<TimeDelta> for StdDuration {
+    type Error = OutOfRangeError;
+
+    #[inline]
+    fn try_from(duration: TimeDelta) -> Result<StdDuration, OutOfRangeError> {
+        duration.to_std()
+    }
+}
+
+/// Formats a `TimeDelta` as an ISO 8601 duration with hours and minutes.
+struct Iso8601(TimeDelta);
+
+impl fmt::Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (abs, sign) = if self.0.secs < 0 { (-self.0, "-") } else { (self.0, "") };
+
+        let days = abs.secs / SECS_PER_DAY;
+        let secs = abs.secs % SECS_PER_DAY;
+        let (hours, minutes, secs) =
+            (secs / SECS_PER_HOUR, secs % SECS_PER_HOUR / SECS_PER_MINUTE, secs % SECS_PER_MINUTE);
+
+        write!(f, "{}P", sign)?;
+
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        if days != 0 && hours == 0 && minutes == 0 && secs == 0 && abs.nanos == 0 {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+
+        if abs.nanos != 0 {
+            if abs.nanos % NANOS_PER_MILLI == 0 {
+                write!(f, "{}.{:03}S", secs, abs.nanos / NANOS_PER_MILLI)?;
+            } else if abs.nanos % NANOS_PER_MICRO == 0 {
+                write!(f, "{}.{:06}S", secs, abs.nanos / NANOS_PER_MICRO)?;
+            } else {
+                write!(f, "{}.{:09}S", secs, abs.nanos)?;
+            }
+        } else if secs != 0 || (hours == 0 && minutes == 0) {
+            write!(f, "{}S", secs)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A human readable representation of a [`TimeDelta`].
+///
+/// Created through [`TimeDelta::humanize`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HumanizedTimeDelta(TimeDelta);
+
+impl fmt::Display for HumanizedTimeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_zero() {
+            return f.write_str(if f.alternate() { "0 seconds" } else { "0s" });
+        }
+
+        if self.0 < TimeDelta::zero() {
+            f.write_str("-")?;
+        }
+
+        // `abs` of `MIN` is a millisecond more than `MAX`, which isn't a valid
+        // `TimeDelta` but still fits in its fields, and only those are read here.
+        let abs = self.0.abs();
+        let days = abs.secs / SECS_PER_DAY;
+        let secs = abs.secs % SECS_PER_DAY;
+        let nanos = abs.nanos as i64;
+
+        let parts = [
+            (days, "d", "day"),
+            (secs / SECS_PER_HOUR, "h", "hour"),
+            (secs % SECS_PER_HOUR / SECS_PER_MINUTE, "m", "minute"),
+            (secs % SECS_PER_MINUTE, "s", "second"),
+            (nanos / NANOS_PER_MILLI as i64, "ms", "millisecond"),
+            (nanos % NANOS_PER_MILLI as i64 / NANOS_PER_MICRO as i64, "µs", "microsecond"),
+            (nanos % NANOS_PER_MICRO as i64, "ns", "nanosecond"),
+        ];
+
+        let mut first = true;
+
+        for &(value, short, long) in parts.iter().filter(|(value, ..)| *value != 0) {
+            if !first {
+                f.write_str(" ")?;
+            }
+
+            first = false;
+
+            if f.alternate() {
+                let plural = if value == 1 { "" } else { "s" };
+                write!(f, "{} {}{}", value, long, plural)?;
+            } else {
+                write!(f, "{}{}", value, short)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Represents error when converting `Duration` to/from a standard library
 /// implementation
 ///
@@ -486,6 +995,8 @@ fn div_rem_64(this: i64, other: i64) -> (i64, i64) {
 #[cfg(test)]
 mod tests {
     use super::{OutOfRangeError, TimeDelta, MAX, MIN};
+    use crate::error::{ChronoError, ChronoErrorKind};
+    use core::convert::TryFrom;
     use std::time::Duration as StdDuration;
     use std::{i32, i64};
 
@@ -510,6 +1021,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_alias() {
+        use crate::{Duration, NaiveDate, TimeZone, Utc};
+
+        let delta: TimeDelta = Duration::hours(1) + TimeDelta::minutes(30);
+        assert_eq!(delta, Duration::minutes(90));
+        assert_eq!(Duration::seconds(5) - TimeDelta::seconds(2), TimeDelta::seconds(3));
+
+        let dt = Utc.ymd(2022, 6, 1).unwrap().and_hms(12, 0, 0).unwrap();
+        assert_eq!(dt + Duration::days(1), dt + TimeDelta::days(1));
+        assert_eq!((dt + Duration::hours(2)) - dt, TimeDelta::hours(2));
+
+        let date = NaiveDate::from_ymd(2022, 6, 1).unwrap();
+        let diff: Duration = NaiveDate::from_ymd(2022, 6, 8).unwrap().signed_duration_since(date);
+        assert_eq!(diff.num_weeks(), 1);
+    }
+
     #[test]
     fn test_duration_num_days() {
         assert_eq!(TimeDelta::zero().num_days(), 0);
@@ -523,6 +1051,35 @@ mod tests {
         assert_eq!(TimeDelta::days(i32::MIN as i64).num_days(), i32::MIN as i64);
     }
 
+    #[test]
+    fn test_duration_weeks() {
+        assert_eq!(TimeDelta::weeks(2).num_days(), 14);
+        assert_eq!(TimeDelta::weeks(-3), TimeDelta::days(-21));
+        assert_eq!(TimeDelta::try_weeks(1), Ok(TimeDelta::seconds(7 * 86400)));
+
+        assert_eq!(TimeDelta::zero().num_weeks(), 0);
+        assert_eq!(TimeDelta::weeks(2).num_weeks(), 2);
+        assert_eq!(TimeDelta::days(13).num_weeks(), 1);
+        assert_eq!(TimeDelta::days(-13).num_weeks(), -1);
+        assert_eq!((TimeDelta::weeks(1) - TimeDelta::nanoseconds(1)).num_weeks(), 0);
+
+        // the largest number of weeks within `i64::MAX` milliseconds
+        let max_weeks = i64::MAX / 1000 / (7 * 86400);
+        assert_eq!(TimeDelta::try_weeks(max_weeks).map(|d| d.num_weeks()), Ok(max_weeks));
+        assert_eq!(TimeDelta::try_weeks(-max_weeks).map(|d| d.num_weeks()), Ok(-max_weeks));
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        assert_eq!(TimeDelta::try_weeks(max_weeks + 1), overflow);
+        assert_eq!(TimeDelta::try_weeks(-max_weeks - 1), overflow);
+        assert_eq!(TimeDelta::try_weeks(i64::MAX), overflow);
+        assert_eq!(TimeDelta::try_weeks(i64::MIN), overflow);
+    }
+
+    #[test]
+    #[should_panic(expected = "Duration::weeks out of bounds")]
+    fn test_duration_weeks_overflow() {
+        TimeDelta::weeks(i64::MAX / 1000);
+    }
+
     #[test]
     fn test_duration_num_seconds() {
         assert_eq!(TimeDelta::zero().num_seconds(), 0);
@@ -618,6 +1175,44 @@ mod tests {
         assert!(TimeDelta::milliseconds(i64::MIN)
             .checked_sub(&TimeDelta::milliseconds(1))
             .is_none());
+
+        // `checked_mul` agrees with `*` where it doesn't overflow
+        for &d in &[
+            TimeDelta::zero(),
+            TimeDelta::nanoseconds(30),
+            TimeDelta::milliseconds(-1500),
+            TimeDelta::days(1) + TimeDelta::nanoseconds(1),
+        ] {
+            for &n in &[0, 1, -1, 3, -2, 333_333_333, i32::MAX, i32::MIN] {
+                assert_eq!(d.checked_mul(n), Some(d * n), "{:?} * {}", d, n);
+            }
+        }
+        assert_eq!(TimeDelta::max_value().checked_mul(1), Some(TimeDelta::max_value()));
+        assert_eq!(TimeDelta::max_value().checked_mul(-1), Some(-TimeDelta::max_value()));
+        assert_eq!(TimeDelta::min_value().checked_mul(-1), None);
+        assert_eq!(TimeDelta::max_value().checked_mul(2), None);
+        assert_eq!(TimeDelta::min_value().checked_mul(-2), None);
+        assert_eq!(
+            TimeDelta::days(1).checked_mul(i32::MAX),
+            Some(TimeDelta::days(i64::from(i32::MAX)))
+        );
+        assert_eq!(TimeDelta::weeks(1_000_000).checked_mul(i32::MAX), None);
+        assert_eq!(TimeDelta::weeks(1_000_000).checked_mul(i32::MIN), None);
+        assert_eq!(TimeDelta::milliseconds(i64::MAX / 2 + 1).checked_mul(2), None);
+
+        // `checked_div` agrees with `/`, except for zero
+        assert_eq!(
+            TimeDelta::seconds(-4).checked_div(3),
+            Some(TimeDelta::nanoseconds(-1_333_333_333))
+        );
+        assert_eq!(TimeDelta::max_value().checked_div(-1), Some(-TimeDelta::max_value()));
+        assert_eq!(TimeDelta::min_value().checked_div(-1), None);
+        assert_eq!(
+            TimeDelta::min_value().checked_div(i32::MIN),
+            Some(TimeDelta::min_value() / i32::MIN)
+        );
+        assert_eq!(TimeDelta::seconds(1).checked_div(0), None);
+        assert_eq!(TimeDelta::zero().checked_div(0), None);
     }
 
     #[test]
@@ -672,6 +1267,52 @@ mod tests {
         assert_eq!(TimeDelta::seconds(-4) / -3, TimeDelta::nanoseconds(1_333_333_333));
     }
 
+    #[test]
+    fn test_duration_div_duration_f64() {
+        assert_eq!(TimeDelta::hours(1).div_duration_f64(TimeDelta::minutes(30)), 2.0);
+        assert_eq!(TimeDelta::minutes(30).div_duration_f64(TimeDelta::hours(1)), 0.5);
+        assert_eq!(TimeDelta::nanoseconds(1).div_duration_f64(TimeDelta::seconds(1)), 1e-9);
+        assert_eq!(TimeDelta::seconds(-3).div_duration_f64(TimeDelta::seconds(2)), -1.5);
+        assert_eq!(TimeDelta::max_value().div_duration_f64(TimeDelta::max_value()), 1.0);
+        assert_eq!(TimeDelta::min_value().div_duration_f64(TimeDelta::max_value()), -1.0);
+
+        assert_eq!(TimeDelta::seconds(1).div_duration_f64(TimeDelta::zero()), f64::INFINITY);
+        assert_eq!(TimeDelta::seconds(-1).div_duration_f64(TimeDelta::zero()), f64::NEG_INFINITY);
+        assert!(TimeDelta::zero().div_duration_f64(TimeDelta::zero()).is_nan());
+    }
+
+    #[test]
+    fn test_duration_mul_f64() {
+        assert_eq!(TimeDelta::hours(1).mul_f64(0.5), TimeDelta::minutes(30));
+        assert_eq!(TimeDelta::seconds(1).mul_f64(1e-9), TimeDelta::nanoseconds(1));
+        assert_eq!(TimeDelta::seconds(3).mul_f64(-0.5), TimeDelta::milliseconds(-1500));
+        assert_eq!(TimeDelta::seconds(-3).mul_f64(0.5), TimeDelta::milliseconds(-1500));
+        assert_eq!(TimeDelta::seconds(5).mul_f64(0.0), TimeDelta::zero());
+        // rounds towards zero
+        assert_eq!(TimeDelta::nanoseconds(3).mul_f64(0.5), TimeDelta::nanoseconds(1));
+        assert_eq!(TimeDelta::nanoseconds(-3).mul_f64(0.5), TimeDelta::nanoseconds(-1));
+
+        // a duration is recovered from its ratio
+        let a = TimeDelta::minutes(90);
+        let b = TimeDelta::hours(1);
+        assert_eq!(b.mul_f64(a.div_duration_f64(b)), a);
+
+        let big = TimeDelta::seconds(1 << 40);
+        assert_eq!(big.checked_mul_f64(1.0), Some(big));
+        assert_eq!(big.checked_mul_f64(-2.0), Some(big * -2));
+        assert_eq!(TimeDelta::max_value().checked_mul_f64(1.5), None);
+        assert_eq!(TimeDelta::min_value().checked_mul_f64(-1.0), None);
+        assert_eq!(TimeDelta::seconds(1).checked_mul_f64(f64::INFINITY), None);
+        assert_eq!(TimeDelta::seconds(1).checked_mul_f64(f64::NAN), None);
+        assert_eq!(TimeDelta::zero().checked_mul_f64(f64::NAN), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_mul_f64_overflow() {
+        let _ = TimeDelta::max_value().mul_f64(2.0);
+    }
+
     #[test]
     fn test_duration_sum() {
         let duration_list_1 = [TimeDelta::zero(), TimeDelta::seconds(1)];
@@ -695,6 +1336,33 @@ mod tests {
         ];
         let sum_3: TimeDelta = duration_vec.into_iter().sum();
         assert_eq!(sum_3, TimeDelta::seconds(17));
+
+        let empty: [TimeDelta; 0] = [];
+        assert_eq!(empty.iter().sum::<TimeDelta>(), TimeDelta::zero());
+
+        // saturates instead of overflowing
+        let max = TimeDelta::max_value();
+        let min = TimeDelta::min_value();
+        assert_eq!([max, TimeDelta::seconds(1)].iter().sum::<TimeDelta>(), max);
+        assert_eq!(vec![min, -TimeDelta::seconds(1)].into_iter().sum::<TimeDelta>(), min);
+        assert_eq!([max, max, min].iter().sum::<TimeDelta>(), max + min);
+    }
+
+    #[test]
+    fn test_duration_try_sum() {
+        let durations =
+            vec![TimeDelta::seconds(1), TimeDelta::milliseconds(1500), TimeDelta::zero()];
+        assert_eq!(TimeDelta::try_sum(&durations), Ok(TimeDelta::milliseconds(2500)));
+        assert_eq!(TimeDelta::try_sum(durations.iter()), Ok(TimeDelta::milliseconds(2500)));
+        assert_eq!(TimeDelta::try_sum(durations), Ok(TimeDelta::milliseconds(2500)));
+        assert_eq!(TimeDelta::try_sum(Vec::<TimeDelta>::new()), Ok(TimeDelta::zero()));
+
+        let overflow = Err(ChronoError::new(ChronoErrorKind::Overflow));
+        let max = TimeDelta::max_value();
+        let min = TimeDelta::min_value();
+        assert_eq!(TimeDelta::try_sum(&[max, TimeDelta::nanoseconds(1)]), overflow);
+        assert_eq!(TimeDelta::try_sum(&[min, -TimeDelta::nanoseconds(1)]), overflow);
+        assert_eq!(TimeDelta::try_sum(&[max, min]), Ok(max + min));
     }
 
     #[test]
@@ -717,6 +1385,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_humanize() {
+        assert_eq!(TimeDelta::seconds(3723).humanize().to_string(), "1h 2m 3s");
+        assert_eq!(TimeDelta::seconds(-3723).humanize().to_string(), "-1h 2m 3s");
+        assert_eq!(TimeDelta::zero().humanize().to_string(), "0s");
+        assert_eq!(TimeDelta::days(3).humanize().to_string(), "3d");
+        assert_eq!((TimeDelta::days(1) + TimeDelta::seconds(1)).humanize().to_string(), "1d 1s");
+        assert_eq!(TimeDelta::nanoseconds(1_001_001).humanize().to_string(), "1ms 1µs 1ns");
+        assert_eq!(TimeDelta::nanoseconds(-1).humanize().to_string(), "-1ns");
+
+        assert_eq!(format!("{:#}", TimeDelta::days(3).humanize()), "3 days");
+        assert_eq!(
+            format!("{:#}", TimeDelta::seconds(3661).humanize()),
+            "1 hour 1 minute 1 second"
+        );
+        assert_eq!(format!("{:#}", TimeDelta::zero().humanize()), "0 seconds");
+
+        assert_eq!(MAX.humanize().to_string(), "106751991167d 7h 12m 55s 807ms");
+        assert_eq!(MIN.humanize().to_string(), "-106751991167d 7h 12m 55s 808ms");
+        assert_eq!(
+            format!("{:#}", TimeDelta::min_value().humanize()),
+            "-106751991167 days 7 hours 12 minutes 55 seconds 808 milliseconds"
+        );
+    }
+
+    #[test]
+    fn test_duration_iso8601() {
+        for &(s, d) in &[
+            ("PT1H30M", TimeDelta::minutes(90)),
+            ("P2DT3H", TimeDelta::days(2) + TimeDelta::hours(3)),
+            ("P42D", TimeDelta::days(42)),
+            ("PT0S", TimeDelta::zero()),
+            ("PT1M0.500S", TimeDelta::milliseconds(60_500)),
+            ("PT0.000000001S", TimeDelta::nanoseconds(1)),
+            ("-P1DT1S", TimeDelta::seconds(-86401)),
+        ] {
+            assert_eq!(TimeDelta::from_iso8601(s), Ok(d));
+            assert_eq!(d.to_iso8601(), s);
+        }
+
+        assert_eq!(TimeDelta::from_iso8601("P1W"), Ok(TimeDelta::weeks(1)));
+        assert_eq!(TimeDelta::from_iso8601("+PT90M"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(TimeDelta::from_iso8601("PT1,5S"), Ok(TimeDelta::milliseconds(1500)));
+        assert_eq!(
+            TimeDelta::from_iso8601("PT0.1234567891S"),
+            Ok(TimeDelta::nanoseconds(123_456_789))
+        );
+        assert_eq!(TimeDelta::from_iso8601(&MAX.to_iso8601()), Ok(MAX));
+        assert_eq!(TimeDelta::from_iso8601(&MIN.to_iso8601()), Ok(MIN));
+        assert_eq!(TimeDelta::from_iso8601("-PT9223372036854775.808S"), Ok(MIN));
+        assert!(TimeDelta::from_iso8601("-PT9223372036854775.809S").is_err());
+        assert!(TimeDelta::from_iso8601("PT9223372036854775.808S").is_err());
+
+        let unsupported = Err(ChronoError::new(ChronoErrorKind::UnsupportedDurationUnit));
+        assert_eq!(TimeDelta::from_iso8601("P1Y"), unsupported);
+        assert_eq!(TimeDelta::from_iso8601("P1M"), unsupported);
+        assert_eq!(TimeDelta::from_iso8601("P1Y2DT3H"), unsupported);
+
+        for s in &[
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "P1",
+            "PT1D",
+            "P1H",
+            "PT1H1H",
+            "PT1M1H",
+            "P1DT1HT1M",
+            "PT1.5M",
+            "PT.5S",
+            "PT1.S",
+            "P-1D",
+            "PT9223372036854775808S",
+            "P106751991167301D",
+        ] {
+            assert_eq!(
+                TimeDelta::from_iso8601(s),
+                Err(ChronoError::new(ChronoErrorKind::InvalidDuration)),
+                "{}",
+                s
+            );
+        }
+    }
+
     #[test]
     fn test_to_std() {
         assert_eq!(TimeDelta::seconds(1).to_std(), Ok(StdDuration::new(1, 0)));
@@ -752,4 +1506,22 @@ mod tests {
             Err(OutOfRangeError(()))
         );
     }
+
+    #[test]
+    fn test_std_try_from() {
+        let std = StdDuration::new(123, 456_789_012);
+        let delta = TimeDelta::try_from(std).unwrap();
+        assert_eq!(delta, TimeDelta::nanoseconds(123_456_789_012));
+        assert_eq!(StdDuration::try_from(delta), Ok(std));
+
+        assert_eq!(StdDuration::try_from(TimeDelta::zero()), Ok(StdDuration::new(0, 0)));
+        assert_eq!(StdDuration::try_from(-delta), Err(OutOfRangeError(())));
+        assert_eq!(StdDuration::try_from(TimeDelta::nanoseconds(-1)), Err(OutOfRangeError(())));
+
+        assert_eq!(TimeDelta::try_from(StdDuration::new(9223372036854775, 807000000)), Ok(MAX));
+        assert_eq!(
+            TimeDelta::try_from(StdDuration::new(9223372036854776, 0)),
+            Err(OutOfRangeError(()))
+        );
+    }
 }
@@ -1,5 +1,34 @@
 use crate::{ChronoError, IsoWeek, Weekday};
 
+/// A field of a date and time, to read it with
+/// [`DateTime::get_field`](./struct.DateTime.html#method.get_field).
+///
+/// This allows generic code to pick a field at runtime, rather than matching
+/// on the [`Datelike`] and [`Timelike`] accessors.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum DateField {
+    /// The year, see [`Datelike::year`].
+    Year,
+    /// The month from 1 to 12, see [`Datelike::month`].
+    Month,
+    /// The day of the month from 1 to 31, see [`Datelike::day`].
+    Day,
+    /// The hour from 0 to 23, see [`Timelike::hour`].
+    Hour,
+    /// The minute from 0 to 59, see [`Timelike::minute`].
+    Minute,
+    /// The second from 0 to 59, see [`Timelike::second`].
+    Second,
+    /// The nanosecond from 0 to 1,999,999,999, see [`Timelike::nanosecond`].
+    Nanosecond,
+    /// The day of the year from 1 to 366, see [`Datelike::ordinal`].
+    Ordinal,
+    /// The day of the week from 1 (Monday) to 7 (Sunday), as in ISO 8601.
+    Weekday,
+    /// The ISO week number from 1 to 53, see [`IsoWeek::week`].
+    IsoWeek,
+}
+
 /// The common set of methods for date component.
 pub trait Datelike: Sized {
     /// Returns the year number in the [calendar date](./naive/struct.NaiveDate.html#calendar-date).
@@ -27,6 +56,26 @@ pub trait Datelike: Sized {
     /// The return value ranges from 0 to 11.
     fn month0(&self) -> u32;
 
+    /// Returns the quarter of the year, starting from 1.
+    ///
+    /// The return value ranges from 1 to 4: January to March is the first quarter, April to June
+    /// the second and so on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2022, 3, 31)?.quarter(), 1);
+    /// assert_eq!(NaiveDate::from_ymd(2022, 4, 1)?.quarter(), 2);
+    /// assert_eq!(NaiveDate::from_ymd(2022, 12, 31)?.quarter(), 4);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    #[inline]
+    fn quarter(&self) -> u32 {
+        self.month0() / 3 + 1
+    }
+
     /// Returns the day of month starting from 1.
     ///
     /// The return value ranges from 1 to 31. (The last day of month differs by months.)
@@ -95,10 +144,15 @@ pub trait Datelike: Sized {
     /// ```
     /// use chrono::{NaiveDate, Datelike};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(1970, 1, 1).num_days_from_ce(), 719_163);
-    /// assert_eq!(NaiveDate::from_ymd(2, 1, 1).num_days_from_ce(), 366);
-    /// assert_eq!(NaiveDate::from_ymd(1, 1, 1).num_days_from_ce(), 1);
-    /// assert_eq!(NaiveDate::from_ymd(0, 1, 1).num_days_from_ce(), -365);
+    /// assert_eq!(NaiveDate::from_ymd(1970, 1, 1)?.num_days_from_ce(), 719_163);
+    /// assert_eq!(NaiveDate::from_ymd(2, 1, 1)?.num_days_from_ce(), 366);
+    /// assert_eq!(NaiveDate::from_ymd(1, 1, 1)?.num_days_from_ce(), 1);
+    /// assert_eq!(NaiveDate::from_ymd(0, 1, 1)?.num_days_from_ce(), -365);
+    ///
+    /// // the inverse is `NaiveDate::from_num_days_from_ce`
+    /// let date = NaiveDate::from_ymd(2022, 6, 15)?;
+    /// assert_eq!(NaiveDate::from_num_days_from_ce(date.num_days_from_ce())?, date);
+    /// # Ok::<_, chrono::ChronoError>(())
     /// ```
     fn num_days_from_ce(&self) -> i32 {
         // See test_num_days_from_ce_against_alternative_impl below for a more straightforward
@@ -116,6 +170,28 @@ pub trait Datelike: Sized {
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
         ndays + self.ordinal() as i32
     }
+
+    /// Returns the number of days in the year, 366 in leap years and 365 otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2020, 6, 15)?.num_days_in_year(), 366);
+    /// assert_eq!(NaiveDate::from_ymd(2021, 6, 15)?.num_days_in_year(), 365);
+    /// assert_eq!(NaiveDate::from_ymd(1900, 6, 15)?.num_days_in_year(), 365);
+    /// assert_eq!(NaiveDate::from_ymd(2000, 6, 15)?.num_days_in_year(), 366);
+    /// # Ok::<_, chrono::ChronoError>(())
+    /// ```
+    fn num_days_in_year(&self) -> u32 {
+        let year = self.year();
+        if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+            366
+        } else {
+            365
+        }
+    }
 }
 
 /// The common set of methods for time component.
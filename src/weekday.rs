@@ -137,10 +137,61 @@ impl Weekday {
             Weekday::Sun => 0,
         }
     }
+
+    /// Returns the number of days from `other` forward to this day of week,
+    /// from 0 to 6.
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::Fri.days_since(Weekday::Mon), 4);
+    /// assert_eq!(Weekday::Mon.days_since(Weekday::Fri), 3);
+    /// assert_eq!(Weekday::Sun.days_since(Weekday::Sun), 0);
+    /// ```
+    #[inline]
+    pub fn days_since(&self, other: Weekday) -> u32 {
+        (self.num_days_from_monday() + 7 - other.num_days_from_monday()) % 7
+    }
+
+    /// Returns the full English name of the day of week.
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::Tue.name(), "Tuesday");
+    /// assert_eq!(Weekday::Tue.to_string(), "Tue");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+    }
 }
 
+/// Formats the day of week with its three-letter abbreviation, e.g. `Tue`.
+///
+/// The alternate form (`{:#}`) prints the [full name](#method.name) instead.
+/// Both forms can be parsed back with [`str::parse`].
+///
+/// ```
+/// use chrono::Weekday;
+///
+/// assert_eq!(format!("{}", Weekday::Tue), "Tue");
+/// assert_eq!(format!("{:#}", Weekday::Tue), "Tuesday");
+/// assert_eq!(format!("{:#}", Weekday::Tue).parse::<Weekday>(), Ok(Weekday::Tue));
+/// ```
 impl fmt::Display for Weekday {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(self.name());
+        }
+
         f.write_str(match *self {
             Weekday::Mon => "Mon",
             Weekday::Tue => "Tue",
@@ -297,3 +348,54 @@ mod weekday_serde {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Weekday;
+
+    #[test]
+    fn test_weekday_from_str_and_display() {
+        assert_eq!("tue".parse::<Weekday>(), Ok(Weekday::Tue));
+        assert_eq!("Tuesday".parse::<Weekday>(), Ok(Weekday::Tue));
+        assert_eq!("TUESDAY".parse::<Weekday>(), Ok(Weekday::Tue));
+        assert!("Funday".parse::<Weekday>().is_err());
+        assert!("tues".parse::<Weekday>().is_err());
+        assert!(" tue".parse::<Weekday>().is_err());
+        assert!("".parse::<Weekday>().is_err());
+
+        let mut day = Weekday::Mon;
+        for _ in 0..7 {
+            assert_eq!(day.to_string().len(), 3);
+            assert_eq!(format!("{:#}", day), day.name());
+            assert_eq!(day.to_string().parse::<Weekday>(), Ok(day));
+            assert_eq!(day.name().parse::<Weekday>(), Ok(day));
+            assert_eq!(day.name().to_lowercase().parse::<Weekday>(), Ok(day));
+            day = day.succ();
+        }
+        assert_eq!(Weekday::Wed.name(), "Wednesday");
+    }
+
+    #[test]
+    fn test_weekday_numbers() {
+        assert_eq!(Weekday::Mon.number_from_monday(), 1);
+        assert_eq!(Weekday::Sun.number_from_monday(), 7);
+        assert_eq!(Weekday::Mon.number_from_sunday(), 2);
+        assert_eq!(Weekday::Sun.number_from_sunday(), 1);
+        assert_eq!(Weekday::Sat.number_from_sunday(), 7);
+    }
+
+    #[test]
+    fn test_weekday_days_since() {
+        let mut day = Weekday::Mon;
+        for _ in 0..7 {
+            assert_eq!(day.days_since(day), 0);
+            assert_eq!(day.succ().days_since(day), 1);
+            assert_eq!(day.pred().days_since(day), 6);
+            assert_eq!(day.days_since(Weekday::Mon), day.num_days_from_monday());
+            assert_eq!(day.days_since(Weekday::Sun), day.num_days_from_sunday());
+            day = day.succ();
+        }
+        assert_eq!(Weekday::Sun.days_since(Weekday::Wed), 4);
+        assert_eq!(Weekday::Wed.days_since(Weekday::Sun), 3);
+    }
+}
@@ -0,0 +1,80 @@
+//! Tests for `Local` honoring the `TZ` environment variable.
+//!
+//! These live in their own test binary, as changing the environment affects
+//! every other test using `Local` in the same process.
+
+#![cfg(all(unix, feature = "clock"))]
+
+use std::{env, path, thread};
+
+use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+/// Returns the offsets of `Local` in January and July 2022 with `TZ` set to the given value.
+///
+/// `Local` caches the time zone per thread, so each value is tried on a fresh thread.
+fn offsets_with_tz(tz: &'static str) -> (FixedOffset, FixedOffset) {
+    thread::spawn(move || {
+        env::set_var("TZ", tz);
+        let at = |m| -> NaiveDateTime {
+            NaiveDate::from_ymd(2022, m, 15).unwrap().and_hms(12, 0, 0).unwrap()
+        };
+        let winter = Local.offset_from_utc_datetime(&at(1)).unwrap();
+        let summer = Local.offset_from_utc_datetime(&at(7)).unwrap();
+        (winter, summer)
+    })
+    .join()
+    .unwrap()
+}
+
+#[test]
+fn test_local_tz_env() {
+    let hours = |h| FixedOffset::east(h * 3600);
+
+    // POSIX strings with a fixed offset, which is the negated UTC offset
+    assert_eq!(offsets_with_tz("UTC0"), (hours(0), hours(0)));
+    let ist = FixedOffset::east(5 * 3600 + 1800);
+    assert_eq!(offsets_with_tz("IST-5:30"), (ist, ist));
+    assert_eq!(offsets_with_tz("<-03>3"), (hours(-3), hours(-3)));
+
+    // POSIX strings with daylight saving time rules
+    assert_eq!(offsets_with_tz("EST5EDT,M3.2.0,M11.1.0"), (hours(-5), hours(-4)));
+    assert_eq!(offsets_with_tz("CET-1CEST,M3.5.0,M10.5.0/3"), (hours(1), hours(2)));
+
+    // references to the time zone database, when it is installed
+    if path::Path::new("/usr/share/zoneinfo/America/New_York").exists() {
+        assert_eq!(offsets_with_tz(":America/New_York"), (hours(-5), hours(-4)));
+        assert_eq!(offsets_with_tz("America/New_York"), (hours(-5), hours(-4)));
+    }
+
+    // invalid values fall back to the system time zone, rather than failing
+    let system = thread::spawn(|| {
+        env::remove_var("TZ");
+        let now = Local::now().unwrap();
+        *now.offset()
+    })
+    .join()
+    .unwrap();
+    let fallback = thread::spawn(|| {
+        env::set_var("TZ", "not a time zone!");
+        *Local::now().unwrap().offset()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(system, fallback);
+
+    // the name of the time zone is taken from `TZ` when it refers to a file
+    if path::Path::new("/usr/share/zoneinfo/Europe/Berlin").exists() {
+        env::set_var("TZ", ":/usr/share/zoneinfo/Europe/Berlin");
+        assert_eq!(Local::timezone_name(), Some("Europe/Berlin".to_owned()));
+    }
+    if path::Path::new("/usr/share/zoneinfo/America/New_York").exists() {
+        env::set_var("TZ", "America/New_York");
+        assert_eq!(Local::timezone_name(), Some("America/New_York".to_owned()));
+    }
+    env::set_var("TZ", "EST5EDT,M3.2.0,M11.1.0");
+    assert_eq!(Local::timezone_name(), None);
+
+    // but not when the file doesn't exist, as `Local` uses the system time zone then
+    env::set_var("TZ", ":/usr/share/zoneinfo/Nowhere/Atlantis");
+    assert_eq!(Local::timezone_name(), None);
+}